@@ -0,0 +1,117 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use hex_literal::hex;
+use isis_packet::*;
+
+// LSP with hostname, IS/IP reachability, router capability and SRv6
+// locator TLVs.
+const LSP: &[u8] = &hex!(
+    "
+83 1b 01 00 14 01 00 00 01 9d 01 4f 00 00 00 00
+00 01 00 00 00 00 0e 7d ae a3 03 81 02 cc 8e 01
+04 03 49 00 00 89 07 75 62 75 6e 74 75 31 f2 30
+0b 00 00 01 00 02 09 c0 00 1f 40 01 03 00 3e 80
+13 01 00 16 09 00 00 03 e8 01 03 00 3a 98 17 02
+01 08 19 02 00 00 17 08 29 03 2a 03 2c 02 2d 05
+86 04 01 01 01 01 16 af 00 00 00 00 00 01 03 00
+00 0a 2b 06 04 0b 00 00 01 08 04 0b 00 00 02 0c
+10 20 11 00 00 00 00 00 00 00 00 00 00 00 00 00
+01 20 0b 30 00 00 00 00 00 00 02 00 3a 9a 00 00
+00 00 00 01 04 00 00 0a 6e 06 04 0a 00 00 01 08
+04 0a 00 00 03 0c 10 20 10 00 00 00 00 00 00 00
+00 00 00 00 00 00 01 0d 10 20 10 00 00 00 00 00
+00 00 00 00 00 00 00 00 02 20 0b 30 00 00 00 00
+00 00 03 00 3a 98 20 0b b0 00 00 00 00 00 00 03
+00 3a 99 2c 22 00 00 00 00 00 03 00 00 00 00 05
+20 01 de ad 00 01 00 00 00 01 00 00 00 00 00 00
+06 01 04 28 18 10 00 84 04 0b 00 00 01 87 22 00
+00 00 0a 60 01 01 01 01 08 03 06 00 00 00 00 00
+01 00 00 00 0a 18 0b 00 00 00 00 00 0a 18 0a 00
+00 ec 2a 00 00 00 00 00 40 20 01 de ad 00 01 00
+00 00 00 00 0a 00 40 20 11 00 00 00 00 00 00 00
+00 00 0a 00 40 20 10 00 00 00 00 00 00 1b 2e 00
+00 00 00 00 00 00 00 40 20 01 de ad 00 01 00 00
+1c 05 1a 00 00 01 20 01 de ad 00 01 00 00 00 00
+00 00 00 00 00 00 06 01 04 28 18 10 00
+"
+);
+
+const ITERATIONS: u32 = 200_000;
+
+fn bench(name: &str, f: impl Fn(&[u8]) -> usize) {
+    // Warm up.
+    for _ in 0..ITERATIONS / 10 {
+        black_box(f(black_box(LSP)));
+    }
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f(black_box(LSP)));
+    }
+    let elapsed = start.elapsed();
+    let per_packet = elapsed / ITERATIONS;
+    let rate = ITERATIONS as f64 / elapsed.max(Duration::from_nanos(1)).as_secs_f64();
+    println!("{name:<24} {per_packet:>10?}/packet {rate:>12.0} packets/s");
+}
+
+// Count of entries and decoded sub-TLVs so both variants decode the same
+// parts of every TLV.
+fn owned(input: &[u8]) -> usize {
+    let (_, packet) = parse(input).unwrap();
+    let (IsisPdu::L1Lsp(lsp) | IsisPdu::L2Lsp(lsp)) = packet.pdu else {
+        return 0;
+    };
+    lsp.tlvs
+        .iter()
+        .map(|tlv| match tlv {
+            IsisTlv::ExtIsReach(v) => v.entries.iter().map(|e| 1 + e.subs.len()).sum(),
+            IsisTlv::ExtIpReach(v) => v.entries.iter().map(|e| 1 + e.subs.len()).sum(),
+            IsisTlv::Ipv6Reach(v) => v.entries.iter().map(|e| 1 + e.subs.len()).sum(),
+            IsisTlv::Srv6(v) => v.locators.iter().map(|l| 1 + l.subs.len()).sum(),
+            IsisTlv::RouterCap(v) => v.subs.len(),
+            _ => 1,
+        })
+        .sum()
+}
+
+fn borrowed(input: &[u8]) -> usize {
+    let packet = IsisPacketRef::parse(input).unwrap();
+    let mut count = 0;
+    for tlv in packet.tlvs().flatten() {
+        count += match tlv.view() {
+            Ok(IsisTlvView::ExtIsReach(entries)) => entries
+                .map(|e| 1 + e.subs.filter_map(|sub| sub.neigh().ok()).count())
+                .sum(),
+            Ok(IsisTlvView::ExtIpReach(entries)) => entries
+                .map(|e| 1 + e.subs.filter_map(|sub| sub.prefix().ok()).count())
+                .sum(),
+            Ok(IsisTlvView::Ipv6Reach(entries)) => entries
+                .map(|e| 1 + e.subs.filter_map(|sub| sub.prefix().ok()).count())
+                .sum(),
+            Ok(IsisTlvView::Srv6 { locators, .. }) => locators
+                .map(|l| 1 + l.subs.filter_map(|sub| sub.prefix().ok()).count())
+                .sum(),
+            Ok(IsisTlvView::RouterCap { subs, .. }) => {
+                subs.filter_map(|sub| sub.cap().ok()).count()
+            }
+            Ok(_) => 1,
+            Err(_) => 0,
+        };
+    }
+    count
+}
+
+fn headers(input: &[u8]) -> usize {
+    let packet = IsisPacketRef::parse(input).unwrap();
+    match packet.pdu() {
+        IsisPduRef::L1Lsp(lsp) | IsisPduRef::L2Lsp(lsp) => lsp.seq_number() as usize,
+        _ => 0,
+    }
+}
+
+fn main() {
+    assert_eq!(owned(LSP), borrowed(LSP));
+    bench("parse()", owned);
+    bench("IsisPacketRef tlvs", borrowed);
+    bench("IsisPacketRef header", headers);
+}
@@ -0,0 +1,504 @@
+use bytes::{BufMut, BytesMut};
+use ipnet::{Ipv4Net, Ipv6Net};
+
+use crate::neigh::IsisSubTlv as NeighSubTlv;
+use crate::prefix::IsisSubTlv as PrefixSubTlv;
+use crate::prefix::{IsisSub2Tlv, Srv6Locator};
+use crate::util::TlvEmitter;
+use crate::{
+    Behavior, IsisFadSubTlv, IsisLsp, IsisNeighborId, IsisSubPrefixSid, IsisTlv,
+    IsisTlvExtIsReachEntry, IsisTlvRouterCap, SidLabelTlv, SidLabelValue,
+};
+
+/// BGP-LS NLRI types (RFC 9552, RFC 9514).
+#[repr(u16)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum IsisBgpLsNlriType {
+    Node = 1,
+    Link = 2,
+    Ipv4Prefix = 3,
+    Ipv6Prefix = 4,
+    Srv6Sid = 6,
+}
+
+/// BGP-LS NLRI and the value of its BGP-LS Attribute (path attribute 29).
+#[derive(Debug, Clone)]
+pub struct IsisBgpLsNlri {
+    pub typ: IsisBgpLsNlriType,
+    /// NLRI including the type and length, as placed in MP_REACH_NLRI.
+    pub nlri: BytesMut,
+    /// Attribute TLVs, empty when there are none.
+    pub attr: BytesMut,
+}
+
+// NLRI descriptor TLVs.
+const LOCAL_NODE_DESC: u16 = 256;
+const REMOTE_NODE_DESC: u16 = 257;
+const IPV4_IF_ADDR: u16 = 259;
+const IPV4_NEIGH_ADDR: u16 = 260;
+const IPV6_IF_ADDR: u16 = 261;
+const IPV6_NEIGH_ADDR: u16 = 262;
+const MT_ID: u16 = 263;
+const IP_REACH_INFO: u16 = 265;
+const IGP_ROUTER_ID: u16 = 515;
+const SRV6_SID_INFO: u16 = 518;
+
+// Node attribute TLVs.
+const NODE_MSD: u16 = 266;
+const NODE_FLAGS: u16 = 1024;
+const NODE_NAME: u16 = 1026;
+const ISIS_AREA_ID: u16 = 1027;
+const IPV4_ROUTER_ID: u16 = 1028;
+const IPV6_ROUTER_ID: u16 = 1029;
+const SR_CAP: u16 = 1034;
+const SR_ALGO: u16 = 1035;
+const SR_LB: u16 = 1036;
+const SRV6_CAP: u16 = 1038;
+const FLEX_ALGO_DEF: u16 = 1039;
+
+// Flexible Algorithm Definition sub-TLVs (RFC 9351).
+const FAD_EXCLUDE_ANY: u16 = 1040;
+const FAD_INCLUDE_ANY: u16 = 1041;
+const FAD_INCLUDE_ALL: u16 = 1042;
+const FAD_FLAGS: u16 = 1043;
+
+// Link attribute TLVs.
+const ADMIN_GROUP: u16 = 1088;
+const IGP_METRIC: u16 = 1095;
+const ADJ_SID: u16 = 1099;
+const LAN_ADJ_SID: u16 = 1100;
+const SRV6_END_X_SID: u16 = 1106;
+const SRV6_LAN_END_X_SID: u16 = 1107;
+const MIN_MAX_DELAY: u16 = 1115;
+const EXT_ADMIN_GROUP: u16 = 1173;
+
+// Prefix attribute TLVs.
+const PREFIX_METRIC: u16 = 1155;
+const PREFIX_SID: u16 = 1158;
+const SID_LABEL: u16 = 1161;
+const SRV6_LOCATOR: u16 = 1162;
+
+// SRv6 SID attribute TLVs.
+const SRV6_ENDPOINT_BEHAVIOR: u16 = 1250;
+const SRV6_SID_STRUCTURE: u16 = 1252;
+
+// Node flag bits.
+const NODE_FLAG_OVERLOAD: u8 = 0x80;
+const NODE_FLAG_ATTACHED: u8 = 0x40;
+
+// TLV with a 2 octet type and length, the value is written by `f`.
+fn put_tlv(buf: &mut BytesMut, typ: u16, f: impl FnOnce(&mut BytesMut)) {
+    buf.put_u16(typ);
+    let pp = buf.len();
+    buf.put_u16(0);
+    f(buf);
+    let len = (buf.len() - pp - 2) as u16;
+    buf[pp..pp + 2].copy_from_slice(&len.to_be_bytes());
+}
+
+// Node descriptor with the IGP Router-ID, 6 octets for a router and 7 for a
+// pseudonode.
+fn node_desc(buf: &mut BytesMut, typ: u16, id: &IsisNeighborId) {
+    put_tlv(buf, typ, |buf| {
+        put_tlv(buf, IGP_ROUTER_ID, |buf| {
+            if id.pseudo_id() == 0 {
+                buf.put(&id.id[..6]);
+            } else {
+                buf.put(&id.id[..]);
+            }
+        });
+    });
+}
+
+fn sid_label(buf: &mut BytesMut, sid: &SidLabelTlv) {
+    put_tlv(buf, SID_LABEL, |buf| match sid {
+        SidLabelTlv::Label(v) => buf.put(&v.to_be_bytes()[1..]),
+        SidLabelTlv::Index(v) => buf.put_u32(*v),
+    });
+}
+
+fn sid_structure(buf: &mut BytesMut, sub2s: &[IsisSub2Tlv]) {
+    for sub2 in sub2s {
+        if let IsisSub2Tlv::SidStructure(v) = sub2 {
+            put_tlv(buf, SRV6_SID_STRUCTURE, |buf| {
+                buf.put_u8(v.lb_len);
+                buf.put_u8(v.ln_len);
+                buf.put_u8(v.fun_len);
+                buf.put_u8(v.arg_len);
+            });
+        }
+    }
+}
+
+fn mt_id(buf: &mut BytesMut, mt_id: u16) {
+    if mt_id != 0 {
+        put_tlv(buf, MT_ID, |buf| buf.put_u16(mt_id));
+    }
+}
+
+// Common NLRI header: Protocol-ID, Identifier and the Local Node Descriptors.
+struct NlriHeader {
+    protocol_id: u8,
+    identifier: u64,
+    local: IsisNeighborId,
+}
+
+impl NlriHeader {
+    fn nlri(&self, typ: IsisBgpLsNlriType, f: impl FnOnce(&mut BytesMut)) -> BytesMut {
+        let mut buf = BytesMut::new();
+        put_tlv(&mut buf, typ as u16, |buf| {
+            buf.put_u8(self.protocol_id);
+            buf.put_u64(self.identifier);
+            node_desc(buf, LOCAL_NODE_DESC, &self.local);
+            f(buf);
+        });
+        buf
+    }
+}
+
+fn node_attr(lsp: &IsisLsp, caps: &[&IsisTlvRouterCap]) -> BytesMut {
+    let mut attr = BytesMut::new();
+    let mut flags = 0;
+    if lsp.types.ol_bits() {
+        flags |= NODE_FLAG_OVERLOAD;
+    }
+    if lsp.types.att_bits() != 0 {
+        flags |= NODE_FLAG_ATTACHED;
+    }
+    if flags != 0 {
+        put_tlv(&mut attr, NODE_FLAGS, |buf| buf.put_u8(flags));
+    }
+    for tlv in &lsp.tlvs {
+        match tlv {
+            IsisTlv::Hostname(v) => {
+                put_tlv(&mut attr, NODE_NAME, |buf| buf.put(v.hostname.as_bytes()))
+            }
+            IsisTlv::AreaAddr(v) => {
+                put_tlv(&mut attr, ISIS_AREA_ID, |buf| buf.put(&v.area_addr[..]))
+            }
+            IsisTlv::TeRouterId(v) => put_tlv(&mut attr, IPV4_ROUTER_ID, |buf| {
+                buf.put(&v.router_id.octets()[..])
+            }),
+            IsisTlv::Ipv6TeRouterId(v) => put_tlv(&mut attr, IPV6_ROUTER_ID, |buf| {
+                buf.put(&v.router_id.octets()[..])
+            }),
+            _ => {}
+        }
+    }
+    for cap in caps {
+        for sub in &cap.subs {
+            use crate::cap::IsisSubTlv::*;
+            match sub {
+                SegmentRoutingCap(v) => put_tlv(&mut attr, SR_CAP, |buf| {
+                    buf.put_u8(v.flags.into());
+                    buf.put_u8(0);
+                    buf.put(&v.range.to_be_bytes()[1..]);
+                    sid_label(buf, &v.sid_label);
+                }),
+                SegmentRoutingAlgo(v) => put_tlv(&mut attr, SR_ALGO, |buf| {
+                    v.algo.iter().for_each(|algo| buf.put_u8(algo.to_byte()))
+                }),
+                SegmentRoutingLB(v) => put_tlv(&mut attr, SR_LB, |buf| {
+                    buf.put_u8(v.flags);
+                    buf.put_u8(0);
+                    buf.put(&v.range.to_be_bytes()[1..]);
+                    sid_label(buf, &v.sid_label);
+                }),
+                NodeMaxSidDepth(v) => put_tlv(&mut attr, NODE_MSD, |buf| {
+                    buf.put_u8(v.flags);
+                    buf.put_u8(v.depth);
+                }),
+                Srv6(v) => put_tlv(&mut attr, SRV6_CAP, |buf| {
+                    buf.put_u16(v.flags.into());
+                    buf.put_u16(0);
+                }),
+                FlexAlgoDef(v) => put_tlv(&mut attr, FLEX_ALGO_DEF, |buf| {
+                    buf.put_u8(v.algo.into());
+                    buf.put_u8(v.metric_type.into());
+                    buf.put_u8(v.calc_type);
+                    buf.put_u8(v.priority);
+                    for sub in &v.subs {
+                        match sub {
+                            IsisFadSubTlv::ExcludeAny(v) => {
+                                put_groups(buf, FAD_EXCLUDE_ANY, &v.groups)
+                            }
+                            IsisFadSubTlv::IncludeAny(v) => {
+                                put_groups(buf, FAD_INCLUDE_ANY, &v.groups)
+                            }
+                            IsisFadSubTlv::IncludeAll(v) => {
+                                put_groups(buf, FAD_INCLUDE_ALL, &v.groups)
+                            }
+                            IsisFadSubTlv::Flags(v) => {
+                                put_tlv(buf, FAD_FLAGS, |buf| buf.put(&v.flags[..]))
+                            }
+                            IsisFadSubTlv::Unknown(_) => {}
+                        }
+                    }
+                }),
+                AreaLeader(_) | DynamicFlooding(_) | Unknown(_) => {}
+            }
+        }
+    }
+    attr
+}
+
+fn put_groups(buf: &mut BytesMut, typ: u16, groups: &[u32]) {
+    put_tlv(buf, typ, |buf| groups.iter().for_each(|g| buf.put_u32(*g)));
+}
+
+fn sid_value(attr: &mut BytesMut, sid: &SidLabelValue) {
+    match sid {
+        SidLabelValue::Label(v) => attr.put(&v.to_be_bytes()[1..]),
+        SidLabelValue::Index(v) => attr.put_u32(*v),
+    }
+}
+
+fn end_x_sid(
+    buf: &mut BytesMut,
+    behavior: Behavior,
+    flags: u8,
+    algo: u8,
+    weight: u8,
+    neighbor: Option<&[u8; 6]>,
+) {
+    buf.put_u16(behavior.into());
+    buf.put_u8(flags);
+    buf.put_u8(algo);
+    buf.put_u8(weight);
+    buf.put_u8(0);
+    if let Some(neighbor) = neighbor {
+        buf.put(&neighbor[..]);
+    }
+}
+
+fn link(header: &NlriHeader, entry: &IsisTlvExtIsReachEntry) -> IsisBgpLsNlri {
+    let nlri = header.nlri(IsisBgpLsNlriType::Link, |buf| {
+        node_desc(buf, REMOTE_NODE_DESC, &entry.neighbor_id);
+        // Link descriptors are ordered by type.
+        let descs = [IPV4_IF_ADDR, IPV4_NEIGH_ADDR, IPV6_IF_ADDR, IPV6_NEIGH_ADDR];
+        for typ in descs {
+            for sub in &entry.subs {
+                match sub {
+                    NeighSubTlv::Ipv4IfAddr(v) if typ == IPV4_IF_ADDR => {
+                        put_tlv(buf, typ, |buf| buf.put(&v.addr.octets()[..]))
+                    }
+                    NeighSubTlv::Ipv4NeighAddr(v) if typ == IPV4_NEIGH_ADDR => {
+                        put_tlv(buf, typ, |buf| buf.put(&v.addr.octets()[..]))
+                    }
+                    NeighSubTlv::Ipv6IfAddr(v) if typ == IPV6_IF_ADDR => {
+                        put_tlv(buf, typ, |buf| buf.put(&v.addr.octets()[..]))
+                    }
+                    NeighSubTlv::Ipv6NeighAddr(v) if typ == IPV6_NEIGH_ADDR => {
+                        put_tlv(buf, typ, |buf| buf.put(&v.addr.octets()[..]))
+                    }
+                    _ => {}
+                }
+            }
+        }
+    });
+
+    let mut attr = BytesMut::new();
+    put_tlv(&mut attr, IGP_METRIC, |buf| {
+        buf.put(&entry.metric.to_be_bytes()[1..])
+    });
+    for sub in &entry.subs {
+        match sub {
+            NeighSubTlv::AdminGroup(v) => put_tlv(&mut attr, ADMIN_GROUP, |buf| v.emit(buf)),
+            NeighSubTlv::ExtAdminGroup(v) => put_groups(&mut attr, EXT_ADMIN_GROUP, &v.groups),
+            NeighSubTlv::MinMaxDelay(v) => put_tlv(&mut attr, MIN_MAX_DELAY, |buf| v.emit(buf)),
+            NeighSubTlv::AdjSid(v) => put_tlv(&mut attr, ADJ_SID, |buf| {
+                buf.put_u8(v.flags.into());
+                buf.put_u8(v.weight);
+                buf.put_u16(0);
+                sid_value(buf, &v.sid);
+            }),
+            NeighSubTlv::LanAdjSid(v) => put_tlv(&mut attr, LAN_ADJ_SID, |buf| {
+                buf.put_u8(v.flags.into());
+                buf.put_u8(v.weight);
+                buf.put_u16(0);
+                buf.put(&v.system_id.id[..]);
+                sid_value(buf, &v.sid);
+            }),
+            NeighSubTlv::Srv6EndXSid(v) => put_tlv(&mut attr, SRV6_END_X_SID, |buf| {
+                end_x_sid(buf, v.behavior, v.flags, v.algo.to_byte(), v.weight, None);
+                buf.put(&v.sid.octets()[..]);
+                sid_structure(buf, &v.sub2s);
+            }),
+            NeighSubTlv::Srv6LanEndXSid(v) => put_tlv(&mut attr, SRV6_LAN_END_X_SID, |buf| {
+                end_x_sid(
+                    buf,
+                    v.behavior,
+                    v.flags,
+                    v.algo.to_byte(),
+                    v.weight,
+                    Some(&v.system_id.id),
+                );
+                buf.put(&v.sid.octets()[..]);
+                sid_structure(buf, &v.sub2s);
+            }),
+            _ => {}
+        }
+    }
+    IsisBgpLsNlri {
+        typ: IsisBgpLsNlriType::Link,
+        nlri,
+        attr,
+    }
+}
+
+fn prefix_sid(attr: &mut BytesMut, v: &IsisSubPrefixSid) {
+    put_tlv(attr, PREFIX_SID, |buf| {
+        buf.put_u8(v.flags.into());
+        buf.put_u8(v.algo.to_byte());
+        buf.put_u16(0);
+        sid_value(buf, &v.sid);
+    });
+}
+
+fn prefix_attr(metric: u32, subs: &[PrefixSubTlv]) -> BytesMut {
+    let mut attr = BytesMut::new();
+    put_tlv(&mut attr, PREFIX_METRIC, |buf| buf.put_u32(metric));
+    for sub in subs {
+        if let PrefixSubTlv::PrefixSid(v) = sub {
+            prefix_sid(&mut attr, v);
+        }
+    }
+    attr
+}
+
+// IP Reachability Information, the prefix length followed by the
+// significant octets of the prefix.
+fn ip_reach(buf: &mut BytesMut, prefixlen: u8, octets: &[u8]) {
+    put_tlv(buf, IP_REACH_INFO, |buf| {
+        buf.put_u8(prefixlen);
+        buf.put(&octets[..prefixlen.div_ceil(8) as usize]);
+    });
+}
+
+fn ipv4_prefix(header: &NlriHeader, mt: u16, prefix: &Ipv4Net) -> BytesMut {
+    header.nlri(IsisBgpLsNlriType::Ipv4Prefix, |buf| {
+        mt_id(buf, mt);
+        ip_reach(buf, prefix.prefix_len(), &prefix.network().octets());
+    })
+}
+
+fn ipv6_prefix(header: &NlriHeader, mt: u16, prefix: &Ipv6Net) -> BytesMut {
+    header.nlri(IsisBgpLsNlriType::Ipv6Prefix, |buf| {
+        mt_id(buf, mt);
+        ip_reach(buf, prefix.prefix_len(), &prefix.network().octets());
+    })
+}
+
+fn srv6_locator(
+    header: &NlriHeader,
+    mt: u16,
+    locator: &Srv6Locator,
+    nlris: &mut Vec<IsisBgpLsNlri>,
+) {
+    let mut attr = BytesMut::new();
+    put_tlv(&mut attr, SRV6_LOCATOR, |buf| {
+        buf.put_u8(locator.flags);
+        buf.put_u8(locator.algo.to_byte());
+        buf.put_u16(0);
+        buf.put_u32(locator.metric);
+    });
+    nlris.push(IsisBgpLsNlri {
+        typ: IsisBgpLsNlriType::Ipv6Prefix,
+        nlri: ipv6_prefix(header, mt, &locator.locator),
+        attr,
+    });
+
+    for sub in &locator.subs {
+        if let PrefixSubTlv::Srv6EndSid(v) = sub {
+            let nlri = header.nlri(IsisBgpLsNlriType::Srv6Sid, |buf| {
+                mt_id(buf, mt);
+                put_tlv(buf, SRV6_SID_INFO, |buf| buf.put(&v.sid.octets()[..]));
+            });
+            let mut attr = BytesMut::new();
+            put_tlv(&mut attr, SRV6_ENDPOINT_BEHAVIOR, |buf| {
+                buf.put_u16(v.behavior.into());
+                buf.put_u8(v.flags);
+                buf.put_u8(locator.algo.to_byte());
+            });
+            sid_structure(&mut attr, &v.sub2s);
+            nlris.push(IsisBgpLsNlri {
+                typ: IsisBgpLsNlriType::Srv6Sid,
+                nlri,
+                attr,
+            });
+        }
+    }
+}
+
+impl IsisLsp {
+    /// BGP-LS NLRIs for the contents of the LSP. `level` is the IS-IS level
+    /// used as the Protocol-ID and `identifier` the BGP-LS Identifier of the
+    /// routing universe. Only fragment zero originates the Node NLRI.
+    pub fn bgp_ls(&self, level: u8, identifier: u64) -> Vec<IsisBgpLsNlri> {
+        let header = NlriHeader {
+            protocol_id: level,
+            identifier,
+            local: self.lsp_id.neighbor_id(),
+        };
+        let mut nlris = Vec::new();
+
+        if !self.lsp_id.is_fragment() {
+            let caps: Vec<&IsisTlvRouterCap> = self
+                .tlvs
+                .iter()
+                .filter_map(|tlv| match tlv {
+                    IsisTlv::RouterCap(v) => Some(v),
+                    _ => None,
+                })
+                .collect();
+            nlris.push(IsisBgpLsNlri {
+                typ: IsisBgpLsNlriType::Node,
+                nlri: header.nlri(IsisBgpLsNlriType::Node, |_| {}),
+                attr: node_attr(self, &caps),
+            });
+        }
+
+        for tlv in &self.tlvs {
+            match tlv {
+                IsisTlv::ExtIsReach(v) => {
+                    nlris.extend(v.entries.iter().map(|entry| link(&header, entry)))
+                }
+                IsisTlv::ExtIpReach(v) => {
+                    nlris.extend(v.entries.iter().map(|entry| IsisBgpLsNlri {
+                        typ: IsisBgpLsNlriType::Ipv4Prefix,
+                        nlri: ipv4_prefix(&header, 0, &entry.prefix),
+                        attr: prefix_attr(entry.metric, &entry.subs),
+                    }))
+                }
+                IsisTlv::MtIpReach(v) => {
+                    nlris.extend(v.entries.iter().map(|entry| IsisBgpLsNlri {
+                        typ: IsisBgpLsNlriType::Ipv4Prefix,
+                        nlri: ipv4_prefix(&header, v.mt.id(), &entry.prefix),
+                        attr: prefix_attr(entry.metric, &entry.subs),
+                    }))
+                }
+                IsisTlv::Ipv6Reach(v) => {
+                    nlris.extend(v.entries.iter().map(|entry| IsisBgpLsNlri {
+                        typ: IsisBgpLsNlriType::Ipv6Prefix,
+                        nlri: ipv6_prefix(&header, 0, &entry.prefix),
+                        attr: prefix_attr(entry.metric, &entry.subs),
+                    }))
+                }
+                IsisTlv::MtIpv6Reach(v) => {
+                    nlris.extend(v.entries.iter().map(|entry| IsisBgpLsNlri {
+                        typ: IsisBgpLsNlriType::Ipv6Prefix,
+                        nlri: ipv6_prefix(&header, v.mt.id(), &entry.prefix),
+                        attr: prefix_attr(entry.metric, &entry.subs),
+                    }))
+                }
+                IsisTlv::Srv6(v) => {
+                    for locator in &v.locators {
+                        srv6_locator(&header, v.flags.mt_id(), locator, &mut nlris);
+                    }
+                }
+                _ => {}
+            }
+        }
+        nlris
+    }
+}
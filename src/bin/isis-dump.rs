@@ -0,0 +1,374 @@
+use std::io::Read;
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::Duration;
+
+use isis_packet::*;
+
+const USAGE: &str = "\
+Usage: isis-dump [OPTIONS] [FILE...]
+
+Decode IS-IS packets from pcap/pcapng captures, raw PDU files or hex text.
+Hex input may start at the PDU or at the Ethernet header. A FILE of `-`
+reads from stdin.
+
+Options:
+  -x, --hex <HEX>        Decode a hex string, may be repeated
+  -j, --json             Print packets as JSON, one object per line
+  -d, --detail           Print LSPs as `show isis database detail` does
+  -t, --type <TYPE>      Only PDUs of type l1-hello, l2-hello, l1-lsp, l2-lsp,
+                         l1-csnp, l2-csnp, l1-psnp, l2-psnp, hello, lsp, csnp
+                         or psnp
+  -l, --lsp-id <ID>      Only LSPs with the LSP ID, e.g. 0000.0000.0001.00-00
+  -s, --sys-id <ID>      Only PDUs originated by the system ID
+  -T, --tlv <TLV>        Only PDUs carrying the TLV, by number or name
+  -i, --instance <IID>   Only PDUs of the instance, 0 being the standard one
+  -v, --verify           Check lengths and LSP checksums instead of printing
+  -h, --help             Show this help";
+
+#[derive(Default)]
+struct Options {
+    hex: Vec<String>,
+    files: Vec<String>,
+    json: bool,
+    detail: bool,
+    verify: bool,
+    types: Vec<IsisType>,
+    lsp_id: Option<IsisLspId>,
+    sys_id: Option<IsisSysId>,
+    tlv: Option<IsisTlvType>,
+    instance: Option<u16>,
+}
+
+// One PDU to decode with its capture metadata.
+struct Input {
+    source: String,
+    timestamp: Option<Duration>,
+    src_mac: Option<[u8; 6]>,
+    pdu: Vec<u8>,
+}
+
+fn id<T: FromStr<Err = IsisParseError>>(s: &str) -> Result<T, String> {
+    s.parse().map_err(|e: IsisParseError| e.to_string())
+}
+
+fn pdu_types(s: &str) -> Result<Vec<IsisType>, String> {
+    use IsisType::*;
+    let types = match s.to_lowercase().as_str() {
+        "l1-hello" => vec![L1Hello],
+        "l2-hello" => vec![L2Hello],
+        "p2p-hello" => vec![P2PHello],
+        "l1-lsp" => vec![L1Lsp],
+        "l2-lsp" => vec![L2Lsp],
+        "l1-csnp" => vec![L1Csnp],
+        "l2-csnp" => vec![L2Csnp],
+        "l1-psnp" => vec![L1Psnp],
+        "l2-psnp" => vec![L2Psnp],
+        "hello" => vec![L1Hello, L2Hello, P2PHello],
+        "lsp" => vec![L1Lsp, L2Lsp],
+        "csnp" => vec![L1Csnp, L2Csnp],
+        "psnp" => vec![L1Psnp, L2Psnp],
+        _ => return Err(format!("unknown PDU type: {s}")),
+    };
+    Ok(types)
+}
+
+fn tlv_type(s: &str) -> Result<IsisTlvType, String> {
+    if let Ok(typ) = s.parse::<u8>() {
+        return Ok(typ.into());
+    }
+    serde_json::from_value(serde_json::Value::String(s.to_lowercase()))
+        .map_err(|_| format!("unknown TLV type: {s}"))
+}
+
+fn parse_args() -> Result<Options, String> {
+    let mut opts = Options::default();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or(format!("{name} needs a value"));
+        match arg.as_str() {
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                std::process::exit(0);
+            }
+            "-x" | "--hex" => opts.hex.push(value(&arg)?),
+            "-j" | "--json" => opts.json = true,
+            "-d" | "--detail" => opts.detail = true,
+            "-v" | "--verify" => opts.verify = true,
+            "-t" | "--type" => opts.types.extend(pdu_types(&value(&arg)?)?),
+            "-l" | "--lsp-id" => opts.lsp_id = Some(id(&value(&arg)?)?),
+            "-s" | "--sys-id" => opts.sys_id = Some(id(&value(&arg)?)?),
+            "-T" | "--tlv" => opts.tlv = Some(tlv_type(&value(&arg)?)?),
+            "-i" | "--instance" => {
+                let iid = value(&arg)?;
+                opts.instance = Some(iid.parse().map_err(|_| format!("invalid IID: {iid}"))?);
+            }
+            "-" => opts.files.push(arg),
+            _ if arg.starts_with('-') => return Err(format!("unknown option: {arg}")),
+            _ => opts.files.push(arg),
+        }
+    }
+    if opts.hex.is_empty() && opts.files.is_empty() {
+        return Err("no input".into());
+    }
+    Ok(opts)
+}
+
+// Hex text as produced by Wireshark "copy as hex stream" or hexdumps
+// without offsets.
+fn hex_text(text: &str) -> Option<Vec<u8>> {
+    let digits: String = text
+        .split_whitespace()
+        .map(|word| word.trim_start_matches("0x"))
+        .collect();
+    if digits.is_empty() {
+        return None;
+    }
+    hex::decode(digits).ok()
+}
+
+// Strip the Ethernet and LLC headers when the bytes start with a frame.
+fn strip_frame(bytes: Vec<u8>) -> (Option<[u8; 6]>, Vec<u8>) {
+    if bytes.first() == Some(&0x83) {
+        return (None, bytes);
+    }
+    match isis_frame(LINKTYPE_ETHERNET, &bytes) {
+        Some((src_mac, pdu)) => (src_mac, pdu.to_vec()),
+        None => (None, bytes),
+    }
+}
+
+fn read_file(name: &str, inputs: &mut Vec<Input>) -> Result<(), String> {
+    let data = if name == "-" {
+        let mut data = Vec::new();
+        std::io::stdin()
+            .read_to_end(&mut data)
+            .map_err(|e| format!("{name}: {e}"))?;
+        data
+    } else {
+        std::fs::read(name).map_err(|e| format!("{name}: {e}"))?
+    };
+
+    if let Ok(mut reader) = IsisCaptureReader::new(&data[..]) {
+        let mut index = 0;
+        while let Some(frame) = reader.next_frame().map_err(|e| format!("{name}: {e}"))? {
+            index += 1;
+            if let Some((src_mac, pdu)) = frame.isis() {
+                inputs.push(Input {
+                    source: format!("{name}:{index}"),
+                    timestamp: Some(frame.timestamp),
+                    src_mac,
+                    pdu: pdu.to_vec(),
+                });
+            }
+        }
+        return Ok(());
+    }
+
+    let bytes = match std::str::from_utf8(&data).ok().and_then(hex_text) {
+        Some(bytes) => bytes,
+        None => data,
+    };
+    let (src_mac, pdu) = strip_frame(bytes);
+    inputs.push(Input {
+        source: name.to_string(),
+        timestamp: None,
+        src_mac,
+        pdu,
+    });
+    Ok(())
+}
+
+fn sys_id(packet: &IsisPacket) -> Option<IsisSysId> {
+    use IsisPdu::*;
+    match &packet.pdu {
+        L1Hello(v) | L2Hello(v) => Some(v.source_id.clone()),
+        P2pHello(v) => Some(v.source_id.clone()),
+        L1Lsp(v) | L2Lsp(v) => Some(v.lsp_id.sys_id()),
+        L1Csnp(v) | L2Csnp(v) => Some(v.source_id.clone()),
+        L1Psnp(v) | L2Psnp(v) => Some(v.source_id.clone()),
+        Unknown(_) => None,
+    }
+}
+
+fn matches(opts: &Options, packet: &IsisPacket, pdu: &[u8]) -> bool {
+    if !opts.types.is_empty() && !opts.types.contains(&packet.pdu_type) {
+        return false;
+    }
+    if let Some(lsp_id) = &opts.lsp_id {
+        match &packet.pdu {
+            IsisPdu::L1Lsp(lsp) | IsisPdu::L2Lsp(lsp) if &lsp.lsp_id == lsp_id => {}
+            _ => return false,
+        }
+    }
+    if let Some(id) = &opts.sys_id
+        && sys_id(packet).is_none_or(|sys_id| &sys_id != id)
+    {
+        return false;
+    }
+    if let Some(iid) = opts.instance
+        && !packet.is_instance(iid)
+    {
+        return false;
+    }
+    if let Some(typ) = opts.tlv {
+        let Ok(view) = IsisPacketRef::parse(pdu) else {
+            return false;
+        };
+        if !view.tlvs().flatten().any(|tlv| tlv.typ() == typ) {
+            return false;
+        }
+    }
+    true
+}
+
+fn mac_str(mac: &[u8; 6]) -> String {
+    mac.iter()
+        .map(|b| format!("{b:02x}"))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+// Problems found in the PDU, empty when it is valid.
+fn verify(pdu: &[u8]) -> Vec<String> {
+    let packet = match IsisPacketRef::parse(pdu) {
+        Ok(packet) => packet,
+        Err(e) => return vec![e.to_string()],
+    };
+    let mut errors = Vec::new();
+    if packet.raw().len() < pdu.len() {
+        errors.push(format!(
+            "PDU length {} shorter than {} received bytes",
+            packet.raw().len(),
+            pdu.len()
+        ));
+    }
+    let expected = length_indicator(packet.pdu_type());
+    if packet.length_indicator() != expected {
+        errors.push(format!(
+            "length indicator {} expected {}",
+            packet.length_indicator(),
+            expected
+        ));
+    }
+    if packet.pdu_type().is_lsp() && !is_valid_checksum(packet.raw()) {
+        errors.push("invalid LSP checksum".into());
+    }
+    for tlv in packet.tlvs() {
+        match tlv.and_then(|tlv| tlv.into_owned()) {
+            Ok(_) => {}
+            Err(e) => errors.push(e.to_string()),
+        }
+    }
+    errors
+}
+
+// Hostnames of every LSP in the input, so that neighbors are named even
+// when their LSP comes later.
+fn hostnames(inputs: &[Input]) -> IsisHostnameMap {
+    let mut hostnames = IsisHostnameMap::new();
+    for input in inputs {
+        if let Ok((_, packet)) = parse(&input.pdu)
+            && let IsisPdu::L1Lsp(lsp) | IsisPdu::L2Lsp(lsp) = &packet.pdu
+        {
+            hostnames.insert_lsp(lsp);
+        }
+    }
+    hostnames
+}
+
+fn dump(opts: &Options, input: &Input, hostnames: &IsisHostnameMap) -> bool {
+    let packet = match parse(&input.pdu) {
+        Ok((_, packet)) => packet,
+        Err(e) => {
+            eprintln!("{}: {}", input.source, IsisParseError::from(e));
+            return false;
+        }
+    };
+    if !matches(opts, &packet, &input.pdu) {
+        return true;
+    }
+
+    if opts.verify {
+        let errors = verify(&input.pdu);
+        if errors.is_empty() {
+            println!("{}: {} OK", input.source, packet.pdu_type);
+        }
+        for error in &errors {
+            println!("{}: {} {}", input.source, packet.pdu_type, error);
+        }
+        return errors.is_empty();
+    }
+
+    if opts.json {
+        let json = serde_json::json!({
+            "source": input.source,
+            "timestamp": input.timestamp.map(|ts| ts.as_secs_f64()),
+            "src-mac": input.src_mac.as_ref().map(mac_str),
+            "pdu-type": packet.pdu_type,
+            "pdu": packet.pdu,
+        });
+        println!("{json}");
+    } else {
+        let mut header = input.source.clone();
+        if let Some(ts) = input.timestamp {
+            header += &format!(" {}.{:09}", ts.as_secs(), ts.subsec_nanos());
+        }
+        if let Some(mac) = &input.src_mac {
+            header += &format!(" {}", mac_str(mac));
+        }
+        match &packet.pdu {
+            IsisPdu::L1Lsp(lsp) | IsisPdu::L2Lsp(lsp) if opts.detail => {
+                println!("# {header}\n{}", lsp.detail(Some(hostnames)));
+            }
+            _ => println!("# {header}\n{packet}\n"),
+        }
+    }
+    true
+}
+
+fn main() -> ExitCode {
+    let opts = match parse_args() {
+        Ok(opts) => opts,
+        Err(e) => {
+            eprintln!("isis-dump: {e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut inputs = Vec::new();
+    for (index, hex) in opts.hex.iter().enumerate() {
+        let Some(bytes) = hex_text(hex) else {
+            eprintln!("isis-dump: invalid hex string {}", index + 1);
+            return ExitCode::from(2);
+        };
+        let (src_mac, pdu) = strip_frame(bytes);
+        inputs.push(Input {
+            source: format!("hex:{}", index + 1),
+            timestamp: None,
+            src_mac,
+            pdu,
+        });
+    }
+    for file in &opts.files {
+        if let Err(e) = read_file(file, &mut inputs) {
+            eprintln!("isis-dump: {e}");
+            return ExitCode::from(2);
+        }
+    }
+
+    let hostnames = if opts.detail {
+        hostnames(&inputs)
+    } else {
+        IsisHostnameMap::new()
+    };
+    let mut ok = true;
+    for input in &inputs {
+        ok &= dump(&opts, input, &hostnames);
+    }
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{IsisLsp, IsisLspSizeError, IsisPacket, IsisPdu, IsisTlv};
+
+impl IsisLsp {
+    /// Originating LSP buffer size, carried in fragment 0.
+    pub fn lsp_buffer_size(&self) -> Option<u16> {
+        self.tlvs.iter().find_map(|tlv| match tlv {
+            IsisTlv::LspBufferSize(v) => Some(v.size),
+            _ => None,
+        })
+    }
+}
+
+impl IsisPacket {
+    /// Checks a received LSP against the originating buffer size of its
+    /// source and the local `mtu`, the largest PDU the link carries. The
+    /// buffer size in the LSP is used when present, `buffer_size` from
+    /// fragment 0 of the source otherwise. Other PDUs always pass.
+    pub fn check_lsp_size(
+        &self,
+        buffer_size: Option<u16>,
+        mtu: usize,
+    ) -> Result<(), IsisLspSizeError> {
+        let (IsisPdu::L1Lsp(lsp) | IsisPdu::L2Lsp(lsp)) = &self.pdu else {
+            return Ok(());
+        };
+        let len = lsp.pdu_len as usize;
+        let buffer_size = lsp.lsp_buffer_size().or(buffer_size);
+        if let Some(buffer_size) = buffer_size
+            && len > buffer_size as usize
+        {
+            return Err(IsisLspSizeError::ExceedsBufferSize { len, buffer_size });
+        }
+        if len > mtu {
+            return Err(IsisLspSizeError::ExceedsMtu { len, mtu });
+        }
+        // Later LSPs of the source may grow up to its buffer size.
+        if let Some(buffer_size) = buffer_size
+            && buffer_size as usize > mtu
+        {
+            return Err(IsisLspSizeError::BufferSizeExceedsMtu { buffer_size, mtu });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,728 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+use bytes::{BufMut, BytesMut};
+
+use crate::{IsisCaptureError, IsisPacket, IsisPacketRef, IsisType};
+
+pub const LINKTYPE_ETHERNET: u16 = 1;
+pub const LINKTYPE_RAW: u16 = 101;
+pub const LINKTYPE_LINUX_SLL: u16 = 113;
+pub const LINKTYPE_IPV4: u16 = 228;
+pub const LINKTYPE_IPV6: u16 = 229;
+pub const LINKTYPE_LINUX_SLL2: u16 = 276;
+
+const PCAP_MAGIC_USEC: u32 = 0xa1b2c3d4;
+const PCAP_MAGIC_NSEC: u32 = 0xa1b23c4d;
+const PCAP_HEADER_LEN: usize = 24;
+const PCAP_RECORD_LEN: usize = 16;
+
+const PCAPNG_SHB: u32 = 0x0a0d0d0a;
+const PCAPNG_IDB: u32 = 0x00000001;
+const PCAPNG_SPB: u32 = 0x00000003;
+const PCAPNG_EPB: u32 = 0x00000006;
+const PCAPNG_BYTE_ORDER_MAGIC: u32 = 0x1a2b3c4d;
+const PCAPNG_OPT_END: u16 = 0;
+const PCAPNG_OPT_IF_TSRESOL: u16 = 9;
+
+// Blocks larger than this are treated as corruption rather than allocated.
+const MAX_BLOCK_LEN: usize = 16 * 1024 * 1024;
+
+const ETHERTYPE_IPV4: u16 = 0x0800;
+const ETHERTYPE_IPV6: u16 = 0x86dd;
+const ETHERTYPE_VLAN: u16 = 0x8100;
+const ETHERTYPE_QINQ: u16 = 0x88a8;
+const ETHERTYPE_QINQ_OLD: u16 = 0x9100;
+// Largest value of the 802.3 length field, anything above is an ethertype.
+const ETHER_MAX_LEN: u16 = 1500;
+// Linux cooked capture protocol for 802.2 LLC frames.
+const SLL_PROTO_802_2: u16 = 0x0004;
+const ARPHRD_ETHER: u16 = 1;
+
+// Minimum Ethernet frame length without FCS.
+const ETHER_MIN_LEN: usize = 60;
+const SNAPLEN: u32 = 65535;
+
+pub const ALL_L1_ISS: [u8; 6] = [0x01, 0x80, 0xc2, 0x00, 0x00, 0x14];
+pub const ALL_L2_ISS: [u8; 6] = [0x01, 0x80, 0xc2, 0x00, 0x00, 0x15];
+pub const ALL_ISS: [u8; 6] = [0x09, 0x00, 0x2b, 0x00, 0x00, 0x05];
+
+const LLC_ISIS: [u8; 3] = [0xfe, 0xfe, 0x03];
+const IPPROTO_GRE: u8 = 47;
+const GRE_PROTO_OSI: u16 = 0x00fe;
+
+/// Frame read from a capture file, borrowed from the reader's buffer.
+#[derive(Debug, Clone, Copy)]
+pub struct IsisCaptureFrame<'a> {
+    pub timestamp: Duration,
+    pub linktype: u16,
+    pub data: &'a [u8],
+}
+
+impl<'a> IsisCaptureFrame<'a> {
+    /// Link-layer source MAC and IS-IS PDU carried in the frame, if any.
+    pub fn isis(&self) -> Option<(Option<[u8; 6]>, &'a [u8])> {
+        isis_frame(self.linktype, self.data)
+    }
+}
+
+/// IS-IS packet decoded from a capture.
+#[derive(Debug, Clone)]
+pub struct IsisCapturePacket {
+    pub timestamp: Duration,
+    pub src_mac: Option<[u8; 6]>,
+    pub packet: IsisPacket,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TsResol {
+    Pow10(u8),
+    Pow2(u8),
+}
+
+impl TsResol {
+    fn duration(&self, ts: u64) -> Duration {
+        let (secs, nanos) = match *self {
+            TsResol::Pow10(exp) => {
+                let unit = 10u128.pow(exp as u32);
+                let ts = ts as u128;
+                (ts / unit, (ts % unit) * 1_000_000_000 / unit)
+            }
+            TsResol::Pow2(exp) => {
+                let ts = ts as u128;
+                let mask = (1u128 << exp) - 1;
+                (ts >> exp, ((ts & mask) * 1_000_000_000) >> exp)
+            }
+        };
+        Duration::new(secs as u64, nanos as u32)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Interface {
+    linktype: u16,
+    tsresol: TsResol,
+}
+
+#[derive(Debug, Clone)]
+enum Format {
+    Pcap {
+        big: bool,
+        tsresol: TsResol,
+        linktype: u16,
+    },
+    Pcapng {
+        big: bool,
+        interfaces: Vec<Interface>,
+    },
+}
+
+fn read_u16(big: bool, buf: &[u8]) -> u16 {
+    if big {
+        BigEndian::read_u16(buf)
+    } else {
+        LittleEndian::read_u16(buf)
+    }
+}
+
+fn read_u32(big: bool, buf: &[u8]) -> u32 {
+    if big {
+        BigEndian::read_u32(buf)
+    } else {
+        LittleEndian::read_u32(buf)
+    }
+}
+
+/// pcap and pcapng reader. Frames which do not carry IS-IS are skipped by
+/// the iterator, use `next_frame()` to see every frame.
+#[derive(Debug)]
+pub struct IsisCaptureReader<R> {
+    reader: R,
+    format: Format,
+    buf: Vec<u8>,
+}
+
+impl IsisCaptureReader<BufReader<File>> {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, IsisCaptureError> {
+        Self::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: Read> IsisCaptureReader<R> {
+    /// Read the file header, the format is detected from the magic number.
+    pub fn new(mut reader: R) -> Result<Self, IsisCaptureError> {
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+        let format = match (BigEndian::read_u32(&magic), LittleEndian::read_u32(&magic)) {
+            (PCAPNG_SHB, _) => Self::pcapng_header(&mut reader)?,
+            (PCAP_MAGIC_USEC, _)
+            | (PCAP_MAGIC_NSEC, _)
+            | (_, PCAP_MAGIC_USEC)
+            | (_, PCAP_MAGIC_NSEC) => Self::pcap_header(&mut reader, magic)?,
+            _ => {
+                return Err(IsisCaptureError::invalid_format(format!(
+                    "unknown magic {:02x?}",
+                    magic
+                )));
+            }
+        };
+        Ok(Self {
+            reader,
+            format,
+            buf: Vec::new(),
+        })
+    }
+
+    fn pcap_header(reader: &mut R, magic: [u8; 4]) -> Result<Format, IsisCaptureError> {
+        let mut header = [0u8; PCAP_HEADER_LEN];
+        header[..4].copy_from_slice(&magic);
+        reader.read_exact(&mut header[4..])?;
+        let big = matches!(
+            BigEndian::read_u32(&magic),
+            PCAP_MAGIC_USEC | PCAP_MAGIC_NSEC
+        );
+        let tsresol = if read_u32(big, &magic) == PCAP_MAGIC_NSEC {
+            TsResol::Pow10(9)
+        } else {
+            TsResol::Pow10(6)
+        };
+        // Upper bits of the link type carry FCS information.
+        let linktype = read_u32(big, &header[20..]) as u16;
+        Ok(Format::Pcap {
+            big,
+            tsresol,
+            linktype,
+        })
+    }
+
+    fn pcapng_header(reader: &mut R) -> Result<Format, IsisCaptureError> {
+        let mut buf = Vec::new();
+        let big = Self::pcapng_section(reader, &mut buf)?;
+        Ok(Format::Pcapng {
+            big,
+            interfaces: Vec::new(),
+        })
+    }
+
+    // Rest of a Section Header Block after its block type. Returns the byte
+    // order of the section.
+    fn pcapng_section(reader: &mut R, buf: &mut Vec<u8>) -> Result<bool, IsisCaptureError> {
+        let mut head = [0u8; 8];
+        reader.read_exact(&mut head)?;
+        let big = match BigEndian::read_u32(&head[4..]) {
+            PCAPNG_BYTE_ORDER_MAGIC => true,
+            _ if LittleEndian::read_u32(&head[4..]) == PCAPNG_BYTE_ORDER_MAGIC => false,
+            _ => return Err(IsisCaptureError::invalid_format("bad byte-order magic")),
+        };
+        let len = read_u32(big, &head) as usize;
+        if !(12..=MAX_BLOCK_LEN).contains(&len) || !len.is_multiple_of(4) {
+            return Err(IsisCaptureError::invalid_format(format!(
+                "bad section header length {len}"
+            )));
+        }
+        buf.resize(len - 12, 0);
+        reader.read_exact(buf)?;
+        Ok(big)
+    }
+
+    // Fill the buffer with exactly `len` bytes. A clean end of file before
+    // the first byte returns false.
+    fn fill(&mut self, len: usize) -> Result<bool, IsisCaptureError> {
+        self.buf.resize(len, 0);
+        let mut read = 0;
+        while read < len {
+            match self.reader.read(&mut self.buf[read..]) {
+                Ok(0) if read == 0 => return Ok(false),
+                Ok(0) => return Err(std::io::Error::from(ErrorKind::UnexpectedEof).into()),
+                Ok(n) => read += n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(true)
+    }
+
+    /// Next frame of any kind, `None` at the end of the capture.
+    pub fn next_frame(&mut self) -> Result<Option<IsisCaptureFrame<'_>>, IsisCaptureError> {
+        match self.format {
+            Format::Pcap { .. } => self.pcap_frame(),
+            Format::Pcapng { .. } => self.pcapng_frame(),
+        }
+    }
+
+    fn pcap_frame(&mut self) -> Result<Option<IsisCaptureFrame<'_>>, IsisCaptureError> {
+        let Format::Pcap {
+            big,
+            tsresol,
+            linktype,
+        } = self.format
+        else {
+            return Ok(None);
+        };
+        if !self.fill(PCAP_RECORD_LEN)? {
+            return Ok(None);
+        }
+        let secs = read_u32(big, &self.buf[0..]) as u64;
+        let frac = read_u32(big, &self.buf[4..]) as u64;
+        let caplen = read_u32(big, &self.buf[8..]) as usize;
+        if caplen > MAX_BLOCK_LEN {
+            return Err(IsisCaptureError::invalid_format(format!(
+                "bad record length {caplen}"
+            )));
+        }
+        if !self.fill(caplen)? {
+            return Err(std::io::Error::from(ErrorKind::UnexpectedEof).into());
+        }
+        let timestamp = Duration::from_secs(secs) + tsresol.duration(frac);
+        Ok(Some(IsisCaptureFrame {
+            timestamp,
+            linktype,
+            data: &self.buf,
+        }))
+    }
+
+    fn pcapng_frame(&mut self) -> Result<Option<IsisCaptureFrame<'_>>, IsisCaptureError> {
+        loop {
+            let Format::Pcapng { big, .. } = self.format else {
+                return Ok(None);
+            };
+            if !self.fill(4)? {
+                return Ok(None);
+            }
+            let block_type = read_u32(big, &self.buf);
+            if block_type == PCAPNG_SHB {
+                let big = Self::pcapng_section(&mut self.reader, &mut self.buf)?;
+                self.format = Format::Pcapng {
+                    big,
+                    interfaces: Vec::new(),
+                };
+                continue;
+            }
+            if !self.fill(4)? {
+                return Err(std::io::Error::from(ErrorKind::UnexpectedEof).into());
+            }
+            let len = read_u32(big, &self.buf) as usize;
+            if !(12..=MAX_BLOCK_LEN).contains(&len) || !len.is_multiple_of(4) {
+                return Err(IsisCaptureError::invalid_format(format!(
+                    "bad block length {len}"
+                )));
+            }
+            if !self.fill(len - 8)? {
+                return Err(std::io::Error::from(ErrorKind::UnexpectedEof).into());
+            }
+            // Body without the trailing block length.
+            let body_len = len - 12;
+            match block_type {
+                PCAPNG_IDB => self.pcapng_interface(big, body_len)?,
+                PCAPNG_EPB => return self.pcapng_enhanced(big, body_len).map(Some),
+                PCAPNG_SPB => return self.pcapng_simple(big, body_len).map(Some),
+                _ => {}
+            }
+        }
+    }
+
+    fn pcapng_interface(&mut self, big: bool, body_len: usize) -> Result<(), IsisCaptureError> {
+        if body_len < 8 {
+            return Err(IsisCaptureError::invalid_format("short interface block"));
+        }
+        let body = &self.buf[..body_len];
+        let linktype = read_u16(big, body);
+        let mut tsresol = TsResol::Pow10(6);
+        let mut opts = &body[8..];
+        while opts.len() >= 4 {
+            let code = read_u16(big, opts);
+            let len = read_u16(big, &opts[2..]) as usize;
+            if code == PCAPNG_OPT_END {
+                break;
+            }
+            let Some(value) = opts.get(4..4 + len) else {
+                break;
+            };
+            if code == PCAPNG_OPT_IF_TSRESOL && len == 1 {
+                // Timestamps are 64-bit, finer units cannot be converted.
+                tsresol = match value[0] {
+                    v if v & 0x80 != 0 && v & 0x7f <= 63 => TsResol::Pow2(v & 0x7f),
+                    v if v <= 19 => TsResol::Pow10(v),
+                    v => {
+                        return Err(IsisCaptureError::invalid_format(format!(
+                            "unsupported timestamp resolution 0x{v:02x}"
+                        )));
+                    }
+                };
+            }
+            opts = opts.get(4 + len.next_multiple_of(4)..).unwrap_or(&[]);
+        }
+        if let Format::Pcapng { interfaces, .. } = &mut self.format {
+            interfaces.push(Interface { linktype, tsresol });
+        }
+        Ok(())
+    }
+
+    fn interface(&self, id: usize) -> Result<Interface, IsisCaptureError> {
+        match &self.format {
+            Format::Pcapng { interfaces, .. } => interfaces.get(id).cloned(),
+            _ => None,
+        }
+        .ok_or_else(|| IsisCaptureError::invalid_format(format!("unknown interface {id}")))
+    }
+
+    fn pcapng_enhanced(
+        &self,
+        big: bool,
+        body_len: usize,
+    ) -> Result<IsisCaptureFrame<'_>, IsisCaptureError> {
+        if body_len < 20 {
+            return Err(IsisCaptureError::invalid_format("short packet block"));
+        }
+        let body = &self.buf[..body_len];
+        let interface = self.interface(read_u32(big, body) as usize)?;
+        let ts_high = read_u32(big, &body[4..]) as u64;
+        let ts_low = read_u32(big, &body[8..]) as u64;
+        let caplen = read_u32(big, &body[12..]) as usize;
+        let data = body
+            .get(20..20 + caplen)
+            .ok_or_else(|| IsisCaptureError::invalid_format("bad captured length"))?;
+        Ok(IsisCaptureFrame {
+            timestamp: interface.tsresol.duration((ts_high << 32) | ts_low),
+            linktype: interface.linktype,
+            data,
+        })
+    }
+
+    // Simple Packet Blocks carry no timestamp.
+    fn pcapng_simple(
+        &self,
+        big: bool,
+        body_len: usize,
+    ) -> Result<IsisCaptureFrame<'_>, IsisCaptureError> {
+        if body_len < 4 {
+            return Err(IsisCaptureError::invalid_format("short packet block"));
+        }
+        let body = &self.buf[..body_len];
+        let interface = self.interface(0)?;
+        let len = (read_u32(big, body) as usize).min(body_len - 4);
+        Ok(IsisCaptureFrame {
+            timestamp: Duration::ZERO,
+            linktype: interface.linktype,
+            data: &body[4..4 + len],
+        })
+    }
+}
+
+impl<R: Read> Iterator for IsisCaptureReader<R> {
+    type Item = Result<IsisCapturePacket, IsisCaptureError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let frame = match self.next_frame() {
+                Ok(Some(frame)) => frame,
+                Ok(None) => return None,
+                Err(err) => return Some(Err(err)),
+            };
+            let Some((src_mac, pdu)) = frame.isis() else {
+                continue;
+            };
+            let timestamp = frame.timestamp;
+            let packet = IsisPacketRef::parse(pdu).and_then(|packet| packet.into_owned());
+            return Some(
+                packet
+                    .map(|packet| IsisCapturePacket {
+                        timestamp,
+                        src_mac,
+                        packet,
+                    })
+                    .map_err(IsisCaptureError::from),
+            );
+        }
+    }
+}
+
+/// Locate the IS-IS PDU in a captured frame of the given link type. Returns
+/// the link-layer source MAC when the link type has one.
+pub fn isis_frame(linktype: u16, frame: &[u8]) -> Option<(Option<[u8; 6]>, &[u8])> {
+    match linktype {
+        LINKTYPE_ETHERNET => ether(frame),
+        LINKTYPE_LINUX_SLL => sll(frame),
+        LINKTYPE_LINUX_SLL2 => sll2(frame),
+        LINKTYPE_RAW => ip(frame).map(|pdu| (None, pdu)),
+        LINKTYPE_IPV4 => ipv4(frame).map(|pdu| (None, pdu)),
+        LINKTYPE_IPV6 => ipv6(frame).map(|pdu| (None, pdu)),
+        _ => None,
+    }
+}
+
+fn mac(addr: &[u8]) -> [u8; 6] {
+    let mut mac = [0u8; 6];
+    mac.copy_from_slice(&addr[..6]);
+    mac
+}
+
+fn ether(frame: &[u8]) -> Option<(Option<[u8; 6]>, &[u8])> {
+    if frame.len() < 14 {
+        return None;
+    }
+    let src = mac(&frame[6..12]);
+    let mut typ = BigEndian::read_u16(&frame[12..]);
+    let mut rest = &frame[14..];
+    while matches!(typ, ETHERTYPE_VLAN | ETHERTYPE_QINQ | ETHERTYPE_QINQ_OLD) {
+        if rest.len() < 4 {
+            return None;
+        }
+        typ = BigEndian::read_u16(&rest[2..]);
+        rest = &rest[4..];
+    }
+    let pdu = match typ {
+        0..=ETHER_MAX_LEN => llc(&rest[..rest.len().min(typ as usize)]),
+        _ => ethertype(typ, rest),
+    }?;
+    Some((Some(src), pdu))
+}
+
+fn sll(frame: &[u8]) -> Option<(Option<[u8; 6]>, &[u8])> {
+    if frame.len() < 16 {
+        return None;
+    }
+    let arphrd = BigEndian::read_u16(&frame[2..]);
+    let addr_len = BigEndian::read_u16(&frame[4..]);
+    let src = (arphrd == ARPHRD_ETHER && addr_len == 6).then(|| mac(&frame[6..12]));
+    let proto = BigEndian::read_u16(&frame[14..]);
+    Some((src, sll_proto(proto, &frame[16..])?))
+}
+
+fn sll2(frame: &[u8]) -> Option<(Option<[u8; 6]>, &[u8])> {
+    if frame.len() < 20 {
+        return None;
+    }
+    let proto = BigEndian::read_u16(frame);
+    let arphrd = BigEndian::read_u16(&frame[8..]);
+    let addr_len = frame[11];
+    let src = (arphrd == ARPHRD_ETHER && addr_len == 6).then(|| mac(&frame[12..18]));
+    Some((src, sll_proto(proto, &frame[20..])?))
+}
+
+fn sll_proto(proto: u16, rest: &[u8]) -> Option<&[u8]> {
+    match proto {
+        SLL_PROTO_802_2 => llc(rest),
+        _ => ethertype(proto, rest),
+    }
+}
+
+fn ethertype(typ: u16, rest: &[u8]) -> Option<&[u8]> {
+    match typ {
+        ETHERTYPE_IPV4 => ipv4(rest),
+        ETHERTYPE_IPV6 => ipv6(rest),
+        _ => None,
+    }
+}
+
+fn llc(payload: &[u8]) -> Option<&[u8]> {
+    payload.strip_prefix(&LLC_ISIS[..])
+}
+
+fn ip(packet: &[u8]) -> Option<&[u8]> {
+    match packet.first()? >> 4 {
+        4 => ipv4(packet),
+        6 => ipv6(packet),
+        _ => None,
+    }
+}
+
+fn ipv4(packet: &[u8]) -> Option<&[u8]> {
+    if packet.len() < 20 || packet[0] >> 4 != 4 || packet[9] != IPPROTO_GRE {
+        return None;
+    }
+    let ihl = (packet[0] & 0x0f) as usize * 4;
+    let total = (BigEndian::read_u16(&packet[2..]) as usize).min(packet.len());
+    gre(packet.get(ihl..total)?)
+}
+
+fn ipv6(packet: &[u8]) -> Option<&[u8]> {
+    if packet.len() < 40 || packet[0] >> 4 != 6 || packet[6] != IPPROTO_GRE {
+        return None;
+    }
+    let payload_len = BigEndian::read_u16(&packet[4..]) as usize;
+    let end = (40 + payload_len).min(packet.len());
+    gre(&packet[40..end])
+}
+
+fn gre(packet: &[u8]) -> Option<&[u8]> {
+    if packet.len() < 4 {
+        return None;
+    }
+    let flags = packet[0];
+    let version = packet[1] & 0x07;
+    if version != 0 || BigEndian::read_u16(&packet[2..]) != GRE_PROTO_OSI {
+        return None;
+    }
+    let mut len = 4;
+    // Checksum or routing present.
+    if flags & 0xc0 != 0 {
+        len += 4;
+    }
+    // Key present.
+    if flags & 0x20 != 0 {
+        len += 4;
+    }
+    // Sequence number present.
+    if flags & 0x10 != 0 {
+        len += 4;
+    }
+    packet.get(len..)
+}
+
+/// Multicast destination MAC for the PDU type.
+pub fn isis_dst_mac(pdu_type: IsisType) -> [u8; 6] {
+    use IsisType::*;
+    match pdu_type {
+        L1Hello | L1Lsp | L1Csnp | L1Psnp => ALL_L1_ISS,
+        L2Hello | L2Lsp | L2Csnp | L2Psnp => ALL_L2_ISS,
+        _ => ALL_ISS,
+    }
+}
+
+impl IsisPacket {
+    /// Emit the PDU in an 802.3 frame with LLC header, padded to the
+    /// Ethernet minimum.
+    pub fn emit_frame(&self, src_mac: [u8; 6], buf: &mut BytesMut) {
+        // emit() computes the LSP checksum on offsets from the buffer start.
+        let mut pdu = BytesMut::new();
+        self.emit(&mut pdu);
+
+        let start = buf.len();
+        buf.put(&isis_dst_mac(self.pdu_type)[..]);
+        buf.put(&src_mac[..]);
+        buf.put_u16((LLC_ISIS.len() + pdu.len()) as u16);
+        buf.put(&LLC_ISIS[..]);
+        buf.put(&pdu[..]);
+        if buf.len() - start < ETHER_MIN_LEN {
+            buf.resize(start + ETHER_MIN_LEN, 0);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IsisCaptureFormat {
+    Pcap,
+    Pcapng,
+}
+
+/// Writes Ethernet frames to pcap or pcapng with nanosecond timestamps.
+#[derive(Debug)]
+pub struct IsisCaptureWriter<W: Write> {
+    writer: W,
+    format: IsisCaptureFormat,
+}
+
+impl IsisCaptureWriter<BufWriter<File>> {
+    pub fn create<P: AsRef<Path>>(
+        path: P,
+        format: IsisCaptureFormat,
+    ) -> Result<Self, IsisCaptureError> {
+        Self::new(BufWriter::new(File::create(path)?), format)
+    }
+}
+
+impl<W: Write> IsisCaptureWriter<W> {
+    /// Write the file header.
+    pub fn new(mut writer: W, format: IsisCaptureFormat) -> Result<Self, IsisCaptureError> {
+        let mut buf = BytesMut::new();
+        match format {
+            IsisCaptureFormat::Pcap => {
+                buf.put_u32_le(PCAP_MAGIC_NSEC);
+                buf.put_u16_le(2);
+                buf.put_u16_le(4);
+                buf.put_i32_le(0);
+                buf.put_u32_le(0);
+                buf.put_u32_le(SNAPLEN);
+                buf.put_u32_le(LINKTYPE_ETHERNET as u32);
+            }
+            IsisCaptureFormat::Pcapng => {
+                let mut shb = BytesMut::new();
+                shb.put_u32_le(PCAPNG_BYTE_ORDER_MAGIC);
+                shb.put_u16_le(1);
+                shb.put_u16_le(0);
+                // Section length not specified.
+                shb.put_i64_le(-1);
+                pcapng_block(&mut buf, PCAPNG_SHB, &shb);
+
+                let mut idb = BytesMut::new();
+                idb.put_u16_le(LINKTYPE_ETHERNET);
+                idb.put_u16_le(0);
+                idb.put_u32_le(SNAPLEN);
+                idb.put_u16_le(PCAPNG_OPT_IF_TSRESOL);
+                idb.put_u16_le(1);
+                idb.put_u32_le(9);
+                idb.put_u16_le(PCAPNG_OPT_END);
+                idb.put_u16_le(0);
+                pcapng_block(&mut buf, PCAPNG_IDB, &idb);
+            }
+        }
+        writer.write_all(&buf)?;
+        Ok(Self { writer, format })
+    }
+
+    /// Write an Ethernet frame captured at `timestamp` since the UNIX epoch.
+    pub fn write_frame(
+        &mut self,
+        timestamp: Duration,
+        frame: &[u8],
+    ) -> Result<(), IsisCaptureError> {
+        let mut buf = BytesMut::new();
+        match self.format {
+            IsisCaptureFormat::Pcap => {
+                buf.put_u32_le(timestamp.as_secs() as u32);
+                buf.put_u32_le(timestamp.subsec_nanos());
+                buf.put_u32_le(frame.len() as u32);
+                buf.put_u32_le(frame.len() as u32);
+                buf.put(frame);
+            }
+            IsisCaptureFormat::Pcapng => {
+                let ts = timestamp.as_nanos() as u64;
+                let mut epb = BytesMut::new();
+                epb.put_u32_le(0);
+                epb.put_u32_le((ts >> 32) as u32);
+                epb.put_u32_le(ts as u32);
+                epb.put_u32_le(frame.len() as u32);
+                epb.put_u32_le(frame.len() as u32);
+                epb.put(frame);
+                pcapng_block(&mut buf, PCAPNG_EPB, &epb);
+            }
+        }
+        self.writer.write_all(&buf)?;
+        Ok(())
+    }
+
+    /// Frame the packet with `emit_frame()` and write it.
+    pub fn write_packet(
+        &mut self,
+        timestamp: Duration,
+        src_mac: [u8; 6],
+        packet: &IsisPacket,
+    ) -> Result<(), IsisCaptureError> {
+        let mut frame = BytesMut::new();
+        packet.emit_frame(src_mac, &mut frame);
+        self.write_frame(timestamp, &frame)
+    }
+
+    pub fn flush(&mut self) -> Result<(), IsisCaptureError> {
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+// Block with its body padded to 32 bits and the trailing length.
+fn pcapng_block(buf: &mut BytesMut, block_type: u32, body: &[u8]) {
+    let len = 12 + body.len().next_multiple_of(4);
+    buf.put_u32_le(block_type);
+    buf.put_u32_le(len as u32);
+    buf.put(body);
+    buf.put_bytes(0, len - 12 - body.len());
+    buf.put_u32_le(len as u32);
+}
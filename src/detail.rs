@@ -0,0 +1,560 @@
+use std::fmt::{Display, Formatter, Result};
+
+use itertools::Itertools;
+
+use crate::prefix::{IsisSub2Tlv, MultiTopologyId};
+use crate::sub::{cap, neigh, prefix};
+use crate::{
+    AdjSidFlags, Algo, IsisHostnameMap, IsisLsdb, IsisLsp, IsisNamed, IsisNeighborId, IsisProto,
+    IsisSysId, IsisTlv, IsisTlvAreaAddr, IsisTlvExtIpReachEntry, IsisTlvIpv6ReachEntry,
+    SidLabelTlv, SidLabelValue,
+};
+
+const LSDB_HEADER: &str = "LSP ID                  PduLen  SeqNumber   Chksum  Holdtime  ATT/P/OL";
+
+// Top-level TLVs, their sub-TLVs and sub-sub-TLVs.
+const TLV_INDENT: usize = 2;
+const SUB_INDENT: usize = 4;
+const SUB2_INDENT: usize = 6;
+
+/// LSP in the layout of FRR `show isis database detail`: the header line
+/// followed by every TLV, with system IDs shown as hostnames when known.
+#[derive(Debug, Clone, Copy)]
+pub struct IsisLspDetail<'a> {
+    pub lsp: &'a IsisLsp,
+    pub hostnames: Option<&'a IsisHostnameMap>,
+    /// Self-originated LSP, marked with `*`.
+    pub own: bool,
+}
+
+/// Database of one level in the layout of FRR `show isis database detail`.
+#[derive(Debug, Clone, Copy)]
+pub struct IsisLsdbDetail<'a> {
+    pub lsdb: &'a IsisLsdb,
+    pub level: u8,
+    pub hostnames: Option<&'a IsisHostnameMap>,
+    /// System ID of the local router, its LSPs are marked with `*`.
+    pub own: Option<&'a IsisSysId>,
+}
+
+impl IsisLsp {
+    pub fn detail<'a>(&'a self, hostnames: Option<&'a IsisHostnameMap>) -> IsisLspDetail<'a> {
+        IsisLspDetail {
+            lsp: self,
+            hostnames,
+            own: false,
+        }
+    }
+}
+
+impl IsisLsdb {
+    pub fn detail<'a>(
+        &'a self,
+        level: u8,
+        hostnames: Option<&'a IsisHostnameMap>,
+    ) -> IsisLsdbDetail<'a> {
+        IsisLsdbDetail {
+            lsdb: self,
+            level,
+            hostnames,
+            own: None,
+        }
+    }
+}
+
+impl Display for IsisLsdbDetail<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        writeln!(f, "IS-IS Level-{} link-state database:", self.level)?;
+        writeln!(f, "{}", LSDB_HEADER)?;
+        for lsp in self.lsdb.iter() {
+            let detail = IsisLspDetail {
+                lsp,
+                hostnames: self.hostnames,
+                own: self.own.is_some_and(|id| *id == lsp.lsp_id.sys_id()),
+            };
+            writeln!(f, "{}", detail)?;
+        }
+        writeln!(f, "    {} LSPs", self.lsdb.len())
+    }
+}
+
+impl Display for IsisLspDetail<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let lsp = self.lsp;
+        let lsp_id = IsisNamed::new(&lsp.lsp_id, self.hostnames).to_string();
+        writeln!(
+            f,
+            "{:<21}{}  {:>5}   0x{:08x}  0x{:04x}   {:>5}    {}/{}/{}",
+            lsp_id,
+            if self.own { '*' } else { ' ' },
+            lsp.pdu_len,
+            lsp.seq_number,
+            lsp.checksum,
+            lsp.hold_time,
+            (lsp.types.att_bits() != 0) as u8,
+            lsp.types.p_bits() as u8,
+            lsp.types.ol_bits() as u8,
+        )?;
+        let mut w = DetailWriter {
+            f,
+            hostnames: self.hostnames,
+        };
+        lsp.tlvs.iter().try_for_each(|tlv| w.tlv(tlv))
+    }
+}
+
+fn area_str(area: &IsisTlvAreaAddr) -> String {
+    let Some((afi, rest)) = area.area_addr.split_first() else {
+        return String::new();
+    };
+    let mut s = format!("{:02x}", afi);
+    for chunk in rest.chunks(2) {
+        s.push('.');
+        chunk.iter().for_each(|b| s.push_str(&format!("{:02x}", b)));
+    }
+    s
+}
+
+fn algo_str(algo: Algo) -> String {
+    match algo {
+        Algo::Spf => "SPF".into(),
+        Algo::StrictSpf => "Strict SPF".into(),
+        Algo::FlexAlgo(v) => format!("Flex-Algo {}", v),
+        Algo::Unknown(_) => "Unknown".into(),
+    }
+}
+
+fn mt_str(mt: &MultiTopologyId) -> String {
+    match mt.id() {
+        0 => "ipv4-unicast".into(),
+        1 => "ipv4-mgmt".into(),
+        2 => "ipv6-unicast".into(),
+        3 => "ipv4-multicast".into(),
+        4 => "ipv6-multicast".into(),
+        5 => "ipv6-mgmt".into(),
+        3996 => "ipv6-dstsrc".into(),
+        id => format!("mt-{}", id),
+    }
+}
+
+fn bit(flag: bool) -> char {
+    if flag { '1' } else { '0' }
+}
+
+fn adj_flags(flags: &AdjSidFlags) -> String {
+    format!(
+        "F:{} B:{}, V:{}, L:{}, S:{}, P:{}",
+        bit(flags.f_flag()),
+        bit(flags.b_flag()),
+        bit(flags.v_flag()),
+        bit(flags.l_flag()),
+        bit(flags.s_flag()),
+        bit(flags.p_flag())
+    )
+}
+
+// SRv6 End.X SID flags B, S and P from the top bit down.
+fn srv6_flags(flags: u8) -> String {
+    format!(
+        "B:{}, S:{}, P:{}",
+        bit(flags & 0x80 != 0),
+        bit(flags & 0x40 != 0),
+        bit(flags & 0x20 != 0)
+    )
+}
+
+fn sid_str(sid: &SidLabelValue) -> String {
+    match sid {
+        SidLabelValue::Label(v) => format!("Label: {}", v),
+        SidLabelValue::Index(v) => format!("Index: {}", v),
+    }
+}
+
+fn sid_label_value(sid: &SidLabelTlv) -> u32 {
+    match sid {
+        SidLabelTlv::Label(v) | SidLabelTlv::Index(v) => *v,
+    }
+}
+
+struct DetailWriter<'a, 'b> {
+    f: &'a mut Formatter<'b>,
+    hostnames: Option<&'a IsisHostnameMap>,
+}
+
+impl DetailWriter<'_, '_> {
+    fn line(&mut self, indent: usize, text: impl Display) -> Result {
+        writeln!(self.f, "{:indent$}{}", "", text, indent = indent)
+    }
+
+    // Existing Display output of items without a counterpart in FRR, one
+    // line each at `indent`.
+    fn text(&mut self, indent: usize, item: impl Display) -> Result {
+        item.to_string()
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .try_for_each(|line| self.line(indent, line.trim_start()))
+    }
+
+    fn neighbor_id(&self, id: &IsisNeighborId) -> String {
+        IsisNamed::new(id, self.hostnames).to_string()
+    }
+
+    fn sys_id(&self, id: &IsisSysId) -> String {
+        IsisNamed::new(id, self.hostnames).to_string()
+    }
+
+    fn tlv(&mut self, tlv: &IsisTlv) -> Result {
+        use IsisTlv::*;
+        match tlv {
+            AreaAddr(v) => self.line(TLV_INDENT, format!("Area Address: {}", area_str(v))),
+            ProtoSupported(v) => self.line(
+                TLV_INDENT,
+                format!(
+                    "Protocols Supported: {}",
+                    v.nlpids.iter().map(|n| IsisProto::from(*n)).join(", ")
+                ),
+            ),
+            Hostname(v) => self.line(TLV_INDENT, format!("Hostname: {}", v.hostname)),
+            LspBufferSize(v) => self.line(TLV_INDENT, format!("LSP Buffer Size: {}", v.size)),
+            TeRouterId(v) => self.line(TLV_INDENT, format!("TE Router ID: {}", v.router_id)),
+            Ipv6TeRouterId(v) => {
+                self.line(TLV_INDENT, format!("IPv6 TE Router ID: {}", v.router_id))
+            }
+            Ipv4IfAddr(v) => self.line(TLV_INDENT, format!("IPv4 Interface Address: {}", v.addr)),
+            Ipv6IfAddr(v) => self.line(TLV_INDENT, format!("IPv6 Interface Address: {}", v.addr)),
+            Ipv6GlobalIfAddr(v) => self.line(
+                TLV_INDENT,
+                format!("Global IPv6 Interface Address: {}", v.addr),
+            ),
+            RouterCap(v) => {
+                self.line(
+                    TLV_INDENT,
+                    format!(
+                        "Router Capability: {} , D:{}, S:{}",
+                        v.router_id,
+                        bit(v.flags.d_flag()),
+                        bit(v.flags.s_flag())
+                    ),
+                )?;
+                v.subs.iter().try_for_each(|sub| self.cap_sub(sub))
+            }
+            ExtIsReach(v) => v.entries.iter().try_for_each(|entry| {
+                let neighbor = self.neighbor_id(&entry.neighbor_id);
+                self.line(
+                    TLV_INDENT,
+                    format!(
+                        "Extended Reachability: {} (Metric: {})",
+                        neighbor, entry.metric
+                    ),
+                )?;
+                entry.subs.iter().try_for_each(|sub| self.neigh_sub(sub))
+            }),
+            ExtIpReach(v) => v
+                .entries
+                .iter()
+                .try_for_each(|entry| self.ipv4_reach(entry, None)),
+            MtIpReach(v) => v
+                .entries
+                .iter()
+                .try_for_each(|entry| self.ipv4_reach(entry, Some(&v.mt))),
+            Ipv6Reach(v) => v
+                .entries
+                .iter()
+                .try_for_each(|entry| self.ipv6_reach(entry, None)),
+            MtIpv6Reach(v) => v
+                .entries
+                .iter()
+                .try_for_each(|entry| self.ipv6_reach(entry, Some(&v.mt))),
+            Srv6(v) => v.locators.iter().try_for_each(|locator| {
+                self.line(
+                    TLV_INDENT,
+                    format!(
+                        "SRv6 Locator: {} (Metric: {}) {}",
+                        locator.locator,
+                        locator.metric,
+                        algo_str(locator.algo)
+                    ),
+                )?;
+                locator
+                    .subs
+                    .iter()
+                    .try_for_each(|sub| self.prefix_sub(sub, SUB_INDENT))
+            }),
+            IsNeighbor(v) => v.neighbors.iter().try_for_each(|neighbor| {
+                let o = &neighbor.octets;
+                self.line(
+                    TLV_INDENT,
+                    format!(
+                        "LAN Neighbor: {:02x}{:02x}.{:02x}{:02x}.{:02x}{:02x}",
+                        o[0], o[1], o[2], o[3], o[4], o[5]
+                    ),
+                )
+            }),
+            LspEntries(v) => v.entries.iter().try_for_each(|entry| {
+                let lsp_id = IsisNamed::new(&entry.lsp_id, self.hostnames).to_string();
+                self.line(
+                    TLV_INDENT,
+                    format!(
+                        "LSP Entry: {}, seq 0x{:08x}, cksum 0x{:04x}, lifetime {}s",
+                        lsp_id, entry.seq_number, entry.checksum, entry.hold_time
+                    ),
+                )
+            }),
+            ReverseMetric(v) => {
+                self.line(
+                    TLV_INDENT,
+                    format!("Reverse Metric: {}, {}", v.metric, v.flags),
+                )?;
+                v.subs.iter().try_for_each(|sub| self.neigh_sub(sub))
+            }
+            Unknown(v) => self.line(
+                TLV_INDENT,
+                format!("Unknown TLV: Type {}, Length {}", u8::from(v.typ), v.len),
+            ),
+            _ => self.text(TLV_INDENT, tlv),
+        }
+    }
+
+    fn ipv4_reach(
+        &mut self,
+        entry: &IsisTlvExtIpReachEntry,
+        mt: Option<&MultiTopologyId>,
+    ) -> Result {
+        let down = if entry.flags.distribution() {
+            " Down"
+        } else {
+            ""
+        };
+        let text = match mt {
+            Some(mt) => format!(
+                "MT IP Reachability: {} (Metric: {}){} {}",
+                entry.prefix,
+                entry.metric,
+                down,
+                mt_str(mt)
+            ),
+            None => format!(
+                "Extended IP Reachability: {} (Metric: {}){}",
+                entry.prefix, entry.metric, down
+            ),
+        };
+        self.line(TLV_INDENT, text)?;
+        self.prefix_subs(&entry.subs)
+    }
+
+    fn ipv6_reach(
+        &mut self,
+        entry: &IsisTlvIpv6ReachEntry,
+        mt: Option<&MultiTopologyId>,
+    ) -> Result {
+        let mut text = format!(
+            "{}IPv6 Reachability: {} (Metric: {})",
+            if mt.is_some() { "MT " } else { "" },
+            entry.prefix,
+            entry.metric
+        );
+        // The X bit, named dist_internal, marks external prefixes.
+        if entry.flags.dist_internal() {
+            text.push_str(" {external}");
+        }
+        if entry.flags.dist_up() {
+            text.push_str(" Down");
+        }
+        if let Some(mt) = mt {
+            text.push_str(&format!(" {}", mt_str(mt)));
+        }
+        self.line(TLV_INDENT, text)?;
+        self.prefix_subs(&entry.subs)
+    }
+
+    fn prefix_subs(&mut self, subs: &[prefix::IsisSubTlv]) -> Result {
+        if subs.is_empty() {
+            return Ok(());
+        }
+        self.line(SUB_INDENT, "Subtlvs:")?;
+        subs.iter()
+            .try_for_each(|sub| self.prefix_sub(sub, SUB2_INDENT))
+    }
+
+    fn prefix_sub(&mut self, sub: &prefix::IsisSubTlv, indent: usize) -> Result {
+        use prefix::IsisSubTlv::*;
+        match sub {
+            PrefixSid(v) => {
+                let flags = &v.flags;
+                let mut text = format!(
+                    "SR Prefix-SID {}, Algorithm: {}, Flags:",
+                    sid_str(&v.sid),
+                    u8::from(v.algo)
+                );
+                for (set, name) in [
+                    (flags.r_flag(), " READVERTISED"),
+                    (flags.n_flag(), " NODE"),
+                    (flags.p_flag(), " NO-PHP"),
+                    (!flags.p_flag(), " PHP"),
+                    (flags.e_flag(), " EXPLICIT-NULL"),
+                    (flags.v_flag(), " VALUE"),
+                    (flags.l_flag(), " LOCAL"),
+                ] {
+                    if set {
+                        text.push_str(name);
+                    }
+                }
+                self.line(indent, text)
+            }
+            Srv6EndSid(v) => {
+                self.line(
+                    indent,
+                    format!("SRv6 End SID: {}, Endpoint Behavior: {}", v.sid, v.behavior),
+                )?;
+                self.sub2s(&v.sub2s, indent + 2)
+            }
+            Unknown(v) => self.line(
+                indent,
+                format!("Unknown Sub-TLV: Type {}, Length {}", v.code, v.len),
+            ),
+        }
+    }
+
+    fn sub2s(&mut self, sub2s: &[IsisSub2Tlv], indent: usize) -> Result {
+        sub2s.iter().try_for_each(|sub2| match sub2 {
+            IsisSub2Tlv::SidStructure(v) => self.line(
+                indent,
+                format!(
+                    "SRv6 SID Structure Locator Block length: {}, Locator Node length: {}, Function length: {}, Argument length: {}",
+                    v.lb_len, v.ln_len, v.fun_len, v.arg_len
+                ),
+            ),
+            IsisSub2Tlv::Unknown(v) => self.line(
+                indent,
+                format!("Unknown Sub-Sub-TLV: Type {}, Length {}", v.code, v.len),
+            ),
+        })
+    }
+
+    fn neigh_sub(&mut self, sub: &neigh::IsisSubTlv) -> Result {
+        use neigh::IsisSubTlv::*;
+        let text = match sub {
+            AdminGroup(v) => format!("Administrative Group: 0x{:x}", v.group),
+            Ipv4IfAddr(v) => format!("Local Interface IP Address(es): {}", v.addr),
+            Ipv4NeighAddr(v) => format!("Remote Interface IP Address(es): {}", v.addr),
+            Ipv6IfAddr(v) => format!("Local Interface IPv6 Address(es): {}", v.addr),
+            Ipv6NeighAddr(v) => format!("Remote Interface IPv6 Address(es): {}", v.addr),
+            ExtAdminGroup(v) => format!(
+                "Extended Admin Group: {}",
+                v.groups.iter().map(|g| format!("0x{:08x}", g)).join(" ")
+            ),
+            WideMetric(v) => format!("TE Default Metric: {}", v.metric),
+            AdjSid(v) => format!(
+                "Adjacency-SID: {}, Weight: {}, Flags: {}",
+                v.sid.value(),
+                v.weight,
+                adj_flags(&v.flags)
+            ),
+            LanAdjSid(v) => {
+                self.line(
+                    SUB_INDENT,
+                    format!(
+                        "Lan-Adjacency-SID: {}, Weight: {}, Flags: {}",
+                        v.sid.value(),
+                        v.weight,
+                        adj_flags(&v.flags)
+                    ),
+                )?;
+                let neighbor = self.sys_id(&v.system_id);
+                return self.line(SUB2_INDENT, format!("Neighbor-ID: {}", neighbor));
+            }
+            MinMaxDelay(v) => format!(
+                "{} Min/Max Link Delay: {} / {} (micro-sec)",
+                if v.anomalous { "Anomalous" } else { "Normal" },
+                v.min,
+                v.max
+            ),
+            Srv6EndXSid(v) => {
+                self.line(
+                    SUB_INDENT,
+                    format!(
+                        "SRv6 End.X SID: {}, Algorithm: {}, Weight: {}, Endpoint Behavior: {}, Flags: {}",
+                        v.sid,
+                        u8::from(v.algo),
+                        v.weight,
+                        v.behavior,
+                        srv6_flags(v.flags)
+                    ),
+                )?;
+                return self.sub2s(&v.sub2s, SUB2_INDENT);
+            }
+            Srv6LanEndXSid(v) => {
+                let neighbor = self.sys_id(&v.system_id);
+                self.line(
+                    SUB_INDENT,
+                    format!(
+                        "SRv6 Lan End.X SID: {}, Algorithm: {}, Weight: {}, Endpoint Behavior: {}, Flags: {} Neighbor-ID: {}",
+                        v.sid,
+                        u8::from(v.algo),
+                        v.weight,
+                        v.behavior,
+                        srv6_flags(v.flags),
+                        neighbor
+                    ),
+                )?;
+                return self.sub2s(&v.sub2s, SUB2_INDENT);
+            }
+            Unknown(v) => format!("Unknown Sub-TLV: Type {}, Length {}", v.code, v.len),
+        };
+        self.line(SUB_INDENT, text)
+    }
+
+    fn cap_sub(&mut self, sub: &cap::IsisSubTlv) -> Result {
+        use cap::IsisSubTlv::*;
+        let text = match sub {
+            SegmentRoutingCap(v) => format!(
+                "Segment Routing: I:{} V:{}, Global Block Base: {} Range: {}",
+                bit(v.flags.i_flag()),
+                bit(v.flags.v_flag()),
+                sid_label_value(&v.sid_label),
+                v.range
+            ),
+            SegmentRoutingLB(v) => format!(
+                "SR Local Block Base: {} Range: {}",
+                sid_label_value(&v.sid_label),
+                v.range
+            ),
+            SegmentRoutingAlgo(v) => {
+                self.line(SUB_INDENT, "SR Algorithm:")?;
+                return v.algo.iter().try_for_each(|algo| {
+                    self.line(
+                        SUB2_INDENT,
+                        format!("{}: {}", u8::from(*algo), algo_str(*algo)),
+                    )
+                });
+            }
+            NodeMaxSidDepth(v) => format!("Node Maximum SID Depth: {}", v.depth),
+            Srv6(v) => format!("SRv6: O:{}", bit(v.flags.o_flag())),
+            FlexAlgoDef(v) => {
+                self.line(
+                    SUB_INDENT,
+                    format!(
+                        "Flex-Algo Definition: Algorithm: {}, Metric-Type: {}, Calc-Type: {}, Priority: {}",
+                        u8::from(v.algo),
+                        v.metric_type,
+                        v.calc_type,
+                        v.priority
+                    ),
+                )?;
+                return v
+                    .subs
+                    .iter()
+                    .try_for_each(|sub| self.text(SUB2_INDENT, sub));
+            }
+            AreaLeader(v) => format!(
+                "Area Leader: Priority: {}, Algorithm: {}",
+                v.priority, v.algo
+            ),
+            DynamicFlooding(v) => {
+                format!("Dynamic Flooding Algorithm: {}", v.algos.iter().join(" "))
+            }
+            Unknown(v) => format!("Unknown Sub-TLV: Type {}, Length {}", v.code, v.len),
+        };
+        self.line(SUB_INDENT, text)
+    }
+}
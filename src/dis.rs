@@ -0,0 +1,202 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    IsLevel, IsisHello, IsisLsp, IsisLspId, IsisLspTypes, IsisNeighborId, IsisSysId, IsisTlv,
+    IsisTlvExtIsReach, IsisTlvExtIsReachEntry,
+};
+
+// Hello priority is 7 bits, the top bit is reserved.
+const PRIORITY_MASK: u8 = 0x7f;
+
+// Extended IS Reachability entry without sub-TLVs is 11 bytes, 23 of them
+// fit into one TLV.
+pub(crate) const EXT_IS_REACH_ENTRY_MAX: usize = 255 / 11;
+
+/// LAN adjacency as learned from a received IIH.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IsisLanAdj {
+    pub sys_id: IsisSysId,
+    pub snpa: [u8; 6],
+    pub priority: u8,
+    pub lan_id: IsisNeighborId,
+}
+
+impl IsisLanAdj {
+    pub fn from_hello(hello: &IsisHello, snpa: [u8; 6]) -> Self {
+        Self {
+            sys_id: hello.source_id.clone(),
+            snpa,
+            priority: hello.priority & PRIORITY_MASK,
+            lan_id: hello.lan_id.clone(),
+        }
+    }
+
+    fn key(&self) -> (u8, [u8; 6], IsisSysId) {
+        (
+            self.priority & PRIORITY_MASK,
+            self.snpa,
+            self.sys_id.clone(),
+        )
+    }
+}
+
+/// Result of DIS election on a circuit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IsisDis {
+    pub sys_id: IsisSysId,
+    pub lan_id: IsisNeighborId,
+    pub is_self: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IsisDisEvent {
+    NoChange,
+    Elected(IsisDis),
+    Resigned(IsisDis),
+    Changed { old: IsisDis, new: IsisDis },
+}
+
+/// Broadcast circuit state used for DIS election.
+#[derive(Debug, Clone)]
+pub struct IsisLanCircuit {
+    pub level: IsLevel,
+    pub circuit_id: u8,
+    pub sys_id: IsisSysId,
+    pub snpa: [u8; 6],
+    pub priority: u8,
+    pub adjs: BTreeMap<IsisSysId, IsisLanAdj>,
+    pub dis: Option<IsisDis>,
+}
+
+impl IsisLanCircuit {
+    pub fn new(
+        level: IsLevel,
+        circuit_id: u8,
+        sys_id: IsisSysId,
+        snpa: [u8; 6],
+        priority: u8,
+    ) -> Self {
+        Self {
+            level,
+            circuit_id,
+            sys_id,
+            snpa,
+            priority: priority & PRIORITY_MASK,
+            adjs: BTreeMap::new(),
+            dis: None,
+        }
+    }
+
+    pub fn adj_up(&mut self, adj: IsisLanAdj) {
+        self.adjs.insert(adj.sys_id.clone(), adj);
+    }
+
+    pub fn adj_down(&mut self, sys_id: &IsisSysId) {
+        self.adjs.remove(sys_id);
+    }
+
+    fn local(&self) -> IsisLanAdj {
+        IsisLanAdj {
+            sys_id: self.sys_id.clone(),
+            snpa: self.snpa,
+            priority: self.priority,
+            lan_id: IsisNeighborId::from_sys_id(&self.sys_id, self.circuit_id),
+        }
+    }
+
+    /// Run the election. Highest priority wins, ties are broken by the
+    /// highest SNPA and then by the highest system ID. There is no DIS
+    /// while the circuit has no adjacency.
+    pub fn dis_elect(&self) -> Option<IsisDis> {
+        if self.adjs.is_empty() {
+            return None;
+        }
+        let local = self.local();
+        let winner = self
+            .adjs
+            .values()
+            .chain(std::iter::once(&local))
+            .max_by_key(|adj| adj.key())?;
+
+        let is_self = winner.sys_id == self.sys_id;
+        let lan_id = if is_self {
+            IsisNeighborId::from_sys_id(&self.sys_id, self.circuit_id)
+        } else {
+            winner.lan_id.clone()
+        };
+        Some(IsisDis {
+            sys_id: winner.sys_id.clone(),
+            lan_id,
+            is_self,
+        })
+    }
+
+    /// Re-run the election and record the outcome.
+    pub fn update(&mut self) -> IsisDisEvent {
+        let new = self.dis_elect();
+        let event = match (self.dis.take(), new.clone()) {
+            (None, None) => IsisDisEvent::NoChange,
+            (None, Some(new)) => IsisDisEvent::Elected(new),
+            (Some(old), None) => IsisDisEvent::Resigned(old),
+            (Some(old), Some(new)) if old == new => IsisDisEvent::NoChange,
+            (Some(old), Some(new)) => IsisDisEvent::Changed { old, new },
+        };
+        self.dis = new;
+        event
+    }
+
+    /// LAN ID to advertise in our own hellos.
+    pub fn lan_id(&self) -> IsisNeighborId {
+        match &self.dis {
+            Some(dis) => dis.lan_id.clone(),
+            None => IsisNeighborId::from_sys_id(&self.sys_id, self.circuit_id),
+        }
+    }
+
+    pub fn is_dis(&self) -> bool {
+        self.dis.as_ref().is_some_and(|dis| dis.is_self)
+    }
+
+    /// Pseudonode LSP originated by the DIS. It lists every router on the
+    /// LAN, including the DIS itself, with zero metric.
+    pub fn pseudonode_lsp(&self, seq_number: u32, hold_time: u16) -> Option<IsisLsp> {
+        if !self.is_dis() {
+            return None;
+        }
+        let mut neighbors: Vec<IsisSysId> = self.adjs.keys().cloned().collect();
+        neighbors.push(self.sys_id.clone());
+        neighbors.sort();
+
+        let entries: Vec<IsisTlvExtIsReachEntry> = neighbors
+            .iter()
+            .map(|sys_id| IsisTlvExtIsReachEntry {
+                neighbor_id: IsisNeighborId::from_sys_id(sys_id, 0),
+                metric: 0,
+                subs: Vec::new(),
+            })
+            .collect();
+
+        let tlvs = entries
+            .chunks(EXT_IS_REACH_ENTRY_MAX)
+            .map(|chunk| {
+                IsisTlv::ExtIsReach(IsisTlvExtIsReach {
+                    entries: chunk.to_vec(),
+                })
+            })
+            .collect();
+
+        let level = match self.level {
+            IsLevel::L1 => 1,
+            _ => 2,
+        };
+        Some(IsisLsp {
+            pdu_len: 0,
+            hold_time,
+            lsp_id: IsisLspId::new(self.sys_id.clone(), self.circuit_id, 0),
+            seq_number,
+            checksum: 0,
+            types: IsisLspTypes::from(level),
+            tlvs,
+        })
+    }
+}
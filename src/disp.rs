@@ -4,10 +4,12 @@ use itertools::Itertools;
 
 use crate::{
     Algo, IsLevel, IsisCsnp, IsisHello, IsisLsp, IsisLspEntry, IsisLspId, IsisNeighborId,
-    IsisPacket, IsisPdu, IsisProto, IsisPsnp, IsisSysId, IsisTlv, IsisTlvAreaAddr, IsisTlvHostname,
-    IsisTlvIpv4IfAddr, IsisTlvIpv6GlobalIfAddr, IsisTlvIpv6IfAddr, IsisTlvIpv6TeRouterId,
-    IsisTlvIsNeighbor, IsisTlvLspEntries, IsisTlvP2p3Way, IsisTlvPadding, IsisTlvProtoSupported,
-    IsisTlvSrv6, IsisTlvTeRouterId, NeighborAddr, SidLabelValue,
+    IsisP2pHello, IsisPacket, IsisPdu, IsisProto, IsisPsnp, IsisSysId, IsisTlv, IsisTlvAreaAddr,
+    IsisTlvAreaSysIds, IsisTlvFloodingPath, IsisTlvFloodingRequest, IsisTlvHostname,
+    IsisTlvInstanceId, IsisTlvIpv4IfAddr, IsisTlvIpv6GlobalIfAddr, IsisTlvIpv6IfAddr,
+    IsisTlvIpv6TeRouterId, IsisTlvIsNeighbor, IsisTlvLspBufferSize, IsisTlvLspEntries,
+    IsisTlvP2p3Way, IsisTlvPadding, IsisTlvProtoSupported, IsisTlvSrv6, IsisTlvTeRouterId,
+    NeighborAddr, SidLabelValue,
 };
 
 impl Display for IsisPacket {
@@ -42,6 +44,7 @@ impl Display for IsisPdu {
         match self {
             L1Hello(v) => write!(f, "{}", v),
             L2Hello(v) => write!(f, "{}", v),
+            P2pHello(v) => write!(f, "{}", v),
             L1Lsp(v) => write!(f, "{}", v),
             L2Lsp(v) => write!(f, "{}", v),
             L1Csnp(v) => write!(f, "{}", v),
@@ -120,6 +123,24 @@ impl Display for IsisHello {
     }
 }
 
+impl Display for IsisP2pHello {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            r#" Circuit type: {}
+ Source ID: {}
+ Holding timer: {}
+ PDU length: {}
+ Local circuit ID: {}"#,
+            self.circuit_type, self.source_id, self.hold_time, self.pdu_len, self.circuit_id
+        )?;
+        for tlv in self.tlvs.iter() {
+            write!(f, "\n{}", tlv)?;
+        }
+        Ok(())
+    }
+}
+
 impl Display for IsisCsnp {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(
@@ -160,8 +181,15 @@ impl Display for IsisTlv {
         match self {
             AreaAddr(v) => write!(f, "{}", v),
             IsNeighbor(v) => write!(f, "{}", v),
+            InstanceId(v) => write!(f, "{}", v),
             Padding(v) => write!(f, "{}", v),
             LspEntries(v) => write!(f, "{}", v),
+            LspBufferSize(v) => write!(f, "{}", v),
+            ReverseMetric(v) => write!(f, "{}", v),
+            AreaSysIds(v) => write!(f, "{}", v),
+            FloodingPath(v) => write!(f, "{}", v),
+            FloodingRequest(v) => write!(f, "{}", v),
+            AreaProxy(v) => write!(f, "{}", v),
             ExtIsReach(v) => write!(f, "{}", v),
             Srv6(v) => write!(f, "{}", v),
             ProtoSupported(v) => write!(f, "{}", v),
@@ -356,6 +384,12 @@ impl Display for IsisTlvHostname {
     }
 }
 
+impl Display for IsisTlvLspBufferSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "  LSP Buffer Size: {}", self.size)
+    }
+}
+
 impl Display for IsisTlvTeRouterId {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(f, "  TE Router ID: {}", self.router_id)
@@ -414,3 +448,44 @@ impl Display for IsisTlvP2p3Way {
         )
     }
 }
+
+impl Display for IsisTlvAreaSysIds {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "  Area System IDs: Start:{}, L:{}",
+            self.start,
+            self.flags.l_flag() as u8
+        )?;
+        for sys_id in self.sys_ids.iter() {
+            write!(f, "\n   {}", sys_id)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for IsisTlvFloodingPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "  Flooding Path: {}", self.path.iter().join(" "))
+    }
+}
+
+impl Display for IsisTlvFloodingRequest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "  Flooding Request:")?;
+        for scope in self.scopes.iter() {
+            write!(f, " {}(R:{})", scope.scope(), scope.r_flag() as u8)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for IsisTlvInstanceId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "  Instance ID: {}", self.iid)?;
+        if !self.itids.is_empty() {
+            write!(f, ", ITIDs: {}", self.itids.iter().join(" "))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+use bytes::BytesMut;
+use serde::{Deserialize, Deserializer};
+
+use crate::{
+    IsisEncodeError, IsisPacket, IsisPdu, IsisTlv, IsisTlvExtIpReachEntry, IsisTlvIpv6ReachEntry,
+    IsisType, length_indicator,
+};
+
+/// Packet description for the encoder. Only the PDU is required. Header
+/// fields, PDU length and LSP checksum are computed unless given here, which
+/// is how malformed packets are described.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct IsisPacketDesc {
+    pub pdu: IsisPdu,
+    pub length_indicator: Option<u8>,
+    pub id_extension: Option<u8>,
+    pub id_length: Option<u8>,
+    pub version: Option<u8>,
+    pub max_area_addr: Option<u8>,
+    pub pdu_len: Option<u16>,
+    pub checksum: Option<u16>,
+}
+
+fn deserialize<'de, D: Deserializer<'de>>(de: D) -> Result<IsisPacketDesc, IsisEncodeError> {
+    let mut track = serde_path_to_error::Track::new();
+    let de = serde_path_to_error::Deserializer::new(de, &mut track);
+    // Enums are single key maps in both formats rather than YAML tags.
+    serde_yaml::with::singleton_map_recursive::deserialize(de).map_err(|err| {
+        IsisEncodeError::Schema {
+            path: track.path().to_string(),
+            message: err.to_string(),
+        }
+    })
+}
+
+// Fill the prefix length and sub-TLV flag of reachability entries left at
+// zero in the description.
+fn ipv4_fill(entry: &mut IsisTlvExtIpReachEntry) {
+    if entry.flags.prefixlen() == 0 {
+        entry
+            .flags
+            .set_prefixlen(entry.prefix.prefix_len() as usize);
+    }
+    if !entry.subs.is_empty() {
+        entry.flags.set_sub_tlv(true);
+    }
+}
+
+fn ipv6_fill(entry: &mut IsisTlvIpv6ReachEntry) {
+    if !entry.subs.is_empty() {
+        entry.flags.set_sub_tlv(true);
+    }
+}
+
+fn tlvs_fill(tlvs: &mut [IsisTlv]) {
+    for tlv in tlvs.iter_mut() {
+        match tlv {
+            IsisTlv::ExtIpReach(tlv) => tlv.entries.iter_mut().for_each(ipv4_fill),
+            IsisTlv::MtIpReach(tlv) => tlv.entries.iter_mut().for_each(ipv4_fill),
+            IsisTlv::Ipv6Reach(tlv) => tlv.entries.iter_mut().for_each(ipv6_fill),
+            IsisTlv::MtIpv6Reach(tlv) => tlv.entries.iter_mut().for_each(ipv6_fill),
+            _ => {}
+        }
+    }
+}
+
+impl IsisPacketDesc {
+    pub fn from_json(input: &str) -> Result<Self, IsisEncodeError> {
+        let mut de = serde_json::Deserializer::from_str(input);
+        let desc = deserialize(&mut de)?;
+        de.end().map_err(|err| IsisEncodeError::Schema {
+            path: ".".into(),
+            message: err.to_string(),
+        })?;
+        Ok(desc)
+    }
+
+    pub fn from_yaml(input: &str) -> Result<Self, IsisEncodeError> {
+        deserialize(serde_yaml::Deserializer::from_str(input))
+    }
+
+    /// Packet with the header filled in. `pdu_len` and the checksum are
+    /// computed by `emit()`.
+    pub fn packet(&self) -> Result<IsisPacket, IsisEncodeError> {
+        use IsisPdu::*;
+        let mut pdu = self.pdu.clone();
+        let (pdu_type, tlvs) = match &mut pdu {
+            L1Hello(v) => (IsisType::L1Hello, &mut v.tlvs),
+            L2Hello(v) => (IsisType::L2Hello, &mut v.tlvs),
+            P2pHello(v) => (IsisType::P2PHello, &mut v.tlvs),
+            L1Lsp(v) => (IsisType::L1Lsp, &mut v.tlvs),
+            L2Lsp(v) => (IsisType::L2Lsp, &mut v.tlvs),
+            L1Csnp(v) => (IsisType::L1Csnp, &mut v.tlvs),
+            L2Csnp(v) => (IsisType::L2Csnp, &mut v.tlvs),
+            L1Psnp(v) => (IsisType::L1Psnp, &mut v.tlvs),
+            L2Psnp(v) => (IsisType::L2Psnp, &mut v.tlvs),
+            Unknown(v) => {
+                return Err(IsisEncodeError::Unsupported {
+                    message: format!("{} has no encoder", v.typ),
+                });
+            }
+        };
+        tlvs_fill(tlvs);
+
+        let mut packet = IsisPacket::from(pdu_type, pdu);
+        packet.length_indicator = self.length_indicator.unwrap_or(length_indicator(pdu_type));
+        packet.id_extension = self.id_extension.unwrap_or(packet.id_extension);
+        packet.id_length = self.id_length.unwrap_or(packet.id_length);
+        packet.version = self.version.unwrap_or(packet.version);
+        packet.max_area_addr = self.max_area_addr.unwrap_or(packet.max_area_addr);
+        Ok(packet)
+    }
+
+    /// Wire format of the described packet, starting at the IS-IS header.
+    pub fn encode(&self) -> Result<BytesMut, IsisEncodeError> {
+        let packet = self.packet()?;
+        let mut buf = BytesMut::new();
+        packet.emit(&mut buf);
+
+        if let Some(pdu_len) = self.pdu_len {
+            let pos = match packet.pdu_type {
+                IsisType::L1Hello | IsisType::L2Hello | IsisType::P2PHello => 17,
+                _ => 8,
+            };
+            buf[pos..pos + 2].copy_from_slice(&pdu_len.to_be_bytes());
+        }
+        // emit() already computed the checksum, the PDU length is not
+        // covered by it.
+        if let Some(checksum) = self.checksum
+            && packet.pdu_type.is_lsp()
+        {
+            buf[24..26].copy_from_slice(&checksum.to_be_bytes());
+        }
+        Ok(buf)
+    }
+}
+
+/// Encode a JSON packet description.
+pub fn encode_json(input: &str) -> Result<BytesMut, IsisEncodeError> {
+    IsisPacketDesc::from_json(input)?.encode()
+}
+
+/// Encode a YAML packet description.
+pub fn encode_yaml(input: &str) -> Result<BytesMut, IsisEncodeError> {
+    IsisPacketDesc::from_yaml(input)?.encode()
+}
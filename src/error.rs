@@ -72,6 +72,10 @@ pub enum IsisParseError {
     #[error("Invalid prefix length: {length} for address family")]
     InvalidPrefixLength { length: u8 },
 
+    /// Invalid system ID format
+    #[error("Invalid system ID format: {message}")]
+    InvalidSysId { message: String },
+
     /// Invalid neighbor ID format
     #[error("Invalid neighbor ID format: {message}")]
     InvalidNeighborId { message: String },
@@ -185,6 +189,59 @@ impl ErrorConvert<nom::error::Error<&'static [u8]>> for IsisParseError {
     }
 }
 
+/// Error reading or writing packet capture files
+#[derive(Error, Debug)]
+pub enum IsisCaptureError {
+    /// I/O error from the underlying reader or writer
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Not a pcap or pcapng file, or a malformed block
+    #[error("Invalid capture format: {message}")]
+    InvalidFormat { message: String },
+
+    /// IS-IS PDU in the capture failed to parse
+    #[error("IS-IS parse error: {0}")]
+    Parse(#[from] IsisParseError),
+}
+
+impl IsisCaptureError {
+    /// Create a new invalid format error
+    pub fn invalid_format(message: impl Into<String>) -> Self {
+        Self::InvalidFormat {
+            message: message.into(),
+        }
+    }
+}
+
+/// Error encoding a packet from a JSON or YAML description
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum IsisEncodeError {
+    /// Description does not match the schema, `path` names the field
+    #[error("{path}: {message}")]
+    Schema { path: String, message: String },
+
+    /// PDU which cannot be emitted
+    #[error("Unsupported PDU: {message}")]
+    Unsupported { message: String },
+}
+
+/// LSP too large for the originator buffer or the local link
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum IsisLspSizeError {
+    /// LSP larger than the originatingLSPBufferSize of its source
+    #[error("LSP length {len} exceeds the originating buffer size {buffer_size}")]
+    ExceedsBufferSize { len: usize, buffer_size: u16 },
+
+    /// LSP larger than the local MTU, it cannot be flooded further
+    #[error("LSP length {len} exceeds the local MTU {mtu}")]
+    ExceedsMtu { len: usize, mtu: usize },
+
+    /// Originator builds LSPs which may not fit in the local MTU
+    #[error("Originating buffer size {buffer_size} exceeds the local MTU {mtu}")]
+    BufferSizeExceedsMtu { buffer_size: u16, mtu: usize },
+}
+
 /// Result type for IS-IS parsing operations
 pub type IsisParseResult<T> = Result<T, IsisParseError>;
 
@@ -0,0 +1,191 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use ipnet::IpNet;
+
+use crate::neigh::IsisSubTlv as NeighSubTlv;
+use crate::{
+    Algo, IsisFadSubTlv, IsisFlexAlgoMetric, IsisNeighborId, IsisSpfExclude, IsisSpfGraph,
+    IsisSpfLink, IsisSpfTree, IsisSubFlexAlgoDef, IsisSysId, SidLabelValue,
+};
+
+/// Route of a Flexible Algorithm routing table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IsisFlexAlgoRoute {
+    pub prefix: IpNet,
+    pub metric: u32,
+    /// First hop routers, empty for prefixes of the root.
+    pub nexthops: BTreeSet<IsisSysId>,
+    pub originators: BTreeSet<IsisSysId>,
+    /// Prefix-SID of the algorithm, `None` for an SRv6 locator.
+    pub sid: Option<SidLabelValue>,
+}
+
+/// Routing table of one Flexible Algorithm (RFC 9350).
+#[derive(Debug, Clone)]
+pub struct IsisFlexAlgoTable {
+    pub algo: Algo,
+    /// Winning FAD and the router advertising it.
+    pub fad: IsisSubFlexAlgoDef,
+    pub fad_source: IsisSysId,
+    pub tree: IsisSpfTree,
+    pub routes: Vec<IsisFlexAlgoRoute>,
+}
+
+// Administrative groups of a link, the extended ones when present.
+fn admin_groups(link: &IsisSpfLink) -> Vec<u32> {
+    let mut groups = Vec::new();
+    for sub in &link.subs {
+        match sub {
+            NeighSubTlv::ExtAdminGroup(v) => return v.groups.clone(),
+            NeighSubTlv::AdminGroup(v) => groups = vec![v.group],
+            _ => {}
+        }
+    }
+    groups
+}
+
+fn any_bit(groups: &[u32], mask: &[u32]) -> bool {
+    groups.iter().zip(mask).any(|(g, m)| g & m != 0)
+}
+
+fn all_bits(groups: &[u32], mask: &[u32]) -> bool {
+    mask.iter()
+        .enumerate()
+        .all(|(i, m)| groups.get(i).copied().unwrap_or(0) & m == *m)
+}
+
+// FAD usable by this implementation, RFC 9350 section 5.3 excludes the
+// routers not supporting all of it from the algorithm.
+fn supported(fad: &IsisSubFlexAlgoDef) -> bool {
+    !matches!(fad.metric_type, IsisFlexAlgoMetric::Unknown(_))
+        && fad.calc_type == 0
+        && !fad
+            .subs
+            .iter()
+            .any(|sub| matches!(sub, IsisFadSubTlv::Unknown(_)))
+}
+
+// Link cost for the FAD metric type, `None` when the link is pruned.
+fn link_cost(fad: &IsisSubFlexAlgoDef, link: &IsisSpfLink) -> Option<u32> {
+    let groups = admin_groups(link);
+    if any_bit(&groups, fad.exclude_any())
+        || (!fad.include_any().is_empty() && !any_bit(&groups, fad.include_any()))
+        || !all_bits(&groups, fad.include_all())
+    {
+        return None;
+    }
+    match fad.metric_type {
+        IsisFlexAlgoMetric::Igp => Some(link.metric),
+        IsisFlexAlgoMetric::MinDelay => link.subs.iter().find_map(|sub| match sub {
+            NeighSubTlv::MinMaxDelay(v) => Some(v.min),
+            _ => None,
+        }),
+        IsisFlexAlgoMetric::Te => link.subs.iter().find_map(|sub| match sub {
+            NeighSubTlv::WideMetric(v) => Some(v.metric),
+            _ => None,
+        }),
+        IsisFlexAlgoMetric::Unknown(_) => None,
+    }
+}
+
+impl IsisSpfGraph {
+    /// Winning FAD of `algo`, the highest priority and then the highest
+    /// system ID (RFC 9350 section 5.3).
+    pub fn fad(&self, algo: Algo) -> Option<(IsisSysId, &IsisSubFlexAlgoDef)> {
+        self.nodes
+            .iter()
+            .filter(|(id, _)| id.pseudo_id() == 0)
+            .flat_map(|(id, node)| node.fads.iter().map(move |fad| (id.sys_id(), fad)))
+            .filter(|(_, fad)| fad.algo == algo)
+            .max_by(|(a, x), (b, y)| (x.priority, a).cmp(&(y.priority, b)))
+    }
+
+    /// True when the node advertises `algo`. Pseudonodes take part in
+    /// every algorithm.
+    pub fn participates(&self, id: &IsisNeighborId, algo: Algo) -> bool {
+        id.pseudo_id() != 0 || self.node(id).is_some_and(|node| node.algos.contains(&algo))
+    }
+
+    /// Shortest path tree of `algo` with the links and metric of its FAD,
+    /// `None` when the root does not take part in the algorithm.
+    pub fn flex_spf(&self, root: &IsisNeighborId, algo: Algo) -> Option<IsisSpfTree> {
+        let (_, fad) = self.fad(algo)?;
+        if !supported(fad) || !self.participates(root, algo) {
+            return None;
+        }
+        let tree = self.dijkstra(root, &IsisSpfExclude::default(), |from, link| {
+            if !self.participates(&link.to, algo) {
+                return None;
+            }
+            // Links from a pseudonode carry no attributes.
+            if from.pseudo_id() != 0 {
+                return Some(0);
+            }
+            link_cost(fad, link)
+        });
+        Some(tree)
+    }
+
+    /// Routing table of `algo` from Prefix-SIDs and SRv6 locators of the
+    /// algorithm.
+    pub fn flex_algo(&self, root: &IsisSysId, algo: Algo) -> Option<IsisFlexAlgoTable> {
+        let root = IsisNeighborId::from_sys_id(root, 0);
+        let tree = self.flex_spf(&root, algo)?;
+        let (fad_source, fad) = self.fad(algo)?;
+
+        let mut routes: BTreeMap<IpNet, IsisFlexAlgoRoute> = BTreeMap::new();
+        let mut add = |prefix: IpNet, metric: u32, id: &IsisNeighborId, sid| {
+            let route = IsisFlexAlgoRoute {
+                prefix,
+                metric,
+                nexthops: tree.nexthops(id),
+                originators: BTreeSet::from([id.sys_id()]),
+                sid,
+            };
+            match routes.get_mut(&prefix) {
+                Some(best) if best.metric < metric => {}
+                Some(best) if best.metric == metric => {
+                    best.nexthops.extend(route.nexthops);
+                    best.originators.extend(route.originators);
+                }
+                _ => {
+                    routes.insert(prefix, route);
+                }
+            }
+        };
+        for (id, dist) in &tree.dist {
+            let Some(node) = self.node(id).filter(|_| id.pseudo_id() == 0) else {
+                continue;
+            };
+            for prefix in &node.prefixes {
+                if let Some(sid) = prefix.sids.iter().find(|sid| sid.algo == algo) {
+                    let metric = dist.saturating_add(prefix.metric);
+                    add(prefix.prefix, metric, id, Some(sid.sid.clone()));
+                }
+            }
+            for locator in node.locators.iter().filter(|l| l.algo == algo) {
+                let metric = dist.saturating_add(locator.metric);
+                add(locator.locator.into(), metric, id, None);
+            }
+        }
+        Some(IsisFlexAlgoTable {
+            algo,
+            fad: fad.clone(),
+            fad_source,
+            tree,
+            routes: routes.into_values().collect(),
+        })
+    }
+
+    /// Routing tables of every Flexible Algorithm `root` takes part in.
+    pub fn flex_algo_tables(&self, root: &IsisSysId) -> Vec<IsisFlexAlgoTable> {
+        let Some(node) = self.node(&IsisNeighborId::from_sys_id(root, 0)) else {
+            return Vec::new();
+        };
+        node.algos
+            .iter()
+            .filter(|algo| matches!(algo, Algo::FlexAlgo(_)))
+            .filter_map(|algo| self.flex_algo(root, *algo))
+            .collect()
+    }
+}
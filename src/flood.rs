@@ -0,0 +1,173 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{
+    AreaSysIdsFlags, IsisParseError, IsisSysId, IsisTlv, IsisTlvAreaSysIds, IsisTlvFloodingPath,
+    IsisTlvType,
+};
+
+// Entries fitting in the 255 byte TLV value.
+const AREA_SYS_IDS_MAX: usize = (255 - 3) / 6;
+const FLOODING_PATH_MAX: usize = 255 / 2;
+
+/// Flooding topology distributed by the Area Leader in centralized mode
+/// (RFC 9667 section 4.4).
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct IsisFloodingTopology {
+    /// Systems of the area, the position is the index used by the paths.
+    pub sys_ids: Vec<IsisSysId>,
+    /// Edges as index pairs, lower index first.
+    pub edges: BTreeSet<(u16, u16)>,
+}
+
+impl IsisFloodingTopology {
+    /// Flooding topology of the chosen edges, systems sorted by system ID.
+    pub fn new(edges: &[(IsisSysId, IsisSysId)]) -> Self {
+        let sys_ids: BTreeSet<IsisSysId> = edges
+            .iter()
+            .flat_map(|(a, b)| [a.clone(), b.clone()])
+            .collect();
+        let sys_ids: Vec<IsisSysId> = sys_ids.into_iter().collect();
+        let index = |sys_id: &IsisSysId| sys_ids.binary_search(sys_id).unwrap() as u16;
+        let edges = edges
+            .iter()
+            .filter(|(a, b)| a != b)
+            .map(|(a, b)| {
+                let (a, b) = (index(a), index(b));
+                (a.min(b), a.max(b))
+            })
+            .collect();
+        Self { sys_ids, edges }
+    }
+
+    /// Edges as system ID pairs.
+    pub fn links(&self) -> Vec<(IsisSysId, IsisSysId)> {
+        self.edges
+            .iter()
+            .map(|&(a, b)| {
+                (
+                    self.sys_ids[a as usize].clone(),
+                    self.sys_ids[b as usize].clone(),
+                )
+            })
+            .collect()
+    }
+
+    /// Neighbors of `sys_id` over which LSPs are flooded.
+    pub fn adjacencies(&self, sys_id: &IsisSysId) -> BTreeSet<IsisSysId> {
+        let Some(index) = self.sys_ids.iter().position(|id| id == sys_id) else {
+            return BTreeSet::new();
+        };
+        let index = index as u16;
+        self.edges
+            .iter()
+            .filter_map(|&(a, b)| match (a == index, b == index) {
+                (true, _) => Some(b),
+                (_, true) => Some(a),
+                _ => None,
+            })
+            .map(|other| self.sys_ids[other as usize].clone())
+            .collect()
+    }
+
+    // Edges covered by walking paths, each one continuing while the last
+    // node has an unused edge.
+    fn paths(&self) -> Vec<Vec<u16>> {
+        let mut unused: BTreeMap<u16, BTreeSet<u16>> = BTreeMap::new();
+        for &(a, b) in &self.edges {
+            unused.entry(a).or_default().insert(b);
+            unused.entry(b).or_default().insert(a);
+        }
+        let mut paths = Vec::new();
+        while let Some((&start, _)) = unused.iter().find(|(_, to)| !to.is_empty()) {
+            let mut path = vec![start];
+            let mut node = start;
+            while let Some(&next) = unused.get(&node).and_then(|to| to.first()) {
+                unused.get_mut(&node).unwrap().remove(&next);
+                unused.get_mut(&next).unwrap().remove(&node);
+                path.push(next);
+                node = next;
+            }
+            paths.push(path);
+        }
+        paths
+    }
+
+    /// Area System IDs TLVs followed by the Flooding Path TLVs.
+    pub fn encode(&self) -> Vec<IsisTlv> {
+        let mut tlvs: Vec<IsisTlv> = Vec::new();
+        let chunks: Vec<_> = self.sys_ids.chunks(AREA_SYS_IDS_MAX).collect();
+        for (i, chunk) in chunks.iter().enumerate() {
+            tlvs.push(
+                IsisTlvAreaSysIds {
+                    start: (i * AREA_SYS_IDS_MAX) as u16,
+                    flags: AreaSysIdsFlags::new().with_l_flag(i + 1 == chunks.len()),
+                    sys_ids: chunk.to_vec(),
+                }
+                .into(),
+            );
+        }
+        // Long paths continue from the last index of the previous TLV.
+        for path in self.paths() {
+            let mut start = 0;
+            loop {
+                let end = (start + FLOODING_PATH_MAX).min(path.len());
+                tlvs.push(
+                    IsisTlvFloodingPath {
+                        path: path[start..end].to_vec(),
+                    }
+                    .into(),
+                );
+                if end == path.len() {
+                    break;
+                }
+                start = end - 1;
+            }
+        }
+        tlvs
+    }
+
+    /// Flooding topology from the TLVs of the Area Leader LSPs.
+    pub fn decode(tlvs: &[IsisTlv]) -> Result<Self, IsisParseError> {
+        let mut topo = Self::default();
+        let mut last = false;
+        for tlv in tlvs {
+            if let IsisTlv::AreaSysIds(v) = tlv {
+                if v.start as usize != topo.sys_ids.len() {
+                    return Err(IsisParseError::tlv_parse_error(
+                        IsisTlvType::AreaSysIds,
+                        format!("starting index {} out of sequence", v.start),
+                    ));
+                }
+                topo.sys_ids.extend(v.sys_ids.iter().cloned());
+                last = v.flags.l_flag();
+            }
+        }
+        if !last {
+            return Err(IsisParseError::tlv_parse_error(
+                IsisTlvType::AreaSysIds,
+                "last Area System IDs TLV missing",
+            ));
+        }
+        for tlv in tlvs {
+            if let IsisTlv::FloodingPath(v) = tlv {
+                if let Some(index) = v
+                    .path
+                    .iter()
+                    .find(|index| **index as usize >= topo.sys_ids.len())
+                {
+                    return Err(IsisParseError::tlv_parse_error(
+                        IsisTlvType::FloodingPath,
+                        format!("index {} out of range", index),
+                    ));
+                }
+                for pair in v.path.windows(2) {
+                    if pair[0] != pair[1] {
+                        topo.edges
+                            .insert((pair[0].min(pair[1]), pair[0].max(pair[1])));
+                    }
+                }
+            }
+        }
+        Ok(topo)
+    }
+}
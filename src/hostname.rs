@@ -0,0 +1,110 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Result};
+
+use crate::{IsisLsdb, IsisLsp, IsisLspId, IsisNeighborId, IsisSysId};
+
+/// System ID to hostname map from the Dynamic Hostname TLVs (RFC 5301).
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct IsisHostnameMap {
+    names: BTreeMap<IsisSysId, String>,
+}
+
+impl IsisHostnameMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, sys_id: IsisSysId, hostname: String) {
+        self.names.insert(sys_id, hostname);
+    }
+
+    /// Learn the hostname of the LSP originator, if the LSP carries one.
+    pub fn insert_lsp(&mut self, lsp: &IsisLsp) {
+        if let Some(tlv) = lsp.hostname_tlv() {
+            self.insert(lsp.lsp_id.sys_id(), tlv.hostname.clone());
+        }
+    }
+
+    pub fn get(&self, sys_id: &IsisSysId) -> Option<&str> {
+        self.names.get(sys_id).map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn sys_id<'a>(&'a self, id: &'a IsisSysId) -> IsisNamed<'a, IsisSysId> {
+        IsisNamed::new(id, Some(self))
+    }
+
+    pub fn neighbor_id<'a>(&'a self, id: &'a IsisNeighborId) -> IsisNamed<'a, IsisNeighborId> {
+        IsisNamed::new(id, Some(self))
+    }
+
+    pub fn lsp_id<'a>(&'a self, id: &'a IsisLspId) -> IsisNamed<'a, IsisLspId> {
+        IsisNamed::new(id, Some(self))
+    }
+}
+
+impl From<&IsisLsdb> for IsisHostnameMap {
+    fn from(lsdb: &IsisLsdb) -> Self {
+        let mut map = Self::new();
+        lsdb.iter().for_each(|lsp| map.insert_lsp(lsp));
+        map
+    }
+}
+
+/// ID displayed with the hostname in place of the system ID when known, as
+/// in `r1.01` or `r1.00-00`, and unchanged otherwise.
+#[derive(Debug, Clone, Copy)]
+pub struct IsisNamed<'a, T> {
+    pub id: &'a T,
+    pub hostnames: Option<&'a IsisHostnameMap>,
+}
+
+impl<'a, T> IsisNamed<'a, T> {
+    pub fn new(id: &'a T, hostnames: Option<&'a IsisHostnameMap>) -> Self {
+        Self { id, hostnames }
+    }
+
+    fn name(&self, sys_id: &IsisSysId) -> Option<&'a str> {
+        self.hostnames.and_then(|map| map.get(sys_id))
+    }
+}
+
+impl Display for IsisNamed<'_, IsisSysId> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.name(self.id) {
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "{}", self.id),
+        }
+    }
+}
+
+impl Display for IsisNamed<'_, IsisNeighborId> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.name(&self.id.sys_id()) {
+            Some(name) => write!(f, "{}.{:02x}", name, self.id.pseudo_id()),
+            None => write!(f, "{}", self.id),
+        }
+    }
+}
+
+impl Display for IsisNamed<'_, IsisLspId> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.name(&self.id.sys_id()) {
+            Some(name) => write!(
+                f,
+                "{}.{:02x}-{:02x}",
+                name,
+                self.id.pseudo_id(),
+                self.id.fragment_id()
+            ),
+            None => write!(f, "{}", self.id),
+        }
+    }
+}
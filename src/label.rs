@@ -0,0 +1,224 @@
+use std::collections::BTreeMap;
+
+use ipnet::IpNet;
+
+use crate::prefix::IsisSubTlv as PrefixSubTlv;
+use crate::{Algo, IsisLsdb, IsisSubPrefixSid, IsisSysId, IsisTlv, SidLabelTlv, SidLabelValue};
+
+// Reserved label values (RFC 3032).
+const IPV4_EXPLICIT_NULL: u32 = 0;
+const IPV6_EXPLICIT_NULL: u32 = 2;
+const IMPLICIT_NULL: u32 = 3;
+
+/// SRGB of a router as base label and size of each SR-Capabilities
+/// sub-TLV, in advertisement order.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct IsisSrgb {
+    pub ranges: Vec<(u32, u32)>,
+}
+
+impl IsisSrgb {
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn size(&self) -> u32 {
+        self.ranges.iter().map(|(_, range)| range).sum()
+    }
+
+    /// Label of `index`, which spans the ranges in order (RFC 8667
+    /// section 3.1).
+    pub fn label(&self, index: u32) -> Option<u32> {
+        let mut index = index;
+        for &(base, range) in &self.ranges {
+            if index < range {
+                return base.checked_add(index);
+            }
+            index -= range;
+        }
+        None
+    }
+}
+
+/// Incoming label a router expects for a prefix SID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsisSrLabel {
+    Label(u32),
+    /// The originator expects the penultimate hop to pop the label.
+    ImplicitNull,
+    /// Explicit NULL of the prefix address family.
+    ExplicitNull(u32),
+    /// Index beyond the SRGB of the router.
+    OutOfRange(u32),
+    /// Router without SRGB.
+    NoSrgb,
+    /// SID value not matching the V flag, or a local label expected by
+    /// another router.
+    Invalid,
+}
+
+impl IsisSrLabel {
+    /// MPLS label value, reserved ones included.
+    pub fn label(&self) -> Option<u32> {
+        match self {
+            IsisSrLabel::Label(label) | IsisSrLabel::ExplicitNull(label) => Some(*label),
+            IsisSrLabel::ImplicitNull => Some(IMPLICIT_NULL),
+            _ => None,
+        }
+    }
+}
+
+/// Prefix SID with the router advertising it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IsisSrPrefix {
+    pub originator: IsisSysId,
+    pub prefix: IpNet,
+    pub sid: IsisSubPrefixSid,
+}
+
+/// Prefix SID label resolution over the LSP database of one level.
+#[derive(Debug, Default, Clone)]
+pub struct IsisSrResolver {
+    /// SRGB of every router, empty without SR-Capabilities.
+    pub srgbs: BTreeMap<IsisSysId, IsisSrgb>,
+    pub prefixes: Vec<IsisSrPrefix>,
+}
+
+fn prefix_sids<'a>(
+    originator: &'a IsisSysId,
+    prefix: IpNet,
+    subs: &'a [PrefixSubTlv],
+) -> impl Iterator<Item = IsisSrPrefix> + 'a {
+    subs.iter().filter_map(move |sub| match sub {
+        PrefixSubTlv::PrefixSid(sid) => Some(IsisSrPrefix {
+            originator: originator.clone(),
+            prefix,
+            sid: sid.clone(),
+        }),
+        _ => None,
+    })
+}
+
+impl IsisSrResolver {
+    pub fn new(lsdb: &IsisLsdb) -> Self {
+        let mut resolver = Self::default();
+        for lsp in lsdb.iter().filter(|lsp| lsp.lsp_id.pseudo_id() == 0) {
+            let originator = lsp.lsp_id.sys_id();
+            let srgb = resolver.srgbs.entry(originator.clone()).or_default();
+            for tlv in &lsp.tlvs {
+                match tlv {
+                    IsisTlv::RouterCap(v) => {
+                        for sub in &v.subs {
+                            if let crate::cap::IsisSubTlv::SegmentRoutingCap(cap) = sub
+                                && let SidLabelTlv::Label(base) = cap.sid_label
+                            {
+                                srgb.ranges.push((base, cap.range));
+                            }
+                        }
+                    }
+                    IsisTlv::ExtIpReach(v) => {
+                        for entry in &v.entries {
+                            resolver.prefixes.extend(prefix_sids(
+                                &originator,
+                                entry.prefix.into(),
+                                &entry.subs,
+                            ));
+                        }
+                    }
+                    IsisTlv::MtIpReach(v) => {
+                        for entry in &v.entries {
+                            resolver.prefixes.extend(prefix_sids(
+                                &originator,
+                                entry.prefix.into(),
+                                &entry.subs,
+                            ));
+                        }
+                    }
+                    IsisTlv::Ipv6Reach(v) => {
+                        for entry in &v.entries {
+                            resolver.prefixes.extend(prefix_sids(
+                                &originator,
+                                entry.prefix.into(),
+                                &entry.subs,
+                            ));
+                        }
+                    }
+                    IsisTlv::MtIpv6Reach(v) => {
+                        for entry in &v.entries {
+                            resolver.prefixes.extend(prefix_sids(
+                                &originator,
+                                entry.prefix.into(),
+                                &entry.subs,
+                            ));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        resolver
+    }
+
+    /// Label `router` expects for the prefix SID. The originator expects
+    /// explicit NULL with the E flag, whatever the P flag, and implicit NULL
+    /// unless the P flag is set (RFC 8667 section 2.1.1). The N flag only
+    /// marks a Node-SID and does not change the label.
+    pub fn label(&self, router: &IsisSysId, prefix: &IsisSrPrefix) -> IsisSrLabel {
+        let flags = &prefix.sid.flags;
+        if *router == prefix.originator {
+            if flags.e_flag() {
+                return IsisSrLabel::ExplicitNull(match prefix.prefix {
+                    IpNet::V4(_) => IPV4_EXPLICIT_NULL,
+                    IpNet::V6(_) => IPV6_EXPLICIT_NULL,
+                });
+            }
+            if !flags.p_flag() {
+                return IsisSrLabel::ImplicitNull;
+            }
+        }
+        match prefix.sid.sid {
+            SidLabelValue::Label(label) if flags.v_flag() => {
+                if flags.l_flag() && *router != prefix.originator {
+                    IsisSrLabel::Invalid
+                } else {
+                    IsisSrLabel::Label(label)
+                }
+            }
+            SidLabelValue::Index(index) if !flags.v_flag() => {
+                match self.srgbs.get(router).filter(|srgb| !srgb.is_empty()) {
+                    None => IsisSrLabel::NoSrgb,
+                    Some(srgb) => srgb
+                        .label(index)
+                        .map_or(IsisSrLabel::OutOfRange(index), IsisSrLabel::Label),
+                }
+            }
+            _ => IsisSrLabel::Invalid,
+        }
+    }
+
+    /// Label `router` expects for `prefix` in `algo`, preferring its own
+    /// advertisement of an anycast prefix.
+    pub fn resolve(&self, router: &IsisSysId, prefix: &IpNet, algo: Algo) -> Option<IsisSrLabel> {
+        let mut sids = self
+            .prefixes
+            .iter()
+            .filter(|p| p.prefix == *prefix && p.sid.algo == algo);
+        let sid = sids
+            .clone()
+            .find(|p| p.originator == *router)
+            .or_else(|| sids.next())?;
+        Some(self.label(router, sid))
+    }
+
+    /// Label of every prefix SID on every router.
+    pub fn labels(&self) -> Vec<(&IsisSysId, &IsisSrPrefix, IsisSrLabel)> {
+        self.srgbs
+            .keys()
+            .flat_map(|router| {
+                self.prefixes
+                    .iter()
+                    .map(move |prefix| (router, prefix, self.label(router, prefix)))
+            })
+            .collect()
+    }
+}
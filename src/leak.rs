@@ -0,0 +1,256 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use ipnet::IpNet;
+
+use crate::prefix::IsisSubTlv as PrefixSubTlv;
+use crate::prefix::{Ipv4ControlInfo, Ipv6ControlInfo};
+use crate::{
+    IsisLsdb, IsisLspId, IsisNeighborId, IsisSpfGraph, IsisSysId, IsisTlv, IsisTlvExtIpReachEntry,
+    IsisTlvIpv6ReachEntry,
+};
+
+/// Inter-level policy of an L1/L2 router.
+#[derive(Debug, Default, Clone)]
+pub struct IsisLevelPolicy {
+    /// Summaries advertised into L2 in place of the L1 prefixes they cover.
+    pub summaries: Vec<IpNet>,
+    /// L2 prefixes covered by one of these are leaked into L1 (RFC 5302).
+    pub leak_down: Vec<IpNet>,
+    /// Never set the attached bit.
+    pub no_attached: bool,
+}
+
+/// Inter-level reachability of an L1/L2 router.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct IsisLevelLeak {
+    /// L1 reachability for the L2 LSP.
+    pub l2_ipv4: Vec<IsisTlvExtIpReachEntry>,
+    pub l2_ipv6: Vec<IsisTlvIpv6ReachEntry>,
+    /// L2 reachability for the L1 LSP with the up/down bit set.
+    pub l1_ipv4: Vec<IsisTlvExtIpReachEntry>,
+    pub l1_ipv6: Vec<IsisTlvIpv6ReachEntry>,
+    /// Attached bit of the L1 LSP, set when L2 reaches another area.
+    pub attached: bool,
+}
+
+/// Default route of an L1 router toward the closest attached L1/L2 routers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IsisL1Default {
+    pub metric: u32,
+    pub routers: BTreeSet<IsisSysId>,
+    pub nexthops: BTreeSet<IsisSysId>,
+}
+
+// Best reachability of a prefix within one level.
+#[derive(Debug, Clone)]
+struct Reach {
+    metric: u32,
+    up_down: bool,
+    external: bool,
+    originator: IsisSysId,
+    subs: Vec<PrefixSubTlv>,
+}
+
+// Prefixes reachable from `sys_id` with the SPF distance to the originator
+// added to the prefix metric.
+fn reachable(sys_id: &IsisSysId, lsdb: &IsisLsdb) -> BTreeMap<IpNet, Reach> {
+    let tree = IsisSpfGraph::new(lsdb).spf(&IsisNeighborId::from_sys_id(sys_id, 0));
+    let mut reach: BTreeMap<IpNet, Reach> = BTreeMap::new();
+    let mut add = |prefix: IpNet, entry: Reach| match reach.get(&prefix) {
+        Some(best) if best.metric <= entry.metric => {}
+        _ => {
+            reach.insert(prefix, entry);
+        }
+    };
+    for lsp in lsdb.iter().filter(|lsp| lsp.lsp_id.pseudo_id() == 0) {
+        let Some(dist) = tree.distance(&lsp.lsp_id.neighbor_id()) else {
+            continue;
+        };
+        for tlv in &lsp.tlvs {
+            match tlv {
+                IsisTlv::ExtIpReach(v) => {
+                    for entry in &v.entries {
+                        let value = Reach {
+                            metric: dist.saturating_add(entry.metric),
+                            up_down: entry.flags.distribution(),
+                            external: false,
+                            originator: lsp.lsp_id.sys_id(),
+                            subs: entry.subs.clone(),
+                        };
+                        add(entry.prefix.into(), value);
+                    }
+                }
+                IsisTlv::Ipv6Reach(v) => {
+                    for entry in &v.entries {
+                        let value = Reach {
+                            metric: dist.saturating_add(entry.metric),
+                            up_down: entry.flags.dist_up(),
+                            external: entry.flags.dist_internal(),
+                            originator: lsp.lsp_id.sys_id(),
+                            subs: entry.subs.clone(),
+                        };
+                        add(entry.prefix.into(), value);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    reach
+}
+
+// Prefix-SIDs propagated to the other level with the R flag (RFC 8667
+// section 2.1.1).
+fn propagated(subs: &[PrefixSubTlv]) -> Vec<PrefixSubTlv> {
+    subs.iter()
+        .filter_map(|sub| match sub {
+            PrefixSubTlv::PrefixSid(sid) => {
+                let mut sid = sid.clone();
+                sid.flags = sid.flags.with_r_flag(true);
+                Some(PrefixSubTlv::PrefixSid(sid))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn areas(sys_id: &IsisSysId, lsdb: &IsisLsdb) -> BTreeSet<Vec<u8>> {
+    lsdb.iter()
+        .filter(|lsp| lsp.lsp_id.sys_id() == *sys_id && lsp.lsp_id.pseudo_id() == 0)
+        .flat_map(|lsp| lsp.tlvs.iter())
+        .filter_map(|tlv| match tlv {
+            IsisTlv::AreaAddr(v) => Some(v.area_addr.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+impl IsisLevelLeak {
+    fn push(&mut self, l2: bool, prefix: IpNet, metric: u32, up_down: bool, reach: &Reach) {
+        let subs = propagated(&reach.subs);
+        match prefix {
+            IpNet::V4(prefix) => {
+                let entry = IsisTlvExtIpReachEntry {
+                    metric,
+                    flags: Ipv4ControlInfo::new()
+                        .with_prefixlen(prefix.prefix_len() as usize)
+                        .with_sub_tlv(!subs.is_empty())
+                        .with_distribution(up_down),
+                    prefix,
+                    subs,
+                };
+                let list = if l2 {
+                    &mut self.l2_ipv4
+                } else {
+                    &mut self.l1_ipv4
+                };
+                list.push(entry);
+            }
+            IpNet::V6(prefix) => {
+                let entry = IsisTlvIpv6ReachEntry {
+                    metric,
+                    flags: Ipv6ControlInfo::new()
+                        .with_sub_tlv(!subs.is_empty())
+                        .with_dist_internal(reach.external)
+                        .with_dist_up(up_down),
+                    prefix,
+                    subs,
+                };
+                let list = if l2 {
+                    &mut self.l2_ipv6
+                } else {
+                    &mut self.l1_ipv6
+                };
+                list.push(entry);
+            }
+        }
+    }
+}
+
+impl IsisLevelPolicy {
+    /// Inter-level reachability of L1/L2 router `sys_id` from its L1 and L2
+    /// databases.
+    pub fn apply(&self, sys_id: &IsisSysId, l1: &IsisLsdb, l2: &IsisLsdb) -> IsisLevelLeak {
+        let mut leak = IsisLevelLeak::default();
+        let l1_reach = reachable(sys_id, l1);
+        let l2_reach = reachable(sys_id, l2);
+
+        // L1 into L2, prefixes leaked down before stay in L1. Summaries take
+        // the lowest metric of the prefixes they cover.
+        let mut up: BTreeMap<IpNet, Reach> = BTreeMap::new();
+        for (prefix, reach) in l1_reach.iter().filter(|(_, r)| !r.up_down) {
+            match self.summaries.iter().find(|s| s.contains(prefix)) {
+                Some(summary) => {
+                    let best = up.entry(*summary).or_insert_with(|| Reach {
+                        subs: vec![],
+                        ..reach.clone()
+                    });
+                    best.metric = best.metric.min(reach.metric);
+                }
+                None => {
+                    up.insert(*prefix, reach.clone());
+                }
+            }
+        }
+        for (prefix, reach) in &up {
+            leak.push(true, *prefix, reach.metric, false, reach);
+        }
+
+        // L2 into L1 for the prefixes L1 does not reach itself.
+        for (prefix, reach) in &l2_reach {
+            if reach.originator != *sys_id
+                && !l1_reach.contains_key(prefix)
+                && self.leak_down.iter().any(|l| l.contains(prefix))
+            {
+                leak.push(false, *prefix, reach.metric, true, reach);
+            }
+        }
+
+        // Attached when L2 reaches a router outside the local areas.
+        let local = areas(sys_id, l1)
+            .into_iter()
+            .chain(areas(sys_id, l2))
+            .collect::<BTreeSet<_>>();
+        let tree = IsisSpfGraph::new(l2).spf(&IsisNeighborId::from_sys_id(sys_id, 0));
+        leak.attached = !self.no_attached
+            && tree
+                .dist
+                .keys()
+                .filter(|id| id.pseudo_id() == 0)
+                .map(|id| areas(&id.sys_id(), l2))
+                .any(|remote| !remote.is_empty() && remote.is_disjoint(&local));
+        leak
+    }
+}
+
+impl IsisLsdb {
+    /// Default route of L1 router `sys_id` toward the closest routers
+    /// setting the attached bit.
+    pub fn l1_default(&self, sys_id: &IsisSysId) -> Option<IsisL1Default> {
+        let tree = IsisSpfGraph::new(self).spf(&IsisNeighborId::from_sys_id(sys_id, 0));
+        let mut default: Option<IsisL1Default> = None;
+        for (id, dist) in &tree.dist {
+            let attached = self
+                .get(&IsisLspId::from_neighbor_id(id.clone(), 0))
+                .is_some_and(|lsp| lsp.types.att_bits() != 0);
+            if id.pseudo_id() != 0 || id.sys_id() == *sys_id || !attached {
+                continue;
+            }
+            match &mut default {
+                Some(best) if best.metric < *dist => {}
+                Some(best) if best.metric == *dist => {
+                    best.routers.insert(id.sys_id());
+                    best.nexthops.extend(tree.nexthops(id));
+                }
+                _ => {
+                    default = Some(IsisL1Default {
+                        metric: *dist,
+                        routers: BTreeSet::from([id.sys_id()]),
+                        nexthops: tree.nexthops(id),
+                    })
+                }
+            }
+        }
+        default
+    }
+}
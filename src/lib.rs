@@ -1,22 +1,63 @@
 mod algo;
+mod bgpls;
+mod buffer;
+mod capture;
 mod checksum;
+mod detail;
+mod dis;
 mod disp;
+mod encode;
 mod error;
+mod flex;
+mod flood;
+mod hostname;
+mod label;
+mod leak;
+mod lsdb;
 mod nsap;
+mod overload;
 mod padding;
 mod parser;
+mod proxy;
+mod snp;
+mod spf;
 mod sub;
+mod tilfa;
 mod tlv_type;
+mod topo;
 mod typ;
+mod update;
 mod util;
+mod view;
+mod yang;
 
 pub use algo::*;
+pub use bgpls::*;
+pub use capture::*;
 pub use checksum::*;
+pub use detail::*;
+pub use dis::*;
 pub use disp::*;
+pub use encode::*;
 pub use error::*;
+pub use flex::*;
+pub use flood::*;
+pub use hostname::*;
+pub use label::*;
+pub use leak::*;
+pub use lsdb::*;
 pub use nsap::Nsap;
+pub use overload::*;
+pub use padding::*;
 pub use parser::*;
+pub use proxy::*;
+pub use spf::*;
 pub use sub::*;
+pub use tilfa::*;
 pub use tlv_type::IsisTlvType;
+pub use topo::*;
 pub use typ::IsisType;
+pub use update::*;
 pub use util::write_hold_time;
+pub use view::*;
+pub use yang::*;
@@ -0,0 +1,87 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use crate::{IsisLsp, IsisLspEntry, IsisLspId};
+
+#[derive(Debug, Default, Clone)]
+pub struct IsisLsdb {
+    lsps: BTreeMap<IsisLspId, IsisLsp>,
+}
+
+impl IsisLsdb {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, lsp: IsisLsp) -> Option<IsisLsp> {
+        self.lsps.insert(lsp.lsp_id, lsp)
+    }
+
+    pub fn get(&self, lsp_id: &IsisLspId) -> Option<&IsisLsp> {
+        self.lsps.get(lsp_id)
+    }
+
+    pub fn get_mut(&mut self, lsp_id: &IsisLspId) -> Option<&mut IsisLsp> {
+        self.lsps.get_mut(lsp_id)
+    }
+
+    pub fn remove(&mut self, lsp_id: &IsisLspId) -> Option<IsisLsp> {
+        self.lsps.remove(lsp_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.lsps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lsps.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &IsisLsp> {
+        self.lsps.values()
+    }
+
+    pub fn range(&self, start: IsisLspId, end: IsisLspId) -> impl Iterator<Item = &IsisLsp> {
+        self.lsps.range(start..=end).map(|(_, lsp)| lsp)
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = IsisLspEntry> + '_ {
+        self.lsps.values().map(|lsp| lsp.entry())
+    }
+}
+
+impl FromIterator<IsisLsp> for IsisLsdb {
+    fn from_iter<T: IntoIterator<Item = IsisLsp>>(iter: T) -> Self {
+        let mut lsdb = Self::new();
+        for lsp in iter {
+            lsdb.insert(lsp);
+        }
+        lsdb
+    }
+}
+
+impl IsisLsp {
+    pub fn entry(&self) -> IsisLspEntry {
+        IsisLspEntry {
+            hold_time: self.hold_time,
+            lsp_id: self.lsp_id,
+            seq_number: self.seq_number,
+            checksum: self.checksum,
+        }
+    }
+}
+
+impl IsisLspEntry {
+    /// ISO 10589 7.3.16. `Greater` means `self` is the newer instance. With
+    /// the same sequence number a purged LSP (zero lifetime) wins.
+    pub fn compare(&self, other: &IsisLspEntry) -> Ordering {
+        match self.seq_number.cmp(&other.seq_number) {
+            Ordering::Equal => match (self.hold_time == 0, other.hold_time == 0) {
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                _ => Ordering::Equal,
+            },
+            ord => ord,
+        }
+    }
+}
@@ -2,9 +2,19 @@ use std::fmt;
 use std::num::ParseIntError;
 use std::str::FromStr;
 
-use crate::{IsisNeighborId, IsisSysId};
+use crate::{IsisNeighborId, IsisParseError, IsisSysId, IsisTlvAreaAddr};
 
-#[derive(Debug, Default)]
+// AFIs with a known area layout.
+const AFI_ISO_DCC: u8 = 0x39;
+const AFI_ISO_ICD: u8 = 0x47;
+const AFI_PRIVATE: u8 = 0x49;
+
+// IDI and HO-DSP of the ISO DCC and ICD formats: IDI(2), DFI(1), AA(3),
+// Reserved(2), RD(2) and Area(2).
+const AREA_ID_ISO_LEN: usize = 12;
+const AREA_ID_MAX_LEN: usize = 12;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Nsap {
     pub afi: u8,
     pub area_id: Vec<u8>,
@@ -64,6 +74,35 @@ impl Nsap {
         }
     }
 
+    /// Area address TLV of the area, AFI included.
+    pub fn area_addr(&self) -> IsisTlvAreaAddr {
+        IsisTlvAreaAddr {
+            area_addr: self.area_id(),
+        }
+    }
+
+    /// Check the area layout of the AFI: up to 13 octets for private
+    /// addresses and the full 20 octet NSAP for ISO DCC and ICD.
+    pub fn validate(&self) -> Result<(), IsisParseError> {
+        let invalid = |message: String| Err(IsisParseError::InvalidNsapAddress { message });
+        match self.afi {
+            AFI_PRIVATE if self.area_id.len() > AREA_ID_MAX_LEN => invalid(format!(
+                "private area of {} octets, at most {}",
+                self.area_id.len() + 1,
+                AREA_ID_MAX_LEN + 1
+            )),
+            AFI_PRIVATE => Ok(()),
+            AFI_ISO_DCC | AFI_ISO_ICD if self.area_id.len() != AREA_ID_ISO_LEN => invalid(format!(
+                "AFI {:02x} area of {} octets, expected {}",
+                self.afi,
+                self.area_id.len() + 1,
+                AREA_ID_ISO_LEN + 1
+            )),
+            AFI_ISO_DCC | AFI_ISO_ICD => Ok(()),
+            afi => invalid(format!("unsupported AFI {:02x}", afi)),
+        }
+    }
+
     pub fn neighbor_id(&self) -> IsisNeighborId {
         IsisNeighborId {
             id: [
@@ -79,6 +118,34 @@ impl Nsap {
     }
 }
 
+/// NET of the system in the area, with NSEL 0.
+impl From<(&IsisTlvAreaAddr, &IsisSysId)> for Nsap {
+    fn from((area, sys_id): (&IsisTlvAreaAddr, &IsisSysId)) -> Self {
+        let (afi, area_id) = match area.area_addr.split_first() {
+            Some((afi, area_id)) => (*afi, area_id.to_vec()),
+            None => (0, Vec::new()),
+        };
+        Nsap {
+            afi,
+            area_id,
+            sys_id: sys_id.id,
+            nsel: 0,
+        }
+    }
+}
+
+impl From<&Nsap> for IsisTlvAreaAddr {
+    fn from(nsap: &Nsap) -> Self {
+        nsap.area_addr()
+    }
+}
+
+impl From<&Nsap> for IsisSysId {
+    fn from(nsap: &Nsap) -> Self {
+        nsap.sys_id()
+    }
+}
+
 impl From<ParseIntError> for NsapParseError {
     fn from(_err: ParseIntError) -> Self {
         NsapParseError(())
@@ -0,0 +1,37 @@
+use crate::{IsisLsp, IsisNeighborId, IsisTlv};
+
+/// Highest link metric still used by SPF (RFC 5305 section 3.7).
+pub const MAX_LINK_METRIC: u32 = 0xFFFFFE;
+
+/// Link metric excluding the link from SPF (RFC 5305 section 3.7).
+pub const LINK_METRIC_EXCLUDED: u32 = 0xFFFFFF;
+
+impl IsisLsp {
+    /// Overload bit, only meaningful in fragment 0 (ISO 10589 section 7.3.4).
+    pub fn is_overloaded(&self) -> bool {
+        self.types.ol_bits()
+    }
+
+    pub fn set_overload(&mut self, overload: bool) {
+        self.types.set_ol_bits(overload);
+    }
+
+    /// Advertise every IS reachability at the maximum link metric so that
+    /// transit traffic moves away, and the links to `exclude` with the
+    /// metric removing them from SPF altogether. The 0xFE000000 maximum of
+    /// RFC 5305 applies to prefix metrics, links are 24-bit so the highest
+    /// usable one is `MAX_LINK_METRIC`.
+    pub fn set_max_metric(&mut self, exclude: &[IsisNeighborId]) {
+        for tlv in self.tlvs.iter_mut() {
+            if let IsisTlv::ExtIsReach(v) = tlv {
+                for entry in v.entries.iter_mut() {
+                    entry.metric = if exclude.contains(&entry.neighbor_id) {
+                        LINK_METRIC_EXCLUDED
+                    } else {
+                        MAX_LINK_METRIC
+                    };
+                }
+            }
+        }
+    }
+}
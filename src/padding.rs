@@ -1,59 +1,103 @@
 use bytes::BytesMut;
 
-use crate::{IsisHello, IsisPacket, IsisPdu, IsisTlv, IsisTlvPadding, IsisType, parse};
+use crate::{IsisHello, IsisPacket, IsisPdu, IsisTlv, IsisTlvPadding, IsisType};
 
-impl IsisHello {
-    pub fn padding(&mut self, mtu: usize) {
-        let mut buf = BytesMut::new();
+// 257 = 2 byte header + 255 byte padding
+const TLV_MAX: usize = 255;
+const TLV_OVERHEAD: usize = 2;
+const TLV_SIZE: usize = TLV_OVERHEAD + TLV_MAX;
 
-        // Try to emit Hello packet.
-        let packet = IsisPacket::from(IsisType::L1Hello, IsisPdu::L1Hello(self.clone()));
-        packet.emit(&mut buf);
+// LLC header ahead of the PDU on Ethernet.
+const LLC_LEN: usize = 3;
 
-        if parse(&buf).is_err() {
-            return;
-        }
+/// Which hellos are padded.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum IsisPaddingMode {
+    /// Every hello.
+    #[default]
+    Always,
+    /// Only hellos sent while the adjacency is not up, the MTU being
+    /// verified once at bring-up.
+    Sparse,
+}
 
-        // Make sure we don't underflow
-        let packet_len = buf.len();
-        let base_len = 3;
-        if packet_len + base_len > mtu {
-            // Not enough space for any padding
-            return;
-        }
+/// Hello padding of a circuit (ISO 10589 section 8.2.3).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IsisHelloPadding {
+    /// Largest payload of a frame on the link, 1500 on Ethernet.
+    pub mtu: usize,
+    /// Bytes ahead of the PDU within the payload, the LLC header on
+    /// Ethernet.
+    pub framing: usize,
+    /// Room kept for an authentication TLV added after padding.
+    pub auth_len: usize,
+    pub mode: IsisPaddingMode,
+}
 
-        let available_len = mtu - base_len - packet_len;
-        if available_len < 2 {
-            // Not enough space for even minimum padding TLV
-            return;
+impl IsisHelloPadding {
+    /// Padding of every hello on an Ethernet link.
+    pub fn new(mtu: usize) -> Self {
+        Self {
+            mtu,
+            framing: LLC_LEN,
+            auth_len: 0,
+            mode: IsisPaddingMode::Always,
         }
+    }
+}
 
-        // 257 = 2 byte header + 255 byte padding
-        const TLV_MAX: usize = 255;
-        const TLV_OVERHEAD: usize = 2;
-        const TLV_SIZE: usize = TLV_OVERHEAD + TLV_MAX;
-
-        let full_padding_count = available_len / TLV_SIZE;
-        let remaining = available_len % TLV_SIZE;
-
-        // Helper to generate Padding TLV
-        fn padding_tlv(len: usize) -> IsisTlv {
-            IsisTlv::Padding(IsisTlvPadding {
-                padding: vec![0u8; len],
-            })
+// Padding TLVs of exactly `len` bytes, the last full TLV is shortened when
+// the remainder would be too short for a TLV header.
+fn padding_tlvs(mut len: usize) -> Vec<IsisTlv> {
+    let mut tlvs = Vec::new();
+    while len > 0 {
+        let mut size = len.min(TLV_SIZE);
+        if len - size == 1 {
+            size -= 1;
         }
+        tlvs.push(IsisTlv::Padding(IsisTlvPadding {
+            padding: vec![0u8; size - TLV_OVERHEAD],
+        }));
+        len -= size;
+    }
+    tlvs
+}
 
-        // Add full (255-byte) padding TLVs
-        for _ in 0..full_padding_count {
-            self.tlvs.push(padding_tlv(TLV_MAX));
+impl IsisPacket {
+    /// Pad a L1, L2 or P2P hello so that the framing, the PDU and the
+    /// reserved authentication TLV fill the MTU exactly. `up` is the
+    /// adjacency state, sparse padding skips hellos of an up adjacency.
+    /// Returns false when the hello is left unpadded, including other PDUs
+    /// and hellos within one byte of the MTU.
+    pub fn pad_hello(&mut self, padding: &IsisHelloPadding, up: bool) -> bool {
+        if padding.mode == IsisPaddingMode::Sparse && up {
+            return false;
+        }
+        let mut buf = BytesMut::new();
+        self.emit(&mut buf);
+        let len = padding.framing + buf.len() + padding.auth_len;
+        let available = padding.mtu.saturating_sub(len);
+        if available < TLV_OVERHEAD {
+            return false;
         }
+        let tlvs = match &mut self.pdu {
+            IsisPdu::L1Hello(v) | IsisPdu::L2Hello(v) => &mut v.tlvs,
+            IsisPdu::P2pHello(v) => &mut v.tlvs,
+            _ => return false,
+        };
+        tlvs.extend(padding_tlvs(available));
+        true
+    }
+}
 
-        // Add the remainder as one smaller padding TLV (if enough room for TLV header)
-        if remaining > TLV_OVERHEAD {
-            let pad_len = remaining - TLV_OVERHEAD;
-            if pad_len > 0 {
-                self.tlvs.push(padding_tlv(pad_len));
-            }
+impl IsisHello {
+    /// Pad the LAN hello up to `mtu` on Ethernet.
+    pub fn padding(&mut self, mtu: usize) {
+        let mut packet = IsisPacket::from(IsisType::L1Hello, IsisPdu::L1Hello(self.clone()));
+        if packet.pad_hello(&IsisHelloPadding::new(mtu), false)
+            && let IsisPdu::L1Hello(hello) = packet.pdu
+        {
+            *self = hello;
         }
     }
 }
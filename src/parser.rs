@@ -1,19 +1,20 @@
 use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
 
 use bitfield_struct::bitfield;
 use byteorder::{BigEndian, ByteOrder};
 use bytes::{BufMut, BytesMut};
 use nom::bytes::complete::take;
-use nom::number::complete::{be_u8, be_u24, be_u32, be_u128};
+use nom::number::complete::{be_u8, be_u16, be_u24, be_u32, be_u128};
 use nom::{AsBytes, Err, IResult, Needed};
 use nom_derive::*;
-use serde::{Deserialize, Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use super::checksum_calc;
 use super::util::{ParseBe, TlvEmitter, many0, u32_u8_3};
+use super::{IsisParseError, checksum_calc};
 use super::{
-    IsisTlvExtIpReach, IsisTlvExtIsReach, IsisTlvIpv6Reach, IsisTlvMtIpReach, IsisTlvMtIpv6Reach,
-    IsisTlvRouterCap, IsisTlvSrv6, IsisTlvType, IsisType,
+    IsisTlvAreaProxy, IsisTlvExtIpReach, IsisTlvExtIsReach, IsisTlvIpv6Reach, IsisTlvMtIpReach,
+    IsisTlvMtIpv6Reach, IsisTlvReverseMetric, IsisTlvRouterCap, IsisTlvSrv6, IsisTlvType, IsisType,
 };
 
 // IS-IS discriminator.
@@ -23,7 +24,10 @@ const ISIS_IRDP_DISC: u8 = 0x83;
 pub const IPV4_ADDR_LEN: u8 = 4;
 pub const IPV6_ADDR_LEN: u8 = 16;
 
-#[derive(Debug, NomBE, Clone)]
+// LSP entry length in LSP Entries TLV.
+pub const LSP_ENTRY_LEN: u8 = 16;
+
+#[derive(Debug, NomBE, Clone, Serialize, Deserialize)]
 pub struct IsisPacket {
     #[nom(Verify = "*discriminator == ISIS_IRDP_DISC")]
     pub discriminator: u8,
@@ -37,6 +41,7 @@ pub struct IsisPacket {
     #[nom(Parse = "{ |x| IsisPdu::parse_be(x, pdu_type) }")]
     pub pdu: IsisPdu,
     #[nom(Ignore)]
+    #[serde(skip)]
     pub bytes: Vec<u8>,
 }
 
@@ -45,7 +50,7 @@ pub fn length_indicator(pdu_type: IsisType) -> u8 {
     match pdu_type {
         L1Hello => 27,
         L2Hello => 27,
-        P2PHello => 27,
+        P2PHello => 20,
         L1Lsp => 27,
         L2Lsp => 27,
         L1Csnp => 33,
@@ -72,6 +77,59 @@ impl IsisPacket {
         }
     }
 
+    /// TLVs of the PDU, empty for an unknown PDU.
+    pub fn tlvs(&self) -> &[IsisTlv] {
+        use IsisPdu::*;
+        match &self.pdu {
+            L1Hello(v) | L2Hello(v) => &v.tlvs,
+            P2pHello(v) => &v.tlvs,
+            L1Lsp(v) | L2Lsp(v) => &v.tlvs,
+            L1Csnp(v) | L2Csnp(v) => &v.tlvs,
+            L1Psnp(v) | L2Psnp(v) => &v.tlvs,
+            Unknown(_) => &[],
+        }
+    }
+
+    /// Instance ID TLV, `None` when it is missing or repeated.
+    pub fn instance_id_tlv(&self) -> Option<&IsisTlvInstanceId> {
+        let mut tlvs = self.tlvs().iter().filter_map(|tlv| match tlv {
+            IsisTlv::InstanceId(v) => Some(v),
+            _ => None,
+        });
+        match (tlvs.next(), tlvs.next()) {
+            (Some(tlv), None) => Some(tlv),
+            _ => None,
+        }
+    }
+
+    /// True when the PDU belongs to instance `iid`. PDUs without the
+    /// Instance ID TLV belong to the standard instance 0 and the ones with
+    /// several are discarded (RFC 8202 section 2).
+    pub fn is_instance(&self, iid: u16) -> bool {
+        let count = self
+            .tlvs()
+            .iter()
+            .filter(|tlv| matches!(tlv, IsisTlv::InstanceId(_)))
+            .count();
+        match count {
+            0 => iid == 0,
+            1 => self.instance_id_tlv().is_some_and(|tlv| tlv.iid == iid),
+            _ => false,
+        }
+    }
+
+    /// True when the PDU belongs to topology `itid` of instance `iid`, a
+    /// PDU without ITIDs carries the standard topology 0.
+    pub fn is_topology(&self, iid: u16, itid: u16) -> bool {
+        if !self.is_instance(iid) {
+            return false;
+        }
+        match self.instance_id_tlv() {
+            Some(tlv) if !tlv.itids.is_empty() => tlv.itids.contains(&itid),
+            _ => itid == 0,
+        }
+    }
+
     pub fn emit(&self, buf: &mut BytesMut) {
         use IsisPdu::*;
         buf.put_u8(self.discriminator);
@@ -85,6 +143,7 @@ impl IsisPacket {
         match &self.pdu {
             L1Hello(v) => v.emit(buf),
             L2Hello(v) => v.emit(buf),
+            P2pHello(v) => v.emit(buf),
             L1Lsp(v) => v.emit(buf),
             L2Lsp(v) => v.emit(buf),
             L1Csnp(v) => v.emit(buf),
@@ -94,6 +153,8 @@ impl IsisPacket {
             Unknown(_) => {}
         }
         if self.pdu_type.is_lsp() {
+            // Checksum is calculated with the field itself set to zero.
+            buf[24..26].copy_from_slice(&[0, 0]);
             let checksum = checksum_calc(&buf[12..]);
             buf[24..26].copy_from_slice(&checksum);
         }
@@ -108,6 +169,8 @@ pub enum IsisPdu {
     L1Hello(IsisHello),
     #[nom(Selector = "IsisType::L2Hello")]
     L2Hello(IsisHello),
+    #[nom(Selector = "IsisType::P2PHello")]
+    P2pHello(IsisP2pHello),
     #[nom(Selector = "IsisType::L1Lsp")]
     L1Lsp(IsisLsp),
     #[nom(Selector = "IsisType::L2Lsp")]
@@ -124,7 +187,7 @@ pub enum IsisPdu {
     Unknown(IsisUnknown),
 }
 
-#[derive(Debug, Default, NomBE, PartialOrd, Ord, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, NomBE, PartialOrd, Ord, PartialEq, Eq, Clone)]
 pub struct IsisSysId {
     pub id: [u8; 6],
 }
@@ -135,7 +198,7 @@ impl IsisSysId {
     }
 }
 
-#[derive(Debug, Default, NomBE, PartialOrd, Ord, PartialEq, Eq, Clone, Deserialize)]
+#[derive(Debug, Default, NomBE, PartialOrd, Ord, PartialEq, Eq, Clone)]
 pub struct IsisNeighborId {
     pub id: [u8; 7],
 }
@@ -172,16 +235,7 @@ impl IsisNeighborId {
     }
 }
 
-impl Serialize for IsisNeighborId {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_str(&self.to_string())
-    }
-}
-
-#[derive(Debug, Default, NomBE, PartialOrd, Ord, PartialEq, Eq, Clone, Copy, Deserialize)]
+#[derive(Debug, Default, NomBE, PartialOrd, Ord, PartialEq, Eq, Clone, Copy)]
 pub struct IsisLspId {
     pub id: [u8; 8],
 }
@@ -197,6 +251,13 @@ impl IsisLspId {
         }
     }
 
+    pub fn successor(&self) -> Self {
+        let id = u64::from_be_bytes(self.id).saturating_add(1);
+        Self {
+            id: id.to_be_bytes(),
+        }
+    }
+
     pub fn new(sys_id: IsisSysId, pseudo_id: u8, fragment_id: u8) -> Self {
         Self {
             id: [
@@ -260,6 +321,90 @@ impl IsisLspId {
     }
 }
 
+// Identifier in dotted hex such as 0000.0000.0001.00-00, the dots and the
+// dash are optional.
+fn id_from_str<const N: usize>(s: &str) -> Option<[u8; N]> {
+    let digits: String = s.chars().filter(|c| !matches!(c, '.' | '-')).collect();
+    hex::decode(digits).ok()?.try_into().ok()
+}
+
+impl FromStr for IsisSysId {
+    type Err = IsisParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match id_from_str(s) {
+            Some(id) => Ok(Self { id }),
+            None => Err(IsisParseError::InvalidSysId {
+                message: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl FromStr for IsisNeighborId {
+    type Err = IsisParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match id_from_str(s) {
+            Some(id) => Ok(Self { id }),
+            None => Err(IsisParseError::InvalidNeighborId {
+                message: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl FromStr for IsisLspId {
+    type Err = IsisParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match id_from_str(s) {
+            Some(id) => Ok(Self { id }),
+            None => Err(IsisParseError::InvalidLspId {
+                message: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl Serialize for IsisSysId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for IsisSysId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for IsisNeighborId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for IsisNeighborId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 impl Serialize for IsisLspId {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -269,6 +414,16 @@ impl Serialize for IsisLspId {
     }
 }
 
+impl<'de> Deserialize<'de> for IsisLspId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 impl From<IsisNeighborId> for IsisLspId {
     fn from(value: IsisNeighborId) -> Self {
         Self {
@@ -315,10 +470,12 @@ impl IsisLspTypes {
 
 #[derive(Debug, Default, NomBE, Clone, Serialize, Deserialize)]
 pub struct IsisLsp {
+    #[serde(default)]
     pub pdu_len: u16,
     pub hold_time: u16,
     pub lsp_id: IsisLspId,
     pub seq_number: u32,
+    #[serde(default)]
     pub checksum: u16,
     pub types: IsisLspTypes,
     #[nom(Parse = "IsisTlv::parse_tlvs")]
@@ -406,6 +563,7 @@ pub struct IsisHello {
     pub circuit_type: IsLevel,
     pub source_id: IsisSysId,
     pub hold_time: u16,
+    #[serde(default)]
     pub pdu_len: u16,
     pub priority: u8,
     pub lan_id: IsisNeighborId,
@@ -436,10 +594,86 @@ impl IsisHello {
             }
         })
     }
+
+    pub fn reverse_metric_tlv(&self) -> Option<&IsisTlvReverseMetric> {
+        reverse_metric_tlv(&self.tlvs)
+    }
+
+    /// Metric toward the sender adjusted by its Reverse Metric. On a LAN
+    /// only the DIS asks, with the W flag, for the metric toward the
+    /// pseudonode to be raised (RFC 8500 section 3.3).
+    pub fn reverse_metric(&self, metric: u32) -> u32 {
+        match self.reverse_metric_tlv() {
+            Some(tlv) if tlv.flags.w_flag() && self.lan_id.sys_id() == self.source_id => {
+                tlv.apply(metric)
+            }
+            _ => metric,
+        }
+    }
+}
+
+// Reverse Metric TLV shared by LAN and point-to-point hellos.
+fn reverse_metric_tlv(tlvs: &[IsisTlv]) -> Option<&IsisTlvReverseMetric> {
+    tlvs.iter().find_map(|tlv| match tlv {
+        IsisTlv::ReverseMetric(tlv) => Some(tlv),
+        _ => None,
+    })
+}
+
+/// Point-to-point IIH, without the priority and LAN ID of LAN hellos.
+#[derive(Debug, NomBE, Clone, Serialize, Deserialize)]
+pub struct IsisP2pHello {
+    pub circuit_type: IsLevel,
+    pub source_id: IsisSysId,
+    pub hold_time: u16,
+    #[serde(default)]
+    pub pdu_len: u16,
+    pub circuit_id: u8,
+    #[nom(Parse = "IsisTlv::parse_tlvs")]
+    pub tlvs: Vec<IsisTlv>,
+}
+
+impl IsisP2pHello {
+    pub fn emit(&self, buf: &mut BytesMut) {
+        buf.put_u8(self.circuit_type.into());
+        buf.put(&self.source_id.id[..]);
+        buf.put_u16(self.hold_time);
+        let pp = buf.len();
+        buf.put_u16(self.pdu_len);
+        buf.put_u8(self.circuit_id);
+        self.tlvs.iter().for_each(|tlv| tlv.emit(buf));
+        let pdu_len: u16 = buf.len() as u16;
+        BigEndian::write_u16(&mut buf[pp..pp + 2], pdu_len);
+    }
+
+    pub fn p2p_3way_tlv(&self) -> Option<&IsisTlvP2p3Way> {
+        self.tlvs.iter().find_map(|tlv| {
+            if let IsisTlv::P2p3Way(tlv) = tlv {
+                Some(tlv)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn reverse_metric_tlv(&self) -> Option<&IsisTlvReverseMetric> {
+        reverse_metric_tlv(&self.tlvs)
+    }
+
+    /// Metric toward the sender adjusted by its Reverse Metric. On a
+    /// point-to-point circuit it always applies, the W flag being
+    /// meaningful only on a LAN (RFC 8500 section 3.2).
+    pub fn reverse_metric(&self, metric: u32) -> u32 {
+        match self.reverse_metric_tlv() {
+            Some(tlv) => tlv.apply(metric),
+            None => metric,
+        }
+    }
 }
 
 #[derive(Debug, Default, NomBE, Clone, Serialize, Deserialize)]
 pub struct IsisCsnp {
+    #[serde(default)]
     pub pdu_len: u16,
     pub source_id: IsisSysId,
     pub source_id_circuit: u8,
@@ -465,6 +699,7 @@ impl IsisCsnp {
 
 #[derive(Debug, Default, NomBE, Clone, Serialize, Deserialize)]
 pub struct IsisPsnp {
+    #[serde(default)]
     pub pdu_len: u16,
     pub source_id: IsisSysId,
     pub source_id_curcuit: u8,
@@ -492,10 +727,24 @@ pub enum IsisTlv {
     AreaAddr(IsisTlvAreaAddr),
     #[nom(Selector = "IsisTlvType::IsNeighbor")]
     IsNeighbor(IsisTlvIsNeighbor),
+    #[nom(Selector = "IsisTlvType::InstanceId")]
+    InstanceId(IsisTlvInstanceId),
     #[nom(Selector = "IsisTlvType::Padding")]
     Padding(IsisTlvPadding),
     #[nom(Selector = "IsisTlvType::LspEntries")]
     LspEntries(IsisTlvLspEntries),
+    #[nom(Selector = "IsisTlvType::LspBufferSize")]
+    LspBufferSize(IsisTlvLspBufferSize),
+    #[nom(Selector = "IsisTlvType::ReverseMetric")]
+    ReverseMetric(IsisTlvReverseMetric),
+    #[nom(Selector = "IsisTlvType::AreaSysIds")]
+    AreaSysIds(IsisTlvAreaSysIds),
+    #[nom(Selector = "IsisTlvType::FloodingPath")]
+    FloodingPath(IsisTlvFloodingPath),
+    #[nom(Selector = "IsisTlvType::FloodingRequest")]
+    FloodingRequest(IsisTlvFloodingRequest),
+    #[nom(Selector = "IsisTlvType::AreaProxy")]
+    AreaProxy(IsisTlvAreaProxy),
     #[nom(Selector = "IsisTlvType::ExtIsReach")]
     ExtIsReach(IsisTlvExtIsReach),
     #[nom(Selector = "IsisTlvType::Srv6")]
@@ -536,8 +785,15 @@ impl IsisTlv {
         match self {
             AreaAddr(v) => v.tlv_emit(buf),
             IsNeighbor(v) => v.tlv_emit(buf),
+            InstanceId(v) => v.tlv_emit(buf),
             Padding(v) => v.tlv_emit(buf),
             LspEntries(v) => v.tlv_emit(buf),
+            LspBufferSize(v) => v.tlv_emit(buf),
+            ReverseMetric(v) => v.tlv_emit(buf),
+            AreaSysIds(v) => v.tlv_emit(buf),
+            FloodingPath(v) => v.tlv_emit(buf),
+            FloodingRequest(v) => v.tlv_emit(buf),
+            AreaProxy(v) => v.tlv_emit(buf),
             ExtIsReach(v) => v.tlv_emit(buf),
             Srv6(v) => v.tlv_emit(buf),
             ProtoSupported(v) => v.tlv_emit(buf),
@@ -674,7 +930,7 @@ impl TlvEmitter for IsisTlvLspEntries {
     }
 
     fn len(&self) -> u8 {
-        (self.entries.len() * std::mem::size_of::<IsisLspEntry>()) as u8
+        (self.entries.len() * LSP_ENTRY_LEN as usize) as u8
     }
 
     fn emit(&self, buf: &mut BytesMut) {
@@ -767,6 +1023,33 @@ impl From<IsisTlvIpv4IfAddr> for IsisTlv {
     }
 }
 
+/// Largest LSP the originator can receive, the originatingLSPBufferSize of
+/// ISO 10589 section 9.8.
+#[derive(Debug, NomBE, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IsisTlvLspBufferSize {
+    pub size: u16,
+}
+
+impl TlvEmitter for IsisTlvLspBufferSize {
+    fn typ(&self) -> u8 {
+        IsisTlvType::LspBufferSize.into()
+    }
+
+    fn len(&self) -> u8 {
+        2
+    }
+
+    fn emit(&self, buf: &mut BytesMut) {
+        buf.put_u16(self.size)
+    }
+}
+
+impl From<IsisTlvLspBufferSize> for IsisTlv {
+    fn from(tlv: IsisTlvLspBufferSize) -> Self {
+        IsisTlv::LspBufferSize(tlv)
+    }
+}
+
 #[derive(Debug, NomBE, Clone, Serialize, Deserialize, PartialEq)]
 pub struct IsisTlvTeRouterId {
     pub router_id: Ipv4Addr,
@@ -923,6 +1206,169 @@ impl From<IsisTlvP2p3Way> for IsisTlv {
     }
 }
 
+/// Instance Identifier TLV with the topologies of the instance (RFC 8202).
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IsisTlvInstanceId {
+    pub iid: u16,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub itids: Vec<u16>,
+}
+
+impl ParseBe<IsisTlvInstanceId> for IsisTlvInstanceId {
+    fn parse_be(input: &[u8]) -> IResult<&[u8], Self> {
+        let (input, iid) = be_u16(input)?;
+        let (input, itids) = many0(be_u16)(input)?;
+        Ok((input, Self { iid, itids }))
+    }
+}
+
+impl TlvEmitter for IsisTlvInstanceId {
+    fn typ(&self) -> u8 {
+        IsisTlvType::InstanceId.into()
+    }
+
+    fn len(&self) -> u8 {
+        (2 + self.itids.len() * 2) as u8
+    }
+
+    fn emit(&self, buf: &mut BytesMut) {
+        buf.put_u16(self.iid);
+        self.itids.iter().for_each(|itid| buf.put_u16(*itid));
+    }
+}
+
+impl From<IsisTlvInstanceId> for IsisTlv {
+    fn from(tlv: IsisTlvInstanceId) -> Self {
+        IsisTlv::InstanceId(tlv)
+    }
+}
+
+#[bitfield(u8, debug = true)]
+#[derive(Serialize, Deserialize, PartialEq)]
+pub struct AreaSysIdsFlags {
+    #[bits(7)]
+    pub resvd: u8,
+    pub l_flag: bool,
+}
+
+impl ParseBe<AreaSysIdsFlags> for AreaSysIdsFlags {
+    fn parse_be(input: &[u8]) -> IResult<&[u8], Self> {
+        let (input, flags) = be_u8(input)?;
+        Ok((input, flags.into()))
+    }
+}
+
+/// Area System IDs TLV of the Area Leader, the L flag marks the last one
+/// (RFC 9667 section 5.2.1).
+#[derive(Debug, NomBE, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IsisTlvAreaSysIds {
+    pub start: u16,
+    pub flags: AreaSysIdsFlags,
+    pub sys_ids: Vec<IsisSysId>,
+}
+
+impl TlvEmitter for IsisTlvAreaSysIds {
+    fn typ(&self) -> u8 {
+        IsisTlvType::AreaSysIds.into()
+    }
+
+    fn len(&self) -> u8 {
+        (3 + self.sys_ids.len() * 6) as u8
+    }
+
+    fn emit(&self, buf: &mut BytesMut) {
+        buf.put_u16(self.start);
+        buf.put_u8(self.flags.into());
+        for sys_id in self.sys_ids.iter() {
+            buf.put(&sys_id.id[..]);
+        }
+    }
+}
+
+impl From<IsisTlvAreaSysIds> for IsisTlv {
+    fn from(tlv: IsisTlvAreaSysIds) -> Self {
+        IsisTlv::AreaSysIds(tlv)
+    }
+}
+
+/// Flooding Path TLV, a path over the flooding topology as indices into the
+/// Area System IDs (RFC 9667 section 5.2.3).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IsisTlvFloodingPath {
+    pub path: Vec<u16>,
+}
+
+impl ParseBe<IsisTlvFloodingPath> for IsisTlvFloodingPath {
+    fn parse_be(input: &[u8]) -> IResult<&[u8], Self> {
+        let (input, path) = many0(be_u16)(input)?;
+        Ok((input, Self { path }))
+    }
+}
+
+impl TlvEmitter for IsisTlvFloodingPath {
+    fn typ(&self) -> u8 {
+        IsisTlvType::FloodingPath.into()
+    }
+
+    fn len(&self) -> u8 {
+        (self.path.len() * 2) as u8
+    }
+
+    fn emit(&self, buf: &mut BytesMut) {
+        self.path.iter().for_each(|index| buf.put_u16(*index));
+    }
+}
+
+impl From<IsisTlvFloodingPath> for IsisTlv {
+    fn from(tlv: IsisTlvFloodingPath) -> Self {
+        IsisTlv::FloodingPath(tlv)
+    }
+}
+
+#[bitfield(u8, debug = true)]
+#[derive(Serialize, Deserialize, PartialEq)]
+pub struct FloodingScope {
+    #[bits(7)]
+    pub scope: u8,
+    pub r_flag: bool,
+}
+
+impl ParseBe<FloodingScope> for FloodingScope {
+    fn parse_be(input: &[u8]) -> IResult<&[u8], Self> {
+        let (input, scope) = be_u8(input)?;
+        Ok((input, scope.into()))
+    }
+}
+
+/// Flooding Request TLV of IIHs asking for temporary flooding on the
+/// circuit (RFC 9667 section 5.2.4).
+#[derive(Debug, NomBE, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IsisTlvFloodingRequest {
+    pub scopes: Vec<FloodingScope>,
+}
+
+impl TlvEmitter for IsisTlvFloodingRequest {
+    fn typ(&self) -> u8 {
+        IsisTlvType::FloodingRequest.into()
+    }
+
+    fn len(&self) -> u8 {
+        self.scopes.len() as u8
+    }
+
+    fn emit(&self, buf: &mut BytesMut) {
+        self.scopes
+            .iter()
+            .for_each(|scope| buf.put_u8((*scope).into()));
+    }
+}
+
+impl From<IsisTlvFloodingRequest> for IsisTlv {
+    fn from(tlv: IsisTlvFloodingRequest) -> Self {
+        IsisTlv::FloodingRequest(tlv)
+    }
+}
+
 #[derive(Debug, Default, NomBE, Clone, Serialize, Deserialize, PartialEq)]
 pub struct IsisTlvUnknown {
     pub typ: IsisTlvType,
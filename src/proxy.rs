@@ -0,0 +1,239 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use bytes::BytesMut;
+use ipnet::{Ipv4Net, Ipv6Net};
+
+use crate::area_proxy::IsisSubTlv as AreaProxySubTlv;
+use crate::dis::EXT_IS_REACH_ENTRY_MAX;
+use crate::{
+    IsisLsdb, IsisLsp, IsisLspId, IsisLspTypes, IsisNeighborId, IsisSubAreaSid, IsisSysId, IsisTlv,
+    IsisTlvAreaAddr, IsisTlvAreaProxy, IsisTlvExtIpReach, IsisTlvExtIpReachEntry,
+    IsisTlvExtIsReach, IsisTlvExtIsReachEntry, IsisTlvIpv6Reach, IsisTlvIpv6ReachEntry,
+    IsisTlvProtoSupported,
+};
+
+// Common header and LSP header, the rest of the buffer carries TLVs.
+const LSP_HEADER_LEN: usize = 27;
+
+// Split entries into TLV values of at most 255 bytes.
+fn chunks<T>(entries: impl IntoIterator<Item = T>, len: impl Fn(&T) -> usize) -> Vec<Vec<T>> {
+    let mut chunks = Vec::new();
+    let mut chunk = Vec::new();
+    let mut chunk_len = 0;
+    for entry in entries {
+        let entry_len = len(&entry);
+        if chunk_len + entry_len > 255 && !chunk.is_empty() {
+            chunks.push(std::mem::take(&mut chunk));
+            chunk_len = 0;
+        }
+        chunk_len += entry_len;
+        chunk.push(entry);
+    }
+    if !chunk.is_empty() {
+        chunks.push(chunk);
+    }
+    chunks
+}
+
+/// Area Proxy view of an L2 database, the inside nodes being the ones
+/// advertising the Area Proxy TLV (RFC 9666).
+#[derive(Debug, Clone, PartialEq)]
+pub struct IsisAreaProxy {
+    /// System ID of the Proxy LSP from the Area Leader.
+    pub proxy_id: IsisSysId,
+    pub inside: BTreeSet<IsisSysId>,
+    pub area_sid: Option<IsisSubAreaSid>,
+}
+
+impl IsisAreaProxy {
+    /// Inside nodes of `lsdb`, `None` without a Proxy System ID. The first
+    /// Area Leader in LSP ID order wins.
+    pub fn new(lsdb: &IsisLsdb) -> Option<Self> {
+        let mut inside = BTreeSet::new();
+        let mut leader: Option<&IsisTlvAreaProxy> = None;
+        for lsp in lsdb.iter().filter(|lsp| lsp.lsp_id.pseudo_id() == 0) {
+            for tlv in &lsp.tlvs {
+                if let IsisTlv::AreaProxy(v) = tlv {
+                    inside.insert(lsp.lsp_id.sys_id());
+                    if leader.is_none() && v.proxy_sys_id().is_some() {
+                        leader = Some(v);
+                    }
+                }
+            }
+        }
+        let leader = leader?;
+        let proxy_id = leader.proxy_sys_id()?.clone();
+        // A Proxy LSP already in the database carries an Area Proxy TLV for
+        // the Area Segment SID, yet it stands for the area and is not an
+        // inside node.
+        inside.remove(&proxy_id);
+        Some(Self {
+            proxy_id,
+            inside,
+            area_sid: leader.area_sid().cloned(),
+        })
+    }
+
+    // Outside nodes reached through a neighbor: the neighbor itself when
+    // outside, none for an inside router, and the outside members of a LAN
+    // whose DIS is inside.
+    fn outside(&self, lsdb: &IsisLsdb, id: &IsisNeighborId) -> Vec<IsisNeighborId> {
+        let sys_id = id.sys_id();
+        if !self.inside.contains(&sys_id) {
+            return vec![id.clone()];
+        }
+        if id.pseudo_id() == 0 {
+            return vec![];
+        }
+        let start = IsisLspId::new(sys_id.clone(), id.pseudo_id(), 0);
+        let end = IsisLspId::new(sys_id, id.pseudo_id(), 0xff);
+        lsdb.range(start, end)
+            .flat_map(|lsp| &lsp.tlvs)
+            .filter_map(|tlv| match tlv {
+                IsisTlv::ExtIsReach(v) => Some(&v.entries),
+                _ => None,
+            })
+            .flatten()
+            .filter(|entry| !self.inside.contains(&entry.neighbor_id.sys_id()))
+            .map(|entry| entry.neighbor_id.clone())
+            .collect()
+    }
+
+    /// Proxy LSP fragments standing for the whole area. Links between
+    /// inside nodes are hidden, adjacencies to outside nodes and the inside
+    /// prefixes are advertised once with their lowest metric. Outside
+    /// routers on a LAN with an inside DIS become direct adjacencies. Each
+    /// fragment fits into `buffer_size`, TLVs beyond fragment 255 are
+    /// dropped.
+    pub fn lsp(
+        &self,
+        lsdb: &IsisLsdb,
+        seq_number: u32,
+        hold_time: u16,
+        buffer_size: u16,
+    ) -> Vec<IsisLsp> {
+        let mut areas: BTreeSet<Vec<u8>> = BTreeSet::new();
+        let mut nlpids: BTreeSet<u8> = BTreeSet::new();
+        let mut neighbors: BTreeMap<IsisNeighborId, u32> = BTreeMap::new();
+        let mut ipv4: BTreeMap<Ipv4Net, IsisTlvExtIpReachEntry> = BTreeMap::new();
+        let mut ipv6: BTreeMap<Ipv6Net, IsisTlvIpv6ReachEntry> = BTreeMap::new();
+
+        for lsp in lsdb.iter() {
+            if lsp.lsp_id.pseudo_id() != 0 || !self.inside.contains(&lsp.lsp_id.sys_id()) {
+                continue;
+            }
+            for tlv in &lsp.tlvs {
+                match tlv {
+                    IsisTlv::AreaAddr(v) => {
+                        areas.insert(v.area_addr.clone());
+                    }
+                    IsisTlv::ProtoSupported(v) => nlpids.extend(&v.nlpids),
+                    IsisTlv::ExtIsReach(v) => {
+                        for entry in &v.entries {
+                            for neighbor_id in self.outside(lsdb, &entry.neighbor_id) {
+                                let metric = neighbors.entry(neighbor_id).or_insert(entry.metric);
+                                *metric = (*metric).min(entry.metric);
+                            }
+                        }
+                    }
+                    IsisTlv::ExtIpReach(v) => {
+                        for entry in &v.entries {
+                            match ipv4.get(&entry.prefix) {
+                                Some(best) if best.metric <= entry.metric => {}
+                                _ => {
+                                    ipv4.insert(entry.prefix, entry.clone());
+                                }
+                            }
+                        }
+                    }
+                    IsisTlv::Ipv6Reach(v) => {
+                        for entry in &v.entries {
+                            match ipv6.get(&entry.prefix) {
+                                Some(best) if best.metric <= entry.metric => {}
+                                _ => {
+                                    ipv6.insert(entry.prefix, entry.clone());
+                                }
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut tlvs: Vec<IsisTlv> = areas
+            .into_iter()
+            .map(|area_addr| IsisTlvAreaAddr { area_addr }.into())
+            .collect();
+        if !nlpids.is_empty() {
+            tlvs.push(
+                IsisTlvProtoSupported {
+                    nlpids: nlpids.into_iter().collect(),
+                }
+                .into(),
+            );
+        }
+        if let Some(sid) = &self.area_sid {
+            tlvs.push(
+                IsisTlvAreaProxy {
+                    subs: vec![AreaProxySubTlv::AreaSid(sid.clone())],
+                }
+                .into(),
+            );
+        }
+        let neighbors: Vec<IsisTlvExtIsReachEntry> = neighbors
+            .into_iter()
+            .map(|(neighbor_id, metric)| IsisTlvExtIsReachEntry {
+                neighbor_id,
+                metric,
+                subs: vec![],
+            })
+            .collect();
+        for chunk in neighbors.chunks(EXT_IS_REACH_ENTRY_MAX) {
+            tlvs.push(
+                IsisTlvExtIsReach {
+                    entries: chunk.to_vec(),
+                }
+                .into(),
+            );
+        }
+        for entries in chunks(ipv4.into_values(), |entry| entry.len() as usize) {
+            tlvs.push(IsisTlvExtIpReach { entries }.into());
+        }
+        for entries in chunks(ipv6.into_values(), |entry| entry.len() as usize) {
+            tlvs.push(IsisTlvIpv6Reach { entries }.into());
+        }
+
+        // Fill fragments up to the buffer size, the area addresses and
+        // protocols coming first land in fragment 0.
+        let mut fragments: Vec<Vec<IsisTlv>> = vec![vec![]];
+        let mut len = LSP_HEADER_LEN;
+        for tlv in tlvs {
+            let mut buf = BytesMut::new();
+            tlv.emit(&mut buf);
+            let fragment = fragments.last_mut().unwrap();
+            if len + buf.len() > buffer_size as usize && !fragment.is_empty() {
+                fragments.push(vec![tlv]);
+                len = LSP_HEADER_LEN + buf.len();
+            } else {
+                fragment.push(tlv);
+                len += buf.len();
+            }
+        }
+
+        fragments
+            .into_iter()
+            .take(256)
+            .enumerate()
+            .map(|(fragment_id, tlvs)| IsisLsp {
+                pdu_len: 0,
+                hold_time,
+                lsp_id: IsisLspId::new(self.proxy_id.clone(), 0, fragment_id as u8),
+                seq_number,
+                checksum: 0,
+                types: IsisLspTypes::new().with_is_bits(3),
+                tlvs,
+            })
+            .collect()
+    }
+}
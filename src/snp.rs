@@ -0,0 +1,113 @@
+use crate::{
+    IsisCsnp, IsisLspEntry, IsisLspId, IsisPsnp, IsisSysId, IsisTlv, IsisTlvLspEntries,
+    LSP_ENTRY_LEN,
+};
+
+// 802.3 LLC header (DSAP, SSAP, control) in front of every PDU.
+const LLC_LEN: usize = 3;
+
+// Common header(8) + PDU length(2) + Source ID(7) + Start(8) + End(8).
+const CSNP_HEADER_LEN: usize = 33;
+
+// Common header(8) + PDU length(2) + Source ID(7).
+const PSNP_HEADER_LEN: usize = 17;
+
+// 15 entries of 16 bytes fit into one 255 byte TLV.
+const LSP_ENTRIES_PER_TLV: usize = 255 / LSP_ENTRY_LEN as usize;
+
+// Number of LSP entries which fit into a PDU with the header length when the
+// frame is limited to the MTU.
+fn entries_per_pdu(mtu: usize, header_len: usize) -> usize {
+    let entry_len = LSP_ENTRY_LEN as usize;
+    let tlv_len = 2 + LSP_ENTRIES_PER_TLV * entry_len;
+    let avail = mtu.saturating_sub(LLC_LEN + header_len);
+    let rest = avail % tlv_len;
+    let partial = if rest > 2 { (rest - 2) / entry_len } else { 0 };
+    (avail / tlv_len) * LSP_ENTRIES_PER_TLV + partial
+}
+
+fn entries_tlvs(entries: &[IsisLspEntry]) -> Vec<IsisTlv> {
+    entries
+        .chunks(LSP_ENTRIES_PER_TLV)
+        .map(|chunk| {
+            IsisTlv::LspEntries(IsisTlvLspEntries {
+                entries: chunk.to_vec(),
+            })
+        })
+        .collect()
+}
+
+fn tlvs_entries(tlvs: &[IsisTlv]) -> impl Iterator<Item = &IsisLspEntry> {
+    tlvs.iter()
+        .filter_map(|tlv| match tlv {
+            IsisTlv::LspEntries(tlv) => Some(tlv.entries.iter()),
+            _ => None,
+        })
+        .flatten()
+}
+
+impl IsisCsnp {
+    /// Build CSNPs from LSP entries sorted by LSP ID. Each PDU fits into the
+    /// MTU and the `start`/`end` ranges of the PDUs tile the whole LSP ID
+    /// space, so an empty database still yields one CSNP.
+    pub fn build<I>(source_id: &IsisSysId, entries: I, mtu: usize) -> Vec<IsisCsnp>
+    where
+        I: IntoIterator<Item = IsisLspEntry>,
+    {
+        let entries: Vec<IsisLspEntry> = entries.into_iter().collect();
+        let per_pdu = entries_per_pdu(mtu, CSNP_HEADER_LEN).max(1);
+
+        let mut csnps = Vec::new();
+        let mut start = IsisLspId::start();
+        let mut chunks = entries.chunks(per_pdu).peekable();
+        loop {
+            let chunk = chunks.next().unwrap_or(&[]);
+            let last = chunks.peek().is_none();
+            let end = match chunk.last() {
+                Some(entry) if !last => entry.lsp_id,
+                _ => IsisLspId::end(),
+            };
+            csnps.push(IsisCsnp {
+                pdu_len: 0,
+                source_id: source_id.clone(),
+                source_id_circuit: 0,
+                start,
+                end,
+                tlvs: entries_tlvs(chunk),
+            });
+            if last {
+                break;
+            }
+            start = end.successor();
+        }
+        csnps
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &IsisLspEntry> {
+        tlvs_entries(&self.tlvs)
+    }
+}
+
+impl IsisPsnp {
+    /// Batch LSP entries into as few PSNPs as the MTU allows.
+    pub fn build<I>(source_id: &IsisSysId, entries: I, mtu: usize) -> Vec<IsisPsnp>
+    where
+        I: IntoIterator<Item = IsisLspEntry>,
+    {
+        let entries: Vec<IsisLspEntry> = entries.into_iter().collect();
+        let per_pdu = entries_per_pdu(mtu, PSNP_HEADER_LEN).max(1);
+        entries
+            .chunks(per_pdu)
+            .map(|chunk| IsisPsnp {
+                pdu_len: 0,
+                source_id: source_id.clone(),
+                source_id_curcuit: 0,
+                tlvs: entries_tlvs(chunk),
+            })
+            .collect()
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &IsisLspEntry> {
+        tlvs_entries(&self.tlvs)
+    }
+}
@@ -0,0 +1,351 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap};
+use std::net::Ipv6Addr;
+
+use ipnet::IpNet;
+
+use crate::neigh::IsisSubTlv as NeighSubTlv;
+use crate::prefix::IsisSubTlv as PrefixSubTlv;
+use crate::prefix::Srv6Locator;
+use crate::{
+    Algo, IsisLsdb, IsisNeighborId, IsisSrgb, IsisSubFlexAlgoDef, IsisSubPrefixSid, IsisSysId,
+    IsisTlv, LINK_METRIC_EXCLUDED, SidLabelTlv, SidLabelValue,
+};
+
+/// Link of the SPF graph from one node to a neighbor.
+#[derive(Debug, Clone)]
+pub struct IsisSpfLink {
+    pub to: IsisNeighborId,
+    pub metric: u32,
+    pub subs: Vec<NeighSubTlv>,
+}
+
+impl IsisSpfLink {
+    /// Adjacency SID toward `neighbor`. On a LAN the pseudonode link carries
+    /// one LAN Adj-SID per neighbor.
+    pub fn adj_sid(&self, neighbor: &IsisSysId) -> Option<SidLabelValue> {
+        self.subs.iter().find_map(|sub| match sub {
+            NeighSubTlv::AdjSid(v) if self.to.sys_id() == *neighbor => Some(v.sid.clone()),
+            NeighSubTlv::LanAdjSid(v) if v.system_id == *neighbor => Some(v.sid.clone()),
+            _ => None,
+        })
+    }
+
+    /// SRv6 End.X SID toward `neighbor`.
+    pub fn end_x_sid(&self, neighbor: &IsisSysId) -> Option<Ipv6Addr> {
+        self.subs.iter().find_map(|sub| match sub {
+            NeighSubTlv::Srv6EndXSid(v) if self.to.sys_id() == *neighbor => Some(v.sid),
+            NeighSubTlv::Srv6LanEndXSid(v) if v.system_id == *neighbor => Some(v.sid),
+            _ => None,
+        })
+    }
+}
+
+/// Router or pseudonode with the links and segment routing information from
+/// all of its LSP fragments.
+#[derive(Debug, Default, Clone)]
+pub struct IsisSpfNode {
+    pub hostname: Option<String>,
+    pub links: Vec<IsisSpfLink>,
+    pub srgb: IsisSrgb,
+    /// SRLB ranges, Adj-SID indexes are local labels (RFC 8667 2.2.1).
+    pub srlb: IsisSrgb,
+    /// Prefix-SID of the node, algorithm 0 with the N flag.
+    pub node_sid: Option<IsisSubPrefixSid>,
+    /// SRv6 End SID of the node.
+    pub end_sid: Option<Ipv6Addr>,
+    /// Algorithms of the SR-Algorithm sub-TLV.
+    pub algos: Vec<Algo>,
+    pub fads: Vec<IsisSubFlexAlgoDef>,
+    pub prefixes: Vec<IsisSpfPrefix>,
+    pub locators: Vec<Srv6Locator>,
+    /// Overload bit of fragment 0, no transit through the node.
+    pub overload: bool,
+}
+
+/// IP prefix advertised by a node with its Prefix-SIDs.
+#[derive(Debug, Clone)]
+pub struct IsisSpfPrefix {
+    pub prefix: IpNet,
+    pub metric: u32,
+    pub sids: Vec<IsisSubPrefixSid>,
+}
+
+impl IsisSpfNode {
+    fn add_prefix(&mut self, prefix: IpNet, metric: u32, subs: &[PrefixSubTlv]) {
+        let sids: Vec<IsisSubPrefixSid> = subs
+            .iter()
+            .filter_map(|sub| match sub {
+                PrefixSubTlv::PrefixSid(sid) => Some(sid.clone()),
+                _ => None,
+            })
+            .collect();
+        if self.node_sid.is_none() {
+            self.node_sid = sids
+                .iter()
+                .find(|sid| sid.flags.n_flag() && sid.algo == Algo::Spf)
+                .cloned();
+        }
+        self.prefixes.push(IsisSpfPrefix {
+            prefix,
+            metric,
+            sids,
+        });
+    }
+
+    /// MPLS label of the node SID as seen by a router with `srgb`.
+    pub fn node_label(&self, srgb: &IsisSrgb) -> Option<u32> {
+        let sid = self.node_sid.as_ref()?;
+        match sid.sid {
+            SidLabelValue::Label(label) => Some(label),
+            SidLabelValue::Index(index) => srgb.label(index),
+        }
+    }
+}
+
+/// Topology resources left out of an SPF run.
+#[derive(Debug, Default, Clone)]
+pub struct IsisSpfExclude {
+    /// Link in both directions.
+    pub link: Option<(IsisNeighborId, IsisNeighborId)>,
+    pub node: Option<IsisNeighborId>,
+}
+
+impl IsisSpfExclude {
+    fn excludes(&self, from: &IsisNeighborId, to: &IsisNeighborId) -> bool {
+        if let Some((a, b)) = &self.link
+            && ((from == a && to == b) || (from == b && to == a))
+        {
+            return true;
+        }
+        self.node.as_ref().is_some_and(|node| node == to)
+    }
+}
+
+/// Graph of one level for SPF computation.
+#[derive(Debug, Default, Clone)]
+pub struct IsisSpfGraph {
+    pub nodes: BTreeMap<IsisNeighborId, IsisSpfNode>,
+}
+
+impl IsisSpfGraph {
+    pub fn new(lsdb: &IsisLsdb) -> Self {
+        let mut nodes: BTreeMap<IsisNeighborId, IsisSpfNode> = BTreeMap::new();
+        for lsp in lsdb.iter() {
+            let node = nodes.entry(lsp.lsp_id.neighbor_id()).or_default();
+            if lsp.lsp_id.fragment_id() == 0 && !lsp.lsp_id.is_pseudo() {
+                node.overload = lsp.is_overloaded();
+            }
+            for tlv in &lsp.tlvs {
+                match tlv {
+                    IsisTlv::Hostname(v) => node.hostname = Some(v.hostname.clone()),
+                    IsisTlv::ExtIsReach(v) => {
+                        node.links.extend(v.entries.iter().map(|entry| IsisSpfLink {
+                            to: entry.neighbor_id.clone(),
+                            metric: entry.metric,
+                            subs: entry.subs.clone(),
+                        }))
+                    }
+                    IsisTlv::RouterCap(v) => {
+                        for sub in &v.subs {
+                            use crate::cap::IsisSubTlv::*;
+                            match sub {
+                                SegmentRoutingCap(cap) => {
+                                    if let SidLabelTlv::Label(base) = cap.sid_label {
+                                        node.srgb.ranges.push((base, cap.range));
+                                    }
+                                }
+                                SegmentRoutingLB(lb) => {
+                                    if let SidLabelTlv::Label(base) = lb.sid_label {
+                                        node.srlb.ranges.push((base, lb.range));
+                                    }
+                                }
+                                SegmentRoutingAlgo(v) => node.algos.extend(&v.algo),
+                                FlexAlgoDef(v) => node.fads.push(v.clone()),
+                                _ => {}
+                            }
+                        }
+                    }
+                    IsisTlv::ExtIpReach(v) => {
+                        for entry in &v.entries {
+                            node.add_prefix(entry.prefix.into(), entry.metric, &entry.subs);
+                        }
+                    }
+                    IsisTlv::Ipv6Reach(v) => {
+                        for entry in &v.entries {
+                            node.add_prefix(entry.prefix.into(), entry.metric, &entry.subs);
+                        }
+                    }
+                    IsisTlv::Srv6(v) => {
+                        for locator in &v.locators {
+                            if locator.algo == Algo::Spf {
+                                for sub in &locator.subs {
+                                    if let PrefixSubTlv::Srv6EndSid(end) = sub
+                                        && node.end_sid.is_none()
+                                    {
+                                        node.end_sid = Some(end.sid);
+                                    }
+                                }
+                            }
+                            node.locators.push(locator.clone());
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Self { nodes }
+    }
+
+    pub fn node(&self, id: &IsisNeighborId) -> Option<&IsisSpfNode> {
+        self.nodes.get(id)
+    }
+
+    /// Links from `from` to `to`, the first one with the lowest metric.
+    pub fn link(&self, from: &IsisNeighborId, to: &IsisNeighborId) -> Option<&IsisSpfLink> {
+        self.nodes
+            .get(from)?
+            .links
+            .iter()
+            .filter(|link| link.to == *to)
+            .min_by_key(|link| link.metric)
+    }
+
+    // Links passing the two-way connectivity check, without the ones
+    // advertised with the excluded metric.
+    fn links<'a>(
+        &'a self,
+        from: &'a IsisNeighborId,
+        exclude: &'a IsisSpfExclude,
+    ) -> impl Iterator<Item = &'a IsisSpfLink> + 'a {
+        self.nodes
+            .get(from)
+            .into_iter()
+            .flat_map(|node| node.links.iter())
+            .filter(move |link| {
+                link.metric != LINK_METRIC_EXCLUDED
+                    && !exclude.excludes(from, &link.to)
+                    && self
+                        .nodes
+                        .get(&link.to)
+                        .is_some_and(|to| to.links.iter().any(|back| back.to == *from))
+            })
+    }
+
+    /// Shortest path tree rooted at `root`.
+    pub fn spf(&self, root: &IsisNeighborId) -> IsisSpfTree {
+        self.spf_exclude(root, &IsisSpfExclude::default())
+    }
+
+    /// Shortest path tree rooted at `root` without the excluded resources.
+    pub fn spf_exclude(&self, root: &IsisNeighborId, exclude: &IsisSpfExclude) -> IsisSpfTree {
+        self.dijkstra(root, exclude, |_, link| Some(link.metric))
+    }
+
+    // Dijkstra with the cost of each link, `None` prunes the link.
+    pub(crate) fn dijkstra(
+        &self,
+        root: &IsisNeighborId,
+        exclude: &IsisSpfExclude,
+        cost: impl Fn(&IsisNeighborId, &IsisSpfLink) -> Option<u32>,
+    ) -> IsisSpfTree {
+        let mut tree = IsisSpfTree {
+            root: root.clone(),
+            ..Default::default()
+        };
+        let mut done = BTreeSet::new();
+        let mut heap = BinaryHeap::new();
+        tree.dist.insert(root.clone(), 0);
+        heap.push(Reverse((0u32, root.clone())));
+
+        while let Some(Reverse((dist, id))) = heap.pop() {
+            if !done.insert(id.clone()) {
+                continue;
+            }
+            // Overloaded nodes are reached but not transited.
+            if id != *root && self.nodes.get(&id).is_some_and(|node| node.overload) {
+                continue;
+            }
+            for link in self.links(&id, exclude) {
+                let Some(metric) = cost(&id, link) else {
+                    continue;
+                };
+                let cost = dist.saturating_add(metric);
+                match tree.dist.get(&link.to) {
+                    Some(&d) if d < cost => {}
+                    Some(&d) if d == cost => {
+                        let parents = tree.parents.entry(link.to.clone()).or_default();
+                        if !parents.contains(&id) {
+                            parents.push(id.clone());
+                        }
+                    }
+                    _ => {
+                        tree.dist.insert(link.to.clone(), cost);
+                        tree.parents.insert(link.to.clone(), vec![id.clone()]);
+                        heap.push(Reverse((cost, link.to.clone())));
+                    }
+                }
+            }
+        }
+        tree
+    }
+}
+
+/// Result of an SPF run, equal cost paths keep all parents.
+#[derive(Debug, Default, Clone)]
+pub struct IsisSpfTree {
+    pub root: IsisNeighborId,
+    pub dist: BTreeMap<IsisNeighborId, u32>,
+    pub parents: BTreeMap<IsisNeighborId, Vec<IsisNeighborId>>,
+}
+
+impl IsisSpfTree {
+    pub fn distance(&self, id: &IsisNeighborId) -> Option<u32> {
+        self.dist.get(id).copied()
+    }
+
+    /// One shortest path from the root to `id`, following the lowest parent
+    /// on equal cost paths.
+    pub fn path(&self, id: &IsisNeighborId) -> Option<Vec<IsisNeighborId>> {
+        self.dist.get(id)?;
+        let mut path = vec![id.clone()];
+        let mut cur = id;
+        while *cur != self.root {
+            cur = self.parents.get(cur)?.iter().min()?;
+            path.push(cur.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// First hop routers on all shortest paths to `id`.
+    pub fn nexthops(&self, id: &IsisNeighborId) -> BTreeSet<IsisSysId> {
+        let mut nexthops = BTreeSet::new();
+        let mut stack = vec![id];
+        let mut seen = BTreeSet::new();
+        while let Some(cur) = stack.pop() {
+            if !seen.insert(cur) {
+                continue;
+            }
+            for parent in self.parents.get(cur).into_iter().flatten() {
+                if *parent == self.root {
+                    if cur.pseudo_id() == 0 {
+                        nexthops.insert(cur.sys_id());
+                    }
+                    continue;
+                }
+                // Neighbor on a LAN of the root.
+                if parent.pseudo_id() != 0
+                    && self
+                        .parents
+                        .get(parent)
+                        .is_some_and(|p| p.contains(&self.root))
+                {
+                    nexthops.insert(cur.sys_id());
+                }
+                stack.push(parent);
+            }
+        }
+        nexthops
+    }
+}
@@ -0,0 +1,170 @@
+use bitfield_struct::bitfield;
+use bytes::{BufMut, BytesMut};
+use nom::number::complete::be_u8;
+use nom::{Err, IResult, Needed};
+use nom_derive::*;
+use serde::{Deserialize, Serialize};
+
+use crate::util::{ParseBe, TlvEmitter, many0};
+use crate::{IsisSysId, IsisTlv, IsisTlvType, SidLabelValue};
+
+use super::{IsisAreaProxyCode, IsisCodeLen, IsisSubTlvUnknown};
+
+/// Area Proxy TLV of the inside nodes (RFC 9666 section 4.3).
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IsisTlvAreaProxy {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subs: Vec<IsisSubTlv>,
+}
+
+impl ParseBe<IsisTlvAreaProxy> for IsisTlvAreaProxy {
+    fn parse_be(input: &[u8]) -> IResult<&[u8], Self> {
+        let (input, subs) = many0(IsisSubTlv::parse_subs)(input)?;
+        Ok((input, Self { subs }))
+    }
+}
+
+impl TlvEmitter for IsisTlvAreaProxy {
+    fn typ(&self) -> u8 {
+        IsisTlvType::AreaProxy.into()
+    }
+
+    fn len(&self) -> u8 {
+        self.subs.iter().map(|sub| sub.len() + 2).sum()
+    }
+
+    fn emit(&self, buf: &mut BytesMut) {
+        self.subs.iter().for_each(|sub| sub.emit(buf));
+    }
+}
+
+impl From<IsisTlvAreaProxy> for IsisTlv {
+    fn from(tlv: IsisTlvAreaProxy) -> Self {
+        IsisTlv::AreaProxy(tlv)
+    }
+}
+
+impl IsisTlvAreaProxy {
+    pub fn proxy_sys_id(&self) -> Option<&IsisSysId> {
+        self.subs.iter().find_map(|sub| match sub {
+            IsisSubTlv::ProxySysId(v) => Some(&v.sys_id),
+            _ => None,
+        })
+    }
+
+    pub fn area_sid(&self) -> Option<&IsisSubAreaSid> {
+        self.subs.iter().find_map(|sub| match sub {
+            IsisSubTlv::AreaSid(v) => Some(v),
+            _ => None,
+        })
+    }
+}
+
+#[derive(Debug, NomBE, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+#[nom(Selector = "IsisAreaProxyCode")]
+pub enum IsisSubTlv {
+    #[nom(Selector = "IsisAreaProxyCode::ProxySysId")]
+    ProxySysId(IsisSubProxySysId),
+    #[nom(Selector = "IsisAreaProxyCode::AreaSid")]
+    AreaSid(IsisSubAreaSid),
+    #[nom(Selector = "_")]
+    Unknown(IsisSubTlvUnknown),
+}
+
+impl IsisSubTlv {
+    pub fn parse_subs(input: &[u8]) -> IResult<&[u8], Self> {
+        let (input, cl) = IsisCodeLen::parse_be(input)?;
+        if input.len() < cl.len as usize {
+            return Err(Err::Incomplete(Needed::new(cl.len as usize)));
+        }
+        let (sub, input) = input.split_at(cl.len as usize);
+        let (_, mut val) = Self::parse_be(sub, cl.code.into())?;
+        if let IsisSubTlv::Unknown(ref mut v) = val {
+            v.code = cl.code;
+            v.len = cl.len;
+        }
+        Ok((input, val))
+    }
+
+    pub fn len(&self) -> u8 {
+        use IsisSubTlv::*;
+        match self {
+            ProxySysId(v) => v.len(),
+            AreaSid(v) => v.len(),
+            Unknown(v) => v.len,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn emit(&self, buf: &mut BytesMut) {
+        use IsisSubTlv::*;
+        match self {
+            ProxySysId(v) => v.tlv_emit(buf),
+            AreaSid(v) => v.tlv_emit(buf),
+            Unknown(v) => v.tlv_emit(buf),
+        }
+    }
+}
+
+/// System ID the Area Leader uses for the Proxy LSP.
+#[derive(Debug, NomBE, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IsisSubProxySysId {
+    pub sys_id: IsisSysId,
+}
+
+impl TlvEmitter for IsisSubProxySysId {
+    fn typ(&self) -> u8 {
+        IsisAreaProxyCode::ProxySysId.into()
+    }
+
+    fn len(&self) -> u8 {
+        6
+    }
+
+    fn emit(&self, buf: &mut BytesMut) {
+        buf.put(&self.sys_id.id[..]);
+    }
+}
+
+#[bitfield(u8, debug = true)]
+#[derive(Serialize, Deserialize, PartialEq)]
+pub struct AreaSidFlags {
+    #[bits(5)]
+    pub resvd: u8,
+    pub l_flag: bool,
+    pub v_flag: bool,
+    pub f_flag: bool,
+}
+
+impl ParseBe<AreaSidFlags> for AreaSidFlags {
+    fn parse_be(input: &[u8]) -> IResult<&[u8], Self> {
+        let (input, flags) = be_u8(input)?;
+        Ok((input, flags.into()))
+    }
+}
+
+/// Area Segment SID steering traffic to any node of the area.
+#[derive(Debug, NomBE, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IsisSubAreaSid {
+    pub flags: AreaSidFlags,
+    pub sid: SidLabelValue,
+}
+
+impl TlvEmitter for IsisSubAreaSid {
+    fn typ(&self) -> u8 {
+        IsisAreaProxyCode::AreaSid.into()
+    }
+
+    fn len(&self) -> u8 {
+        1 + self.sid.len()
+    }
+
+    fn emit(&self, buf: &mut BytesMut) {
+        buf.put_u8(self.flags.into());
+        self.sid.emit(buf);
+    }
+}
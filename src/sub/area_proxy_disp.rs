@@ -0,0 +1,44 @@
+use std::fmt::{Display, Formatter, Result};
+
+use super::area_proxy::IsisSubTlv;
+use super::{IsisSubAreaSid, IsisSubProxySysId, IsisTlvAreaProxy};
+
+impl Display for IsisTlvAreaProxy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "  Area Proxy:")?;
+        for sub in self.subs.iter() {
+            write!(f, "\n{}", sub)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for IsisSubTlv {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        use IsisSubTlv::*;
+        match self {
+            ProxySysId(v) => write!(f, "{}", v),
+            AreaSid(v) => write!(f, "{}", v),
+            Unknown(v) => write!(f, "   Unknown Code: {} Len: {}", v.code, v.len),
+        }
+    }
+}
+
+impl Display for IsisSubProxySysId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "   Proxy System ID: {}", self.sys_id)
+    }
+}
+
+impl Display for IsisSubAreaSid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "   Area Segment SID: {} F:{} V:{} L:{}",
+            self.sid,
+            self.flags.f_flag() as u8,
+            self.flags.v_flag() as u8,
+            self.flags.l_flag() as u8
+        )
+    }
+}
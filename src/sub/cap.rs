@@ -10,7 +10,7 @@ use serde::{Deserialize, Serialize};
 use crate::util::{ParseBe, TlvEmitter, many0, u32_u8_3};
 use crate::{Algo, IsisTlv, IsisTlvType};
 
-use super::{IsisCapCode, IsisCodeLen, IsisSubTlvUnknown};
+use super::{IsisCapCode, IsisCodeLen, IsisFadSubCode, IsisSubTlvUnknown};
 
 #[derive(Debug, NomBE, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "kebab-case")]
@@ -26,6 +26,12 @@ pub enum IsisSubTlv {
     NodeMaxSidDepth(IsisSubNodeMaxSidDepth),
     #[nom(Selector = "IsisCapCode::Srv6")]
     Srv6(IsisSubSrv6),
+    #[nom(Selector = "IsisCapCode::FlexAlgoDef")]
+    FlexAlgoDef(IsisSubFlexAlgoDef),
+    #[nom(Selector = "IsisCapCode::AreaLeader")]
+    AreaLeader(IsisSubAreaLeader),
+    #[nom(Selector = "IsisCapCode::DynamicFlooding")]
+    DynamicFlooding(IsisSubDynamicFlooding),
     #[nom(Selector = "_")]
     Unknown(IsisSubTlvUnknown),
 }
@@ -53,6 +59,9 @@ impl IsisSubTlv {
             SegmentRoutingLB(v) => v.len(),
             NodeMaxSidDepth(v) => v.len(),
             Srv6(v) => v.len(),
+            FlexAlgoDef(v) => v.len(),
+            AreaLeader(v) => v.len(),
+            DynamicFlooding(v) => v.len(),
             Unknown(v) => v.len,
         }
     }
@@ -69,6 +78,9 @@ impl IsisSubTlv {
             SegmentRoutingLB(v) => v.tlv_emit(buf),
             NodeMaxSidDepth(v) => v.tlv_emit(buf),
             Srv6(v) => v.tlv_emit(buf),
+            FlexAlgoDef(v) => v.tlv_emit(buf),
+            AreaLeader(v) => v.tlv_emit(buf),
+            DynamicFlooding(v) => v.tlv_emit(buf),
             Unknown(v) => v.tlv_emit(buf),
         }
     }
@@ -255,6 +267,49 @@ impl TlvEmitter for IsisSubNodeMaxSidDepth {
     }
 }
 
+/// Area Leader sub-TLV with the priority to become Area Leader and the
+/// flooding algorithm it would use, 0 for centralized mode (RFC 9667).
+#[derive(Debug, NomBE, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IsisSubAreaLeader {
+    pub priority: u8,
+    pub algo: u8,
+}
+
+impl TlvEmitter for IsisSubAreaLeader {
+    fn typ(&self) -> u8 {
+        IsisCapCode::AreaLeader.into()
+    }
+
+    fn len(&self) -> u8 {
+        2
+    }
+
+    fn emit(&self, buf: &mut BytesMut) {
+        buf.put_u8(self.priority);
+        buf.put_u8(self.algo);
+    }
+}
+
+/// Dynamic Flooding sub-TLV with the supported flooding algorithms.
+#[derive(Debug, NomBE, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IsisSubDynamicFlooding {
+    pub algos: Vec<u8>,
+}
+
+impl TlvEmitter for IsisSubDynamicFlooding {
+    fn typ(&self) -> u8 {
+        IsisCapCode::DynamicFlooding.into()
+    }
+
+    fn len(&self) -> u8 {
+        self.algos.len() as u8
+    }
+
+    fn emit(&self, buf: &mut BytesMut) {
+        buf.put(&self.algos[..]);
+    }
+}
+
 #[bitfield(u8, debug = true)]
 #[derive(Serialize, Deserialize, PartialEq)]
 pub struct RouterCapFlags {
@@ -275,7 +330,7 @@ impl ParseBe<RouterCapFlags> for RouterCapFlags {
 pub struct IsisTlvRouterCap {
     pub router_id: Ipv4Addr,
     pub flags: RouterCapFlags,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub subs: Vec<IsisSubTlv>,
 }
 
@@ -355,3 +410,225 @@ impl TlvEmitter for IsisSubSrv6 {
         buf.put_u16(self.flags.into());
     }
 }
+
+/// Flexible Algorithm metric type (RFC 9350 section 5.1).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum IsisFlexAlgoMetric {
+    Igp,
+    MinDelay,
+    Te,
+    Unknown(u8),
+}
+
+impl From<IsisFlexAlgoMetric> for u8 {
+    fn from(typ: IsisFlexAlgoMetric) -> Self {
+        use IsisFlexAlgoMetric::*;
+        match typ {
+            Igp => 0,
+            MinDelay => 1,
+            Te => 2,
+            Unknown(v) => v,
+        }
+    }
+}
+
+impl From<u8> for IsisFlexAlgoMetric {
+    fn from(typ: u8) -> Self {
+        use IsisFlexAlgoMetric::*;
+        match typ {
+            0 => Igp,
+            1 => MinDelay,
+            2 => Te,
+            v => Unknown(v),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IsisSubFlexAlgoDef {
+    pub algo: Algo,
+    pub metric_type: IsisFlexAlgoMetric,
+    pub calc_type: u8,
+    pub priority: u8,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subs: Vec<IsisFadSubTlv>,
+}
+
+impl IsisSubFlexAlgoDef {
+    fn groups(&self, code: IsisFadSubCode) -> &[u32] {
+        use IsisFadSubTlv::*;
+        self.subs
+            .iter()
+            .find(|sub| sub.code() == code)
+            .and_then(|sub| match sub {
+                ExcludeAny(v) | IncludeAny(v) | IncludeAll(v) => Some(&v.groups[..]),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn exclude_any(&self) -> &[u32] {
+        self.groups(IsisFadSubCode::ExcludeAny)
+    }
+
+    pub fn include_any(&self) -> &[u32] {
+        self.groups(IsisFadSubCode::IncludeAny)
+    }
+
+    pub fn include_all(&self) -> &[u32] {
+        self.groups(IsisFadSubCode::IncludeAll)
+    }
+
+    /// M flag, Flexible Algorithm Prefix Metric in use.
+    pub fn m_flag(&self) -> bool {
+        self.subs.iter().any(|sub| match sub {
+            IsisFadSubTlv::Flags(v) => v.flags.first().is_some_and(|f| f & 0x80 != 0),
+            _ => false,
+        })
+    }
+
+    fn sub_len(&self) -> u8 {
+        self.subs.iter().map(|sub| sub.len() + 2).sum()
+    }
+}
+
+impl ParseBe<IsisSubFlexAlgoDef> for IsisSubFlexAlgoDef {
+    fn parse_be(input: &[u8]) -> IResult<&[u8], Self> {
+        let (input, algo) = Algo::parse_be(input)?;
+        let (input, metric_type) = be_u8(input)?;
+        let (input, calc_type) = be_u8(input)?;
+        let (input, priority) = be_u8(input)?;
+        let (input, subs) = many0(IsisFadSubTlv::parse_subs)(input)?;
+        let sub = Self {
+            algo,
+            metric_type: metric_type.into(),
+            calc_type,
+            priority,
+            subs,
+        };
+        Ok((input, sub))
+    }
+}
+
+impl TlvEmitter for IsisSubFlexAlgoDef {
+    fn typ(&self) -> u8 {
+        IsisCapCode::FlexAlgoDef.into()
+    }
+
+    fn len(&self) -> u8 {
+        4 + self.sub_len()
+    }
+
+    fn emit(&self, buf: &mut BytesMut) {
+        buf.put_u8(self.algo.into());
+        buf.put_u8(self.metric_type.into());
+        buf.put_u8(self.calc_type);
+        buf.put_u8(self.priority);
+        self.subs.iter().for_each(|sub| sub.emit(buf));
+    }
+}
+
+impl From<IsisSubFlexAlgoDef> for IsisSubTlv {
+    fn from(sub: IsisSubFlexAlgoDef) -> Self {
+        IsisSubTlv::FlexAlgoDef(sub)
+    }
+}
+
+#[derive(Debug, NomBE, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+#[nom(Selector = "IsisFadSubCode")]
+pub enum IsisFadSubTlv {
+    #[nom(Selector = "IsisFadSubCode::ExcludeAny")]
+    ExcludeAny(IsisFadAdminGroup),
+    #[nom(Selector = "IsisFadSubCode::IncludeAny")]
+    IncludeAny(IsisFadAdminGroup),
+    #[nom(Selector = "IsisFadSubCode::IncludeAll")]
+    IncludeAll(IsisFadAdminGroup),
+    #[nom(Selector = "IsisFadSubCode::Flags")]
+    Flags(IsisFadFlags),
+    #[nom(Selector = "_")]
+    Unknown(IsisSubTlvUnknown),
+}
+
+impl IsisFadSubTlv {
+    pub fn parse_subs(input: &[u8]) -> IResult<&[u8], Self> {
+        let (input, cl) = IsisCodeLen::parse_be(input)?;
+        if input.len() < cl.len as usize {
+            return Err(Err::Incomplete(Needed::new(cl.len as usize)));
+        }
+        let (sub, input) = input.split_at(cl.len as usize);
+        let (_, mut val) = Self::parse_be(sub, cl.code.into())?;
+        if let IsisFadSubTlv::Unknown(ref mut v) = val {
+            v.code = cl.code;
+            v.len = cl.len;
+        }
+        Ok((input, val))
+    }
+
+    pub fn code(&self) -> IsisFadSubCode {
+        use IsisFadSubTlv::*;
+        match self {
+            ExcludeAny(_) => IsisFadSubCode::ExcludeAny,
+            IncludeAny(_) => IsisFadSubCode::IncludeAny,
+            IncludeAll(_) => IsisFadSubCode::IncludeAll,
+            Flags(_) => IsisFadSubCode::Flags,
+            Unknown(v) => v.code.into(),
+        }
+    }
+
+    pub fn len(&self) -> u8 {
+        use IsisFadSubTlv::*;
+        match self {
+            ExcludeAny(v) | IncludeAny(v) | IncludeAll(v) => (v.groups.len() * 4) as u8,
+            Flags(v) => v.flags.len() as u8,
+            Unknown(v) => v.len,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn emit(&self, buf: &mut BytesMut) {
+        use IsisFadSubTlv::*;
+        buf.put_u8(self.code().into());
+        buf.put_u8(self.len());
+        match self {
+            ExcludeAny(v) | IncludeAny(v) | IncludeAll(v) => {
+                v.groups.iter().for_each(|group| buf.put_u32(*group))
+            }
+            Flags(v) => buf.put(&v.flags[..]),
+            Unknown(v) => buf.put(&v.data[..]),
+        }
+    }
+}
+
+/// Extended Administrative Group of a FAD constraint.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IsisFadAdminGroup {
+    pub groups: Vec<u32>,
+}
+
+impl ParseBe<IsisFadAdminGroup> for IsisFadAdminGroup {
+    fn parse_be(input: &[u8]) -> IResult<&[u8], Self> {
+        let (input, groups) = many0(be_u32)(input)?;
+        Ok((input, Self { groups }))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IsisFadFlags {
+    pub flags: Vec<u8>,
+}
+
+impl ParseBe<IsisFadFlags> for IsisFadFlags {
+    fn parse_be(input: &[u8]) -> IResult<&[u8], Self> {
+        Ok((
+            &input[input.len()..],
+            Self {
+                flags: input.to_vec(),
+            },
+        ))
+    }
+}
@@ -11,6 +11,9 @@ pub enum IsisCapCode {
     SegmentRoutingLb = 22,
     NodeMaxSidDepth = 23,
     Srv6 = 25,
+    FlexAlgoDef = 26,
+    AreaLeader = 27,
+    DynamicFlooding = 28,
     Unknown(u8),
 }
 
@@ -23,6 +26,9 @@ impl From<IsisCapCode> for u8 {
             SegmentRoutingLb => 22,
             NodeMaxSidDepth => 23,
             Srv6 => 25,
+            FlexAlgoDef => 26,
+            AreaLeader => 27,
+            DynamicFlooding => 28,
             Unknown(v) => v,
         }
     }
@@ -37,6 +43,9 @@ impl From<u8> for IsisCapCode {
             22 => SegmentRoutingLb,
             23 => NodeMaxSidDepth,
             25 => Srv6,
+            26 => FlexAlgoDef,
+            27 => AreaLeader,
+            28 => DynamicFlooding,
             v => Unknown(v),
         }
     }
@@ -49,3 +58,40 @@ impl IsisCapCode {
         Ok((input, isis_type))
     }
 }
+
+#[repr(u8)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum IsisFadSubCode {
+    #[default]
+    ExcludeAny = 1,
+    IncludeAny = 2,
+    IncludeAll = 3,
+    Flags = 4,
+    Unknown(u8),
+}
+
+impl From<IsisFadSubCode> for u8 {
+    fn from(typ: IsisFadSubCode) -> Self {
+        use IsisFadSubCode::*;
+        match typ {
+            ExcludeAny => 1,
+            IncludeAny => 2,
+            IncludeAll => 3,
+            Flags => 4,
+            Unknown(v) => v,
+        }
+    }
+}
+
+impl From<u8> for IsisFadSubCode {
+    fn from(typ: u8) -> Self {
+        use IsisFadSubCode::*;
+        match typ {
+            1 => ExcludeAny,
+            2 => IncludeAny,
+            3 => IncludeAll,
+            4 => Flags,
+            v => Unknown(v),
+        }
+    }
+}
@@ -2,8 +2,9 @@ use std::fmt::{Display, Formatter, Result};
 
 use super::cap::{IsisSubSrv6, IsisSubTlv, RouterCapFlags};
 use super::{
-    IsisSubNodeMaxSidDepth, IsisSubSegmentRoutingAlgo, IsisSubSegmentRoutingCap,
-    IsisSubSegmentRoutingLB, IsisTlvRouterCap, SegmentRoutingCapFlags,
+    IsisFadSubTlv, IsisFlexAlgoMetric, IsisSubAreaLeader, IsisSubDynamicFlooding,
+    IsisSubFlexAlgoDef, IsisSubNodeMaxSidDepth, IsisSubSegmentRoutingAlgo,
+    IsisSubSegmentRoutingCap, IsisSubSegmentRoutingLB, IsisTlvRouterCap, SegmentRoutingCapFlags,
 };
 
 impl Display for RouterCapFlags {
@@ -35,6 +36,9 @@ impl Display for IsisSubTlv {
             SegmentRoutingLB(v) => write!(f, "{}", v),
             NodeMaxSidDepth(v) => write!(f, "{}", v),
             Srv6(v) => write!(f, "{}", v),
+            FlexAlgoDef(v) => write!(f, "{}", v),
+            AreaLeader(v) => write!(f, "{}", v),
+            DynamicFlooding(v) => write!(f, "{}", v),
             Unknown(v) => write!(f, "   Unknown Code: {} Len: {}", v.code, v.len),
         }
     }
@@ -91,3 +95,67 @@ impl Display for IsisSubSrv6 {
         write!(f, r#"   SRv6: O:{}"#, self.flags.o_flag() as u8)
     }
 }
+
+impl Display for IsisFlexAlgoMetric {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        use IsisFlexAlgoMetric::*;
+        match self {
+            Igp => write!(f, "IGP"),
+            MinDelay => write!(f, "Min Delay"),
+            Te => write!(f, "TE"),
+            Unknown(v) => write!(f, "Unknown({})", v),
+        }
+    }
+}
+
+impl Display for IsisSubFlexAlgoDef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            r#"   Flex-Algo Definition: {}, Metric: {}, Calc: {}, Priority: {}"#,
+            self.algo, self.metric_type, self.calc_type, self.priority
+        )?;
+        for sub in &self.subs {
+            write!(f, "\n{}", sub)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for IsisFadSubTlv {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        use IsisFadSubTlv::*;
+        let (name, groups) = match self {
+            ExcludeAny(v) => ("Exclude Any", &v.groups),
+            IncludeAny(v) => ("Include Any", &v.groups),
+            IncludeAll(v) => ("Include All", &v.groups),
+            Flags(v) => return write!(f, "    Flags: {:02x?}", v.flags),
+            Unknown(v) => return write!(f, "    Unknown Code: {} Len: {}", v.code, v.len),
+        };
+        write!(f, "    {}:", name)?;
+        for group in groups {
+            write!(f, " 0x{:08x}", group)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for IsisSubAreaLeader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            r#"   Area Leader: Priority: {}, Algorithm: {}"#,
+            self.priority, self.algo
+        )
+    }
+}
+
+impl Display for IsisSubDynamicFlooding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, r#"   Dynamic Flooding Algorithm:"#)?;
+        for algo in &self.algos {
+            write!(f, " {}", algo)?;
+        }
+        Ok(())
+    }
+}
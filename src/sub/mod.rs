@@ -6,20 +6,29 @@ pub struct IsisCodeLen {
     pub len: u8,
 }
 
+pub mod area_proxy;
+pub use area_proxy::{AreaSidFlags, IsisSubAreaSid, IsisSubProxySysId, IsisTlvAreaProxy};
+pub mod area_proxy_code;
+pub use area_proxy_code::IsisAreaProxyCode;
+pub mod area_proxy_disp;
+
 pub mod cap;
 pub use cap::{
-    IsisSubNodeMaxSidDepth, IsisSubSegmentRoutingAlgo, IsisSubSegmentRoutingCap,
-    IsisSubSegmentRoutingLB, IsisSubSrv6, IsisTlvRouterCap, SegmentRoutingCapFlags, SidLabelTlv,
+    IsisFadAdminGroup, IsisFadFlags, IsisFadSubTlv, IsisFlexAlgoMetric, IsisSubAreaLeader,
+    IsisSubDynamicFlooding, IsisSubFlexAlgoDef, IsisSubNodeMaxSidDepth, IsisSubSegmentRoutingAlgo,
+    IsisSubSegmentRoutingCap, IsisSubSegmentRoutingLB, IsisSubSrv6, IsisTlvRouterCap,
+    SegmentRoutingCapFlags, SidLabelTlv,
 };
 pub mod cap_code;
-pub use cap_code::IsisCapCode;
+pub use cap_code::{IsisCapCode, IsisFadSubCode};
 pub mod cap_disp;
 
 pub mod neigh;
 pub use neigh::{
-    AdjSidFlags, IsisSubIpv4IfAddr, IsisSubIpv4NeighAddr, IsisSubIpv6IfAddr, IsisSubIpv6NeighAddr,
-    IsisSubLanAdjSid, IsisSubSrv6EndXSid, IsisSubSrv6LanEndXSid, IsisSubWideMetric,
-    IsisTlvExtIsReach, IsisTlvExtIsReachEntry,
+    AdjSidFlags, IsisSubAdminGroup, IsisSubExtAdminGroup, IsisSubIpv4IfAddr, IsisSubIpv4NeighAddr,
+    IsisSubIpv6IfAddr, IsisSubIpv6NeighAddr, IsisSubLanAdjSid, IsisSubMinMaxDelay,
+    IsisSubSrv6EndXSid, IsisSubSrv6LanEndXSid, IsisSubWideMetric, IsisTlvExtIsReach,
+    IsisTlvExtIsReachEntry, IsisTlvReverseMetric, ReverseMetricFlags,
 };
 pub mod neigh_code;
 pub use neigh_code::IsisNeighCode;
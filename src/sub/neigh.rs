@@ -3,7 +3,7 @@ use std::net::{Ipv4Addr, Ipv6Addr};
 use bitfield_struct::bitfield;
 use bytes::{BufMut, BytesMut};
 use nom::bytes::complete::take;
-use nom::number::complete::{be_u8, be_u16, be_u24};
+use nom::number::complete::{be_u8, be_u16, be_u24, be_u32};
 use nom::{Err, IResult, Needed};
 use nom_derive::*;
 use serde::{Deserialize, Serialize};
@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 use crate::util::{ParseBe, TlvEmitter, many0, u32_u8_3};
 use crate::{
     Algo, IPV4_ADDR_LEN, IPV6_ADDR_LEN, IsisNeighborId, IsisSysId, IsisTlv, IsisTlvType,
-    SidLabelValue,
+    LINK_METRIC_EXCLUDED, MAX_LINK_METRIC, SidLabelValue,
 };
 
 use super::{Behavior, IsisCodeLen, IsisNeighCode, IsisSub2Tlv, IsisSubTlvUnknown};
@@ -52,7 +52,7 @@ impl TlvEmitter for IsisTlvExtIsReach {
 pub struct IsisTlvExtIsReachEntry {
     pub neighbor_id: IsisNeighborId,
     pub metric: u32,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub subs: Vec<IsisSubTlv>,
 }
 
@@ -92,10 +92,108 @@ impl ParseBe<IsisTlvExtIsReachEntry> for IsisTlvExtIsReachEntry {
     }
 }
 
+#[bitfield(u8, debug = true)]
+#[derive(Serialize, Deserialize, PartialEq)]
+pub struct ReverseMetricFlags {
+    pub w_flag: bool,
+    pub u_flag: bool,
+    #[bits(6)]
+    pub resvd: u8,
+}
+
+/// Reverse Metric TLV of IIHs (RFC 8500).
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IsisTlvReverseMetric {
+    pub flags: ReverseMetricFlags,
+    pub metric: u32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subs: Vec<IsisSubTlv>,
+}
+
+impl From<IsisTlvReverseMetric> for IsisTlv {
+    fn from(tlv: IsisTlvReverseMetric) -> Self {
+        IsisTlv::ReverseMetric(tlv)
+    }
+}
+
+impl ParseBe<IsisTlvReverseMetric> for IsisTlvReverseMetric {
+    fn parse_be(input: &[u8]) -> IResult<&[u8], Self> {
+        let (input, flags) = be_u8(input)?;
+        let (input, metric) = be_u24(input)?;
+        let (input, sublen) = be_u8(input)?;
+        let (input, sub) = take(sublen)(input)?;
+        let (_, subs) = many0(IsisSubTlv::parse_subs)(sub)?;
+        let tlv = Self {
+            flags: flags.into(),
+            metric,
+            subs,
+        };
+        Ok((input, tlv))
+    }
+}
+
+impl TlvEmitter for IsisTlvReverseMetric {
+    fn typ(&self) -> u8 {
+        IsisTlvType::ReverseMetric.into()
+    }
+
+    fn len(&self) -> u8 {
+        5 + self.sub_len()
+    }
+
+    fn emit(&self, buf: &mut BytesMut) {
+        buf.put_u8(self.flags.into());
+        buf.put(&u32_u8_3(self.metric)[..]);
+        buf.put_u8(self.sub_len());
+        self.subs.iter().for_each(|sub| sub.emit(buf));
+    }
+}
+
+impl IsisTlvReverseMetric {
+    fn sub_len(&self) -> u8 {
+        self.subs.iter().map(|sub| sub.len() + 2).sum()
+    }
+
+    /// Reverse metric of the traffic engineering default metric sub-TLV.
+    pub fn te_metric(&self) -> Option<u32> {
+        self.subs.iter().find_map(|sub| match sub {
+            IsisSubTlv::WideMetric(v) => Some(v.metric),
+            _ => None,
+        })
+    }
+
+    // Sum capped at the maximum link metric, or at the metric excluding the
+    // link from SPF when the U flag is set (RFC 8500 section 2).
+    fn add(&self, metric: u32, reverse: u32) -> u32 {
+        let max = if self.flags.u_flag() {
+            LINK_METRIC_EXCLUDED
+        } else {
+            MAX_LINK_METRIC
+        };
+        metric.saturating_add(reverse).min(max)
+    }
+
+    /// Metric toward the neighbor with the reverse metric added.
+    pub fn apply(&self, metric: u32) -> u32 {
+        self.add(metric, self.metric)
+    }
+
+    /// Traffic engineering metric toward the neighbor with the reverse TE
+    /// metric added, unchanged without the sub-TLV.
+    pub fn apply_te(&self, te_metric: u32) -> u32 {
+        match self.te_metric() {
+            Some(reverse) => self.add(te_metric, reverse),
+            None => te_metric,
+        }
+    }
+}
+
 #[derive(Debug, NomBE, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "kebab-case")]
 #[nom(Selector = "IsisNeighCode")]
 pub enum IsisSubTlv {
+    #[nom(Selector = "IsisNeighCode::AdminGroup")]
+    AdminGroup(IsisSubAdminGroup),
     #[nom(Selector = "IsisNeighCode::Ipv4IfAddr")]
     Ipv4IfAddr(IsisSubIpv4IfAddr),
     #[nom(Selector = "IsisNeighCode::Ipv4NeighAddr")]
@@ -104,12 +202,16 @@ pub enum IsisSubTlv {
     Ipv6IfAddr(IsisSubIpv6IfAddr),
     #[nom(Selector = "IsisNeighCode::Ipv6NeighAddr")]
     Ipv6NeighAddr(IsisSubIpv6NeighAddr),
+    #[nom(Selector = "IsisNeighCode::ExtAdminGroup")]
+    ExtAdminGroup(IsisSubExtAdminGroup),
     #[nom(Selector = "IsisNeighCode::WideMetric")]
     WideMetric(IsisSubWideMetric),
     #[nom(Selector = "IsisNeighCode::AdjSid")]
     AdjSid(IsisSubAdjSid),
     #[nom(Selector = "IsisNeighCode::LanAdjSid")]
     LanAdjSid(IsisSubLanAdjSid),
+    #[nom(Selector = "IsisNeighCode::MinMaxDelay")]
+    MinMaxDelay(IsisSubMinMaxDelay),
     #[nom(Selector = "IsisNeighCode::Srv6EndXSid")]
     Srv6EndXSid(IsisSubSrv6EndXSid),
     #[nom(Selector = "IsisNeighCode::Srv6LanEndXSid")]
@@ -136,13 +238,16 @@ impl IsisSubTlv {
     pub fn len(&self) -> u8 {
         use IsisSubTlv::*;
         match self {
+            AdminGroup(v) => v.len(),
             Ipv4IfAddr(v) => v.len(),
             Ipv4NeighAddr(v) => v.len(),
             Ipv6IfAddr(v) => v.len(),
             Ipv6NeighAddr(v) => v.len(),
+            ExtAdminGroup(v) => v.len(),
             WideMetric(v) => v.len(),
             AdjSid(v) => v.len(),
             LanAdjSid(v) => v.len(),
+            MinMaxDelay(v) => v.len(),
             Srv6EndXSid(v) => v.len(),
             Srv6LanEndXSid(v) => v.len(),
             Unknown(v) => v.len,
@@ -156,13 +261,16 @@ impl IsisSubTlv {
     pub fn emit(&self, buf: &mut BytesMut) {
         use IsisSubTlv::*;
         match self {
+            AdminGroup(v) => v.tlv_emit(buf),
             Ipv4IfAddr(v) => v.tlv_emit(buf),
             Ipv4NeighAddr(v) => v.tlv_emit(buf),
             Ipv6IfAddr(v) => v.tlv_emit(buf),
             Ipv6NeighAddr(v) => v.tlv_emit(buf),
+            ExtAdminGroup(v) => v.tlv_emit(buf),
             WideMetric(v) => v.tlv_emit(buf),
             AdjSid(v) => v.tlv_emit(buf),
             LanAdjSid(v) => v.tlv_emit(buf),
+            MinMaxDelay(v) => v.tlv_emit(buf),
             Srv6EndXSid(v) => v.tlv_emit(buf),
             Srv6LanEndXSid(v) => v.tlv_emit(buf),
             Unknown(v) => v.tlv_emit(buf),
@@ -170,6 +278,25 @@ impl IsisSubTlv {
     }
 }
 
+#[derive(Debug, NomBE, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IsisSubAdminGroup {
+    pub group: u32,
+}
+
+impl TlvEmitter for IsisSubAdminGroup {
+    fn typ(&self) -> u8 {
+        IsisNeighCode::AdminGroup.into()
+    }
+
+    fn len(&self) -> u8 {
+        4
+    }
+
+    fn emit(&self, buf: &mut BytesMut) {
+        buf.put_u32(self.group);
+    }
+}
+
 #[derive(Debug, NomBE, Clone, Serialize, Deserialize, PartialEq)]
 pub struct IsisSubIpv4IfAddr {
     pub addr: Ipv4Addr,
@@ -246,6 +373,33 @@ impl TlvEmitter for IsisSubIpv6NeighAddr {
     }
 }
 
+// Extended Administrative Group (RFC 7308).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IsisSubExtAdminGroup {
+    pub groups: Vec<u32>,
+}
+
+impl ParseBe<IsisSubExtAdminGroup> for IsisSubExtAdminGroup {
+    fn parse_be(input: &[u8]) -> IResult<&[u8], Self> {
+        let (input, groups) = many0(be_u32)(input)?;
+        Ok((input, Self { groups }))
+    }
+}
+
+impl TlvEmitter for IsisSubExtAdminGroup {
+    fn typ(&self) -> u8 {
+        IsisNeighCode::ExtAdminGroup.into()
+    }
+
+    fn len(&self) -> u8 {
+        (self.groups.len() * 4) as u8
+    }
+
+    fn emit(&self, buf: &mut BytesMut) {
+        self.groups.iter().for_each(|group| buf.put_u32(*group));
+    }
+}
+
 #[derive(Debug, NomBE, Clone, Serialize, Deserialize, PartialEq)]
 pub struct IsisSubWideMetric {
     #[nom(Parse = "be_u24")]
@@ -350,6 +504,47 @@ impl TlvEmitter for IsisSubLanAdjSid {
     }
 }
 
+// Min/Max Unidirectional Link Delay in microseconds (RFC 8570).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IsisSubMinMaxDelay {
+    /// Anomalous bit.
+    pub anomalous: bool,
+    pub min: u32,
+    pub max: u32,
+}
+
+impl ParseBe<IsisSubMinMaxDelay> for IsisSubMinMaxDelay {
+    fn parse_be(input: &[u8]) -> IResult<&[u8], Self> {
+        let (input, flags) = be_u8(input)?;
+        let (input, min) = be_u24(input)?;
+        let (input, _resvd) = be_u8(input)?;
+        let (input, max) = be_u24(input)?;
+        let sub = Self {
+            anomalous: flags & 0x80 != 0,
+            min,
+            max,
+        };
+        Ok((input, sub))
+    }
+}
+
+impl TlvEmitter for IsisSubMinMaxDelay {
+    fn typ(&self) -> u8 {
+        IsisNeighCode::MinMaxDelay.into()
+    }
+
+    fn len(&self) -> u8 {
+        8
+    }
+
+    fn emit(&self, buf: &mut BytesMut) {
+        buf.put_u8(if self.anomalous { 0x80 } else { 0 });
+        buf.put(&u32_u8_3(self.min)[..]);
+        buf.put_u8(0);
+        buf.put(&u32_u8_3(self.max)[..]);
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct IsisSubSrv6EndXSid {
     pub flags: u8,
@@ -357,7 +552,7 @@ pub struct IsisSubSrv6EndXSid {
     pub weight: u8,
     pub behavior: Behavior,
     pub sid: Ipv6Addr,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub sub2s: Vec<IsisSub2Tlv>,
 }
 
@@ -421,7 +616,7 @@ pub struct IsisSubSrv6LanEndXSid {
     pub weight: u8,
     pub behavior: Behavior,
     pub sid: Ipv6Addr,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub sub2s: Vec<IsisSub2Tlv>,
 }
 
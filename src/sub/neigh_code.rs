@@ -6,13 +6,16 @@ use nom_derive::*;
 #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
 pub enum IsisNeighCode {
     #[default]
+    AdminGroup = 3,
     Ipv4IfAddr = 6,
     Ipv4NeighAddr = 8,
     Ipv6IfAddr = 12,
     Ipv6NeighAddr = 13,
+    ExtAdminGroup = 14,
     WideMetric = 18,
     AdjSid = 31,
     LanAdjSid = 32,
+    MinMaxDelay = 34,
     Srv6EndXSid = 43,
     Srv6LanEndXSid = 44,
     Unknown(u8),
@@ -22,13 +25,16 @@ impl From<IsisNeighCode> for u8 {
     fn from(typ: IsisNeighCode) -> Self {
         use IsisNeighCode::*;
         match typ {
+            AdminGroup => 3,
             Ipv4IfAddr => 6,
             Ipv4NeighAddr => 8,
             Ipv6IfAddr => 12,
             Ipv6NeighAddr => 13,
+            ExtAdminGroup => 14,
             WideMetric => 18,
             AdjSid => 31,
             LanAdjSid => 32,
+            MinMaxDelay => 34,
             Srv6EndXSid => 43,
             Srv6LanEndXSid => 44,
             Unknown(v) => v,
@@ -40,13 +46,16 @@ impl From<u8> for IsisNeighCode {
     fn from(typ: u8) -> Self {
         use IsisNeighCode::*;
         match typ {
+            3 => AdminGroup,
             6 => Ipv4IfAddr,
             8 => Ipv4NeighAddr,
             12 => Ipv6IfAddr,
             13 => Ipv6NeighAddr,
+            14 => ExtAdminGroup,
             18 => WideMetric,
             31 => AdjSid,
             32 => LanAdjSid,
+            34 => MinMaxDelay,
             43 => Srv6EndXSid,
             44 => Srv6LanEndXSid,
             v => Unknown(v),
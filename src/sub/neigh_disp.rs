@@ -2,9 +2,10 @@ use std::fmt::{Display, Formatter, Result};
 
 use super::neigh::{IsisSubAdjSid, IsisSubTlv};
 use super::{
-    AdjSidFlags, IsisSubIpv4IfAddr, IsisSubIpv4NeighAddr, IsisSubIpv6IfAddr, IsisSubIpv6NeighAddr,
-    IsisSubLanAdjSid, IsisSubSrv6EndXSid, IsisSubSrv6LanEndXSid, IsisSubWideMetric,
-    IsisTlvExtIsReach, IsisTlvExtIsReachEntry,
+    AdjSidFlags, IsisSubAdminGroup, IsisSubExtAdminGroup, IsisSubIpv4IfAddr, IsisSubIpv4NeighAddr,
+    IsisSubIpv6IfAddr, IsisSubIpv6NeighAddr, IsisSubLanAdjSid, IsisSubMinMaxDelay,
+    IsisSubSrv6EndXSid, IsisSubSrv6LanEndXSid, IsisSubWideMetric, IsisTlvExtIsReach,
+    IsisTlvExtIsReachEntry, IsisTlvReverseMetric, ReverseMetricFlags,
 };
 
 impl Display for IsisTlvExtIsReach {
@@ -17,6 +18,26 @@ impl Display for IsisTlvExtIsReach {
     }
 }
 
+impl Display for IsisTlvReverseMetric {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "  Reverse Metric: {}, Flags: {}",
+            self.metric, self.flags
+        )?;
+        for sub in self.subs.iter() {
+            write!(f, "\n{}", sub)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for ReverseMetricFlags {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "W:{} U:{}", self.w_flag() as u8, self.u_flag() as u8)
+    }
+}
+
 impl Display for IsisTlvExtIsReachEntry {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(
@@ -35,13 +56,16 @@ impl Display for IsisSubTlv {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         use IsisSubTlv::*;
         match self {
+            AdminGroup(v) => write!(f, "{}", v),
             Ipv4IfAddr(v) => write!(f, "{}", v),
             Ipv4NeighAddr(v) => write!(f, "{}", v),
             Ipv6IfAddr(v) => write!(f, "{}", v),
             Ipv6NeighAddr(v) => write!(f, "{}", v),
+            ExtAdminGroup(v) => write!(f, "{}", v),
             WideMetric(v) => write!(f, "{}", v),
             AdjSid(v) => write!(f, "{}", v),
             LanAdjSid(v) => write!(f, "{}", v),
+            MinMaxDelay(v) => write!(f, "{}", v),
             Srv6EndXSid(v) => write!(f, "{}", v),
             Srv6LanEndXSid(v) => write!(f, "{}", v),
             Unknown(v) => write!(f, "    Unknown: ({:?})", v.code),
@@ -49,6 +73,12 @@ impl Display for IsisSubTlv {
     }
 }
 
+impl Display for IsisSubAdminGroup {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "    Admin Group: 0x{:08x}", self.group)
+    }
+}
+
 impl Display for IsisSubIpv4IfAddr {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(f, "    IPv4 Interface Address: {}", self.addr)
@@ -73,6 +103,16 @@ impl Display for IsisSubIpv6NeighAddr {
     }
 }
 
+impl Display for IsisSubExtAdminGroup {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "    Extended Admin Group:")?;
+        for group in &self.groups {
+            write!(f, " 0x{:08x}", group)?;
+        }
+        Ok(())
+    }
+}
+
 impl Display for IsisSubWideMetric {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(f, "    Wide Metric: {}", self.metric)
@@ -115,6 +155,16 @@ impl Display for IsisSubLanAdjSid {
     }
 }
 
+impl Display for IsisSubMinMaxDelay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "    Min/Max Link Delay: {}/{} us, A:{}",
+            self.min, self.max, self.anomalous as u8
+        )
+    }
+}
+
 impl Display for IsisSubSrv6EndXSid {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(f, "    End.X SID: {}", self.sid)
@@ -74,7 +74,7 @@ pub struct IsisSubSrv6EndSid {
     pub flags: u8,
     pub behavior: Behavior,
     pub sid: Ipv6Addr,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub sub2s: Vec<IsisSub2Tlv>,
 }
 
@@ -313,14 +313,15 @@ impl TlvEmitter for IsisTlvMtIpReach {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct IsisTlvExtIpReachEntry {
     pub metric: u32,
+    #[serde(default)]
     pub flags: Ipv4ControlInfo,
     pub prefix: Ipv4Net,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub subs: Vec<IsisSubTlv>,
 }
 
 impl IsisTlvExtIpReachEntry {
-    fn len(&self) -> u8 {
+    pub(crate) fn len(&self) -> u8 {
         if self.subs.is_empty() {
             // Metric:4 + Flags:1 + Prefix.
             4 + 1 + (psize(self.prefix.prefix_len()) as u8)
@@ -395,10 +396,10 @@ impl From<IsisTlvIpv6Reach> for IsisTlv {
 #[bitfield(u16, debug = true)]
 #[derive(Serialize, Deserialize, PartialEq)]
 pub struct MultiTopologyId {
-    #[bits(4)]
-    pub resvd: u8,
     #[bits(12)]
     pub id: u16,
+    #[bits(4)]
+    pub resvd: u8,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -450,14 +451,15 @@ pub struct Ipv6ControlInfo {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct IsisTlvIpv6ReachEntry {
     pub metric: u32,
+    #[serde(default)]
     pub flags: Ipv6ControlInfo,
     pub prefix: Ipv6Net,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub subs: Vec<IsisSubTlv>,
 }
 
 impl IsisTlvIpv6ReachEntry {
-    fn len(&self) -> u8 {
+    pub(crate) fn len(&self) -> u8 {
         if self.subs.is_empty() {
             // Metric:4 + Flags:1 + Prefixlen:1.
             4 + 1 + 1 + (psize(self.prefix.prefix_len()) as u8)
@@ -579,10 +581,10 @@ impl ParseBe<IsisTlvIpv6ReachEntry> for IsisTlvIpv6ReachEntry {
 #[bitfield(u16, debug = true)]
 #[derive(Serialize, Deserialize, PartialEq)]
 pub struct Srv6TlvFlags {
+    #[bits(12)]
+    pub mt_id: u16,
     #[bits(4)]
     pub resvd: u8,
-    #[bits(12)]
-    pub v_flag: u16,
 }
 
 impl ParseBe<Srv6TlvFlags> for Srv6TlvFlags {
@@ -598,7 +600,7 @@ pub struct Srv6Locator {
     pub flags: u8,
     pub algo: Algo,
     pub locator: Ipv6Net,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub subs: Vec<IsisSubTlv>,
 }
 
@@ -0,0 +1,294 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::net::Ipv6Addr;
+
+use crate::{IsisNeighborId, IsisSpfExclude, IsisSpfGraph, IsisSpfTree, IsisSysId, SidLabelValue};
+
+/// Resource protected by the repair path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsisProtection {
+    Link,
+    Node,
+}
+
+/// Dataplane of the repair segment list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsisDataplane {
+    Mpls,
+    Srv6,
+}
+
+/// Segment of a repair list.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IsisSegment {
+    /// Prefix-SID of the node as a label of the router processing it.
+    Node { node: IsisSysId, label: u32 },
+    /// Adjacency SID.
+    Adj {
+        from: IsisSysId,
+        to: IsisSysId,
+        label: u32,
+    },
+    /// SRv6 End SID of the node.
+    End { node: IsisSysId, sid: Ipv6Addr },
+    /// SRv6 End.X SID of the adjacency.
+    EndX {
+        from: IsisSysId,
+        to: IsisSysId,
+        sid: Ipv6Addr,
+    },
+}
+
+/// TI-LFA repair of one destination (RFC 9855).
+#[derive(Debug, Clone)]
+pub struct IsisTiLfaRepair {
+    pub destination: IsisSysId,
+    /// Protection in use, link protection when the destination is the
+    /// primary next hop itself.
+    pub protection: IsisProtection,
+    /// Primary next hop the repair protects against.
+    pub primary: IsisSysId,
+    /// Extended P-space of the root.
+    pub p_space: BTreeSet<IsisSysId>,
+    /// Q-space of the destination.
+    pub q_space: BTreeSet<IsisSysId>,
+    /// Post-convergence path from the root, empty when the failure
+    /// partitions the destination.
+    pub path: Vec<IsisNeighborId>,
+    pub nexthop: Option<IsisSysId>,
+    pub p_node: Option<IsisSysId>,
+    pub q_node: Option<IsisSysId>,
+    /// Segments to push, `None` without a repair path or a SID it needs.
+    pub segments: Option<Vec<IsisSegment>>,
+}
+
+impl IsisTiLfaRepair {
+    pub fn is_protected(&self) -> bool {
+        self.segments.is_some()
+    }
+}
+
+// Failed resource with the distances of the pre-failure topology.
+struct Failure<'a> {
+    trees: &'a BTreeMap<IsisNeighborId, IsisSpfTree>,
+    root: &'a IsisNeighborId,
+    exclude: IsisSpfExclude,
+    // Link metric in each direction for link protection.
+    metric: (u32, u32),
+}
+
+impl Failure<'_> {
+    fn dist(&self, from: &IsisNeighborId, to: &IsisNeighborId) -> u64 {
+        self.trees
+            .get(from)
+            .and_then(|tree| tree.distance(to))
+            .map_or(u64::MAX / 4, u64::from)
+    }
+
+    // True when all shortest paths from `from` to `to` avoid the failure.
+    fn avoids(&self, from: &IsisNeighborId, to: &IsisNeighborId) -> bool {
+        let through = if let Some(node) = &self.exclude.node {
+            self.dist(from, node) + self.dist(node, to)
+        } else if let Some((s, x)) = &self.exclude.link {
+            let (sx, xs) = self.metric;
+            (self.dist(from, s) + u64::from(sx) + self.dist(x, to))
+                .min(self.dist(from, x) + u64::from(xs) + self.dist(s, to))
+        } else {
+            u64::MAX
+        };
+        self.dist(from, to) < through
+    }
+}
+
+fn router(id: &IsisSysId) -> IsisNeighborId {
+    IsisNeighborId::from_sys_id(id, 0)
+}
+
+impl IsisSpfGraph {
+    /// TI-LFA repair paths of `root` for every reachable router.
+    pub fn ti_lfa(
+        &self,
+        root: &IsisSysId,
+        protection: IsisProtection,
+        dataplane: IsisDataplane,
+    ) -> Vec<IsisTiLfaRepair> {
+        let root = router(root);
+        let trees: BTreeMap<IsisNeighborId, IsisSpfTree> = self
+            .nodes
+            .keys()
+            .map(|id| (id.clone(), self.spf(id)))
+            .collect();
+        let Some(primary_tree) = trees.get(&root) else {
+            return Vec::new();
+        };
+
+        let mut repairs = Vec::new();
+        for dest in self.nodes.keys() {
+            if dest.pseudo_id() != 0 || *dest == root {
+                continue;
+            }
+            let Some(primary_path) = primary_tree.path(dest) else {
+                continue;
+            };
+            let first = primary_path[1].clone();
+            let Some(primary) = primary_path[1..].iter().find(|id| id.pseudo_id() == 0) else {
+                continue;
+            };
+            let protection = if *primary == *dest {
+                IsisProtection::Link
+            } else {
+                protection
+            };
+            let failure = match protection {
+                IsisProtection::Link => Failure {
+                    trees: &trees,
+                    root: &root,
+                    metric: (
+                        self.link(&root, &first).map_or(0, |l| l.metric),
+                        self.link(&first, &root).map_or(0, |l| l.metric),
+                    ),
+                    exclude: IsisSpfExclude {
+                        link: Some((root.clone(), first.clone())),
+                        node: None,
+                    },
+                },
+                IsisProtection::Node => Failure {
+                    trees: &trees,
+                    root: &root,
+                    metric: (0, 0),
+                    exclude: IsisSpfExclude {
+                        link: None,
+                        node: Some(primary.clone()),
+                    },
+                },
+            };
+            repairs.push(self.repair(dest, primary, protection, &failure, dataplane));
+        }
+        repairs
+    }
+
+    fn repair(
+        &self,
+        dest: &IsisNeighborId,
+        primary: &IsisNeighborId,
+        protection: IsisProtection,
+        failure: &Failure,
+        dataplane: IsisDataplane,
+    ) -> IsisTiLfaRepair {
+        let root = failure.root;
+        let routers: Vec<&IsisNeighborId> =
+            self.nodes.keys().filter(|id| id.pseudo_id() == 0).collect();
+
+        // Extended P-space, nodes reached from the root or one of its
+        // remaining neighbors without crossing the failure.
+        let post_tree = self.spf_exclude(root, &failure.exclude);
+        let neighbors: Vec<IsisNeighborId> = routers
+            .iter()
+            .filter(|id| post_tree.nexthops(id).contains(&id.sys_id()))
+            .map(|id| (*id).clone())
+            .collect();
+        let p_space = routers
+            .iter()
+            .filter(|p| {
+                **p != root
+                    && (failure.avoids(root, p) || neighbors.iter().any(|n| failure.avoids(n, p)))
+            })
+            .map(|p| p.sys_id())
+            .collect();
+        let q_space = routers
+            .iter()
+            .filter(|q| **q != root && failure.avoids(q, dest))
+            .map(|q| q.sys_id())
+            .collect();
+
+        let mut repair = IsisTiLfaRepair {
+            destination: dest.sys_id(),
+            protection,
+            primary: primary.sys_id(),
+            p_space,
+            q_space,
+            path: Vec::new(),
+            nexthop: None,
+            p_node: None,
+            q_node: None,
+            segments: None,
+        };
+        let Some(path) = post_tree.path(dest) else {
+            return repair;
+        };
+        let hops: Vec<&IsisNeighborId> = path.iter().filter(|id| id.pseudo_id() == 0).collect();
+        let nexthop = hops[1];
+        repair.nexthop = Some(nexthop.sys_id());
+
+        // P node is the last node of the path in the P-space of the repair
+        // next hop, Q node the first one after it in the Q-space.
+        let in_p = |id: &IsisNeighborId| id == nexthop || failure.avoids(nexthop, id);
+        let in_q = |id: &IsisNeighborId| failure.avoids(id, dest);
+        let p_end = (1..hops.len())
+            .take_while(|&i| in_p(hops[i]))
+            .last()
+            .unwrap_or(1);
+        let (p, q) = match (1..=p_end).find(|&i| in_q(hops[i])) {
+            Some(i) => (i, i),
+            None => match (p_end + 1..hops.len()).find(|&i| in_q(hops[i])) {
+                Some(q) => (p_end, q),
+                None => {
+                    repair.path = path;
+                    return repair;
+                }
+            },
+        };
+        repair.p_node = Some(hops[p].sys_id());
+        repair.q_node = Some(hops[q].sys_id());
+        repair.segments = self.segments(&path, &hops, p, q, dataplane);
+        repair.path = path;
+        repair
+    }
+
+    fn segments(
+        &self,
+        path: &[IsisNeighborId],
+        hops: &[&IsisNeighborId],
+        p: usize,
+        q: usize,
+        dataplane: IsisDataplane,
+    ) -> Option<Vec<IsisSegment>> {
+        let nexthop = self.node(hops[1])?;
+        let mut segments = Vec::new();
+        // Traffic is sent to the next hop, which needs no segment of its own.
+        if p > 1 {
+            let p_node = self.node(hops[p])?;
+            let node = hops[p].sys_id();
+            segments.push(match dataplane {
+                IsisDataplane::Mpls => IsisSegment::Node {
+                    node,
+                    label: p_node.node_label(&nexthop.srgb)?,
+                },
+                IsisDataplane::Srv6 => IsisSegment::End {
+                    node,
+                    sid: p_node.end_sid?,
+                },
+            });
+        }
+        for i in p..q {
+            let (from, to) = (hops[i], hops[i + 1]);
+            let pos = path.iter().position(|id| id == from)?;
+            let link = self.link(from, &path[pos + 1])?;
+            segments.push(match dataplane {
+                IsisDataplane::Mpls => IsisSegment::Adj {
+                    from: from.sys_id(),
+                    to: to.sys_id(),
+                    label: match link.adj_sid(&to.sys_id())? {
+                        SidLabelValue::Label(label) => label,
+                        SidLabelValue::Index(index) => self.node(from)?.srlb.label(index)?,
+                    },
+                },
+                IsisDataplane::Srv6 => IsisSegment::EndX {
+                    from: from.sys_id(),
+                    to: to.sys_id(),
+                    sid: link.end_x_sid(&to.sys_id())?,
+                },
+            });
+        }
+        Some(segments)
+    }
+}
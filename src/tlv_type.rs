@@ -10,8 +10,15 @@ pub enum IsisTlvType {
     #[default]
     AreaAddr = 1,
     IsNeighbor = 6,
+    InstanceId = 7,
     Padding = 8,
     LspEntries = 9,
+    LspBufferSize = 14,
+    ReverseMetric = 16,
+    AreaSysIds = 17,
+    FloodingPath = 18,
+    FloodingRequest = 19,
+    AreaProxy = 20,
     ExtIsReach = 22,
     Srv6 = 27,
     ProtSupported = 129,
@@ -45,8 +52,15 @@ impl IsisTlvType {
             self,
             AreaAddr
                 | IsNeighbor
+                | InstanceId
                 | Padding
                 | LspEntries
+                | LspBufferSize
+                | ReverseMetric
+                | AreaSysIds
+                | FloodingPath
+                | FloodingRequest
+                | AreaProxy
                 | ExtIsReach
                 | Srv6
                 | ProtSupported
@@ -72,8 +86,15 @@ impl From<IsisTlvType> for u8 {
         match typ {
             AreaAddr => 1,
             IsNeighbor => 6,
+            InstanceId => 7,
             Padding => 8,
             LspEntries => 9,
+            LspBufferSize => 14,
+            ReverseMetric => 16,
+            AreaSysIds => 17,
+            FloodingPath => 18,
+            FloodingRequest => 19,
+            AreaProxy => 20,
             ExtIsReach => 22,
             Srv6 => 27,
             ProtSupported => 129,
@@ -100,8 +121,15 @@ impl From<u8> for IsisTlvType {
         match typ {
             1 => AreaAddr,
             6 => IsNeighbor,
+            7 => InstanceId,
             8 => Padding,
             9 => LspEntries,
+            14 => LspBufferSize,
+            16 => ReverseMetric,
+            17 => AreaSysIds,
+            18 => FloodingPath,
+            19 => FloodingRequest,
+            20 => AreaProxy,
             22 => ExtIsReach,
             27 => Srv6,
             129 => ProtSupported,
@@ -0,0 +1,134 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use serde::Serialize;
+
+use crate::neigh::IsisSubTlv;
+use crate::{IsisHostnameMap, IsisLsdb, IsisNeighborId, IsisTlv};
+
+/// Topology graph of one level built from the LSP database.
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct IsisTopology {
+    pub nodes: Vec<IsisTopoNode>,
+    pub edges: Vec<IsisTopoEdge>,
+}
+
+/// Router or pseudonode. Routers are identified by the system ID and
+/// pseudonodes by the LAN ID.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct IsisTopoNode {
+    pub id: String,
+    pub label: String,
+    pub pseudonode: bool,
+}
+
+/// Extended IS reachability from `source` to `target`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct IsisTopoEdge {
+    pub source: String,
+    pub target: String,
+    pub metric: u32,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub adj_sids: Vec<u32>,
+}
+
+fn node_id(id: &IsisNeighborId) -> String {
+    if id.pseudo_id() == 0 {
+        id.sys_id().to_string()
+    } else {
+        id.to_string()
+    }
+}
+
+// DOT quoted string.
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+impl IsisTopology {
+    pub fn new(lsdb: &IsisLsdb) -> Self {
+        let hostnames = IsisHostnameMap::from(lsdb);
+        // Nodes sorted by ID, including neighbors without LSPs.
+        let mut nodes: BTreeMap<IsisNeighborId, IsisTopoNode> = BTreeMap::new();
+        let mut add_node = |id: IsisNeighborId| {
+            nodes.entry(id.clone()).or_insert_with(|| {
+                let label = match hostnames.get(&id.sys_id()) {
+                    Some(hostname) if id.pseudo_id() == 0 => hostname.to_string(),
+                    Some(_) => hostnames.neighbor_id(&id).to_string(),
+                    None => node_id(&id),
+                };
+                IsisTopoNode {
+                    id: node_id(&id),
+                    label,
+                    pseudonode: id.pseudo_id() != 0,
+                }
+            });
+        };
+
+        let mut edges = Vec::new();
+        for lsp in lsdb.iter() {
+            let source = lsp.lsp_id.neighbor_id();
+            add_node(source.clone());
+            for tlv in &lsp.tlvs {
+                let IsisTlv::ExtIsReach(tlv) = tlv else {
+                    continue;
+                };
+                for entry in &tlv.entries {
+                    add_node(entry.neighbor_id.clone());
+                    let adj_sids = entry
+                        .subs
+                        .iter()
+                        .filter_map(|sub| match sub {
+                            IsisSubTlv::AdjSid(v) => Some(v.sid.value()),
+                            IsisSubTlv::LanAdjSid(v) => Some(v.sid.value()),
+                            _ => None,
+                        })
+                        .collect();
+                    edges.push(IsisTopoEdge {
+                        source: node_id(&source),
+                        target: node_id(&entry.neighbor_id),
+                        metric: entry.metric,
+                        adj_sids,
+                    });
+                }
+            }
+        }
+        Self {
+            nodes: nodes.into_values().collect(),
+            edges,
+        }
+    }
+
+    /// Graphviz DOT digraph, pseudonodes are drawn as boxes.
+    pub fn dot(&self) -> String {
+        let mut dot = String::from("digraph isis {\n");
+        for node in &self.nodes {
+            let shape = if node.pseudonode { "box" } else { "ellipse" };
+            let _ = writeln!(
+                dot,
+                "  {} [label={}, shape={}];",
+                quote(&node.id),
+                quote(&node.label),
+                shape
+            );
+        }
+        for edge in &self.edges {
+            let mut label = edge.metric.to_string();
+            for sid in &edge.adj_sids {
+                let _ = write!(label, "\\nadj-sid {}", sid);
+            }
+            let _ = writeln!(
+                dot,
+                "  {} -> {} [label=\"{}\"];",
+                quote(&edge.source),
+                quote(&edge.target),
+                label
+            );
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
@@ -0,0 +1,324 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{
+    IsLevel, IsisCsnp, IsisLsdb, IsisLsp, IsisLspEntry, IsisLspId, IsisPacket, IsisPdu, IsisPsnp,
+    IsisSysId, IsisType,
+};
+
+pub type IsisCircuitId = u32;
+
+/// ISO 10589 MaxAge and ZeroAgeLifetime in seconds.
+pub const MAX_AGE: u16 = 1200;
+pub const ZERO_AGE_LIFETIME: u16 = 60;
+
+/// ISO 10589 7.3.16.1, how long an LSP whose sequence number is exhausted
+/// must not be originated before restarting at 1.
+pub const SEQ_NUMBER_WAIT: u16 = MAX_AGE + ZERO_AGE_LIFETIME;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsisCircuitType {
+    Broadcast,
+    P2p,
+}
+
+/// Per-circuit flooding state. SRM holds LSPs to be sent, SSN holds entries
+/// to be reported in the next PSNP.
+#[derive(Debug, Clone)]
+pub struct IsisFloodCircuit {
+    pub typ: IsisCircuitType,
+    pub mtu: usize,
+    pub srm: BTreeSet<IsisLspId>,
+    pub ssn: BTreeMap<IsisLspId, IsisLspEntry>,
+}
+
+impl IsisFloodCircuit {
+    pub fn new(typ: IsisCircuitType, mtu: usize) -> Self {
+        Self {
+            typ,
+            mtu,
+            srm: BTreeSet::new(),
+            ssn: BTreeMap::new(),
+        }
+    }
+}
+
+/// ISO 10589 update process for one level. It only keeps the SRM/SSN flags
+/// and the LSDB, the caller decides when to send and delivers the returned
+/// packets on the named circuit.
+#[derive(Debug, Clone)]
+pub struct IsisUpdate {
+    pub level: IsLevel,
+    pub sys_id: IsisSysId,
+    pub lsdb: IsisLsdb,
+    pub circuits: BTreeMap<IsisCircuitId, IsisFloodCircuit>,
+    /// Own LSPs whose sequence number can not be incremented any more. The
+    /// caller waits `SEQ_NUMBER_WAIT` then calls `seq_number_restart`.
+    pub seq_exhausted: BTreeSet<IsisLspId>,
+}
+
+impl IsisUpdate {
+    pub fn new(level: IsLevel, sys_id: IsisSysId) -> Self {
+        Self {
+            level,
+            sys_id,
+            lsdb: IsisLsdb::new(),
+            circuits: BTreeMap::new(),
+            seq_exhausted: BTreeSet::new(),
+        }
+    }
+
+    pub fn circuit_add(&mut self, id: IsisCircuitId, typ: IsisCircuitType, mtu: usize) {
+        let mut circuit = IsisFloodCircuit::new(typ, mtu);
+        // A new P2P adjacency gets the full database, on LAN the DIS CSNP
+        // takes care of the synchronization.
+        if typ == IsisCircuitType::P2p {
+            circuit.srm = self.lsdb.iter().map(|lsp| lsp.lsp_id).collect();
+        }
+        self.circuits.insert(id, circuit);
+    }
+
+    pub fn circuit_remove(&mut self, id: IsisCircuitId) {
+        self.circuits.remove(&id);
+    }
+
+    fn srm_set_all(&mut self, lsp_id: IsisLspId, except: Option<IsisCircuitId>) {
+        for (id, circuit) in self.circuits.iter_mut() {
+            if Some(*id) == except {
+                circuit.srm.remove(&lsp_id);
+            } else {
+                circuit.srm.insert(lsp_id);
+            }
+        }
+    }
+
+    fn ssn_clear_all(&mut self, lsp_id: IsisLspId) {
+        for circuit in self.circuits.values_mut() {
+            circuit.ssn.remove(&lsp_id);
+        }
+    }
+
+    /// Install a self-originated LSP and flood it on every circuit. Nothing
+    /// is originated while the LSP ID is in `seq_exhausted`.
+    pub fn lsp_originate(&mut self, lsp: IsisLsp) {
+        let lsp_id = lsp.lsp_id;
+        if self.seq_exhausted.contains(&lsp_id) {
+            return;
+        }
+        self.lsdb.insert(lsp);
+        self.ssn_clear_all(lsp_id);
+        self.srm_set_all(lsp_id, None);
+    }
+
+    /// ISO 10589 7.3.15.1, LSP received on a circuit.
+    pub fn lsp_recv(&mut self, id: IsisCircuitId, lsp: IsisLsp) {
+        let Some(typ) = self.circuits.get(&id).map(|circuit| circuit.typ) else {
+            return;
+        };
+        let lsp_id = lsp.lsp_id;
+        let entry = lsp.entry();
+        let ord = match self.lsdb.get(&lsp_id) {
+            Some(curr) => entry.compare(&curr.entry()),
+            None => Ordering::Greater,
+        };
+
+        // Someone floods a newer copy of our own LSP, or one with our
+        // sequence number but different contents, out-number it.
+        let differs = ord == Ordering::Equal
+            && lsp.hold_time != 0
+            && self
+                .lsdb
+                .get(&lsp_id)
+                .is_some_and(|curr| curr.checksum != lsp.checksum);
+        if lsp_id.sys_id() == self.sys_id && (ord == Ordering::Greater || differs) {
+            let own = match self.lsdb.get(&lsp_id) {
+                Some(curr) => {
+                    let Some(seq_number) = lsp.seq_number.checked_add(1) else {
+                        // ISO 10589 7.3.16.1, stop originating until every
+                        // copy with the exhausted number has aged out.
+                        self.seq_exhausted.insert(lsp_id);
+                        return;
+                    };
+                    let mut own = curr.clone();
+                    own.seq_number = seq_number;
+                    own
+                }
+                None => {
+                    // We no longer have it, purge it.
+                    let mut purge = lsp;
+                    purge.hold_time = 0;
+                    purge.tlvs.clear();
+                    purge
+                }
+            };
+            self.lsp_originate(own);
+            return;
+        }
+
+        match ord {
+            Ordering::Greater => {
+                self.lsdb.insert(lsp);
+                self.srm_set_all(lsp_id, Some(id));
+                self.ssn_clear_all(lsp_id);
+                if typ == IsisCircuitType::P2p
+                    && let Some(circuit) = self.circuits.get_mut(&id)
+                {
+                    circuit.ssn.insert(lsp_id, entry);
+                }
+            }
+            Ordering::Equal => {
+                if let Some(circuit) = self.circuits.get_mut(&id) {
+                    circuit.srm.remove(&lsp_id);
+                    if typ == IsisCircuitType::P2p {
+                        circuit.ssn.insert(lsp_id, entry);
+                    }
+                }
+            }
+            Ordering::Less => {
+                if let Some(circuit) = self.circuits.get_mut(&id) {
+                    circuit.srm.insert(lsp_id);
+                    circuit.ssn.remove(&lsp_id);
+                }
+            }
+        }
+    }
+
+    /// Restart an exhausted LSP at sequence number 1 once `SEQ_NUMBER_WAIT`
+    /// has passed.
+    pub fn seq_number_restart(&mut self, mut lsp: IsisLsp) {
+        self.seq_exhausted.remove(&lsp.lsp_id);
+        lsp.seq_number = 1;
+        self.lsp_originate(lsp);
+    }
+
+    // Compare one received SNP entry against the LSDB.
+    fn snp_entry(&mut self, id: IsisCircuitId, entry: &IsisLspEntry) {
+        let curr = self.lsdb.get(&entry.lsp_id).map(|lsp| lsp.entry());
+        let Some(circuit) = self.circuits.get_mut(&id) else {
+            return;
+        };
+        match curr {
+            None => {
+                // Request it with a zero sequence number.
+                if entry.seq_number != 0 && entry.hold_time != 0 {
+                    circuit.ssn.insert(
+                        entry.lsp_id,
+                        IsisLspEntry {
+                            hold_time: entry.hold_time,
+                            lsp_id: entry.lsp_id,
+                            seq_number: 0,
+                            checksum: 0,
+                        },
+                    );
+                }
+            }
+            Some(curr) => match entry.compare(&curr) {
+                Ordering::Equal => {
+                    circuit.srm.remove(&entry.lsp_id);
+                }
+                Ordering::Less => {
+                    circuit.srm.insert(entry.lsp_id);
+                    circuit.ssn.remove(&entry.lsp_id);
+                }
+                Ordering::Greater => {
+                    circuit.srm.remove(&entry.lsp_id);
+                    circuit.ssn.insert(entry.lsp_id, curr);
+                }
+            },
+        }
+    }
+
+    /// ISO 10589 7.3.15.2, CSNP received on a circuit.
+    pub fn csnp_recv(&mut self, id: IsisCircuitId, csnp: &IsisCsnp) {
+        if !self.circuits.contains_key(&id) {
+            return;
+        }
+        let mut listed = BTreeSet::new();
+        for entry in csnp.entries() {
+            listed.insert(entry.lsp_id);
+            self.snp_entry(id, entry);
+        }
+
+        // LSPs in the range which the neighbor does not have.
+        let missing: Vec<IsisLspId> = self
+            .lsdb
+            .range(csnp.start, csnp.end)
+            .filter(|lsp| !listed.contains(&lsp.lsp_id))
+            .filter(|lsp| lsp.hold_time != 0 && lsp.seq_number != 0)
+            .map(|lsp| lsp.lsp_id)
+            .collect();
+        if let Some(circuit) = self.circuits.get_mut(&id) {
+            circuit.srm.extend(missing);
+        }
+    }
+
+    /// ISO 10589 7.3.15.2, PSNP received on a circuit.
+    pub fn psnp_recv(&mut self, id: IsisCircuitId, psnp: &IsisPsnp) {
+        if !self.circuits.contains_key(&id) {
+            return;
+        }
+        for entry in psnp.entries() {
+            self.snp_entry(id, entry);
+        }
+    }
+
+    /// Packets for the current SRM and SSN flags of a circuit. On LAN SRM
+    /// is cleared once sent, on P2P it stays set until acknowledged so
+    /// calling this again retransmits.
+    pub fn send(&mut self, id: IsisCircuitId) -> Vec<IsisPacket> {
+        let (lsp_type, psnp_type) = match self.level {
+            IsLevel::L1 => (IsisType::L1Lsp, IsisType::L1Psnp),
+            _ => (IsisType::L2Lsp, IsisType::L2Psnp),
+        };
+        let sys_id = self.sys_id.clone();
+        let Some(circuit) = self.circuits.get_mut(&id) else {
+            return Vec::new();
+        };
+
+        let mut packets = Vec::new();
+        for lsp_id in circuit.srm.iter() {
+            if let Some(lsp) = self.lsdb.get(lsp_id) {
+                let pdu = match lsp_type {
+                    IsisType::L1Lsp => IsisPdu::L1Lsp(lsp.clone()),
+                    _ => IsisPdu::L2Lsp(lsp.clone()),
+                };
+                packets.push(IsisPacket::from(lsp_type, pdu));
+            }
+        }
+        if circuit.typ == IsisCircuitType::Broadcast {
+            circuit.srm.clear();
+        }
+
+        let entries = std::mem::take(&mut circuit.ssn).into_values();
+        for psnp in IsisPsnp::build(&sys_id, entries, circuit.mtu) {
+            let pdu = match psnp_type {
+                IsisType::L1Psnp => IsisPdu::L1Psnp(psnp),
+                _ => IsisPdu::L2Psnp(psnp),
+            };
+            packets.push(IsisPacket::from(psnp_type, pdu));
+        }
+        packets
+    }
+
+    /// Periodic CSNPs describing the whole LSDB on a circuit. The ranges of
+    /// the generated PDUs cover the full LSP ID space.
+    pub fn csnp(&self, id: IsisCircuitId) -> Vec<IsisPacket> {
+        let Some(circuit) = self.circuits.get(&id) else {
+            return Vec::new();
+        };
+        let csnp_type = match self.level {
+            IsLevel::L1 => IsisType::L1Csnp,
+            _ => IsisType::L2Csnp,
+        };
+        IsisCsnp::build(&self.sys_id, self.lsdb.entries(), circuit.mtu)
+            .into_iter()
+            .map(|csnp| {
+                let pdu = match csnp_type {
+                    IsisType::L1Csnp => IsisPdu::L1Csnp(csnp),
+                    _ => IsisPdu::L2Csnp(csnp),
+                };
+                IsisPacket::from(csnp_type, pdu)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,773 @@
+use std::borrow::Cow;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use ipnet::{Ipv4Net, Ipv6Net};
+
+use crate::sub::cap::RouterCapFlags;
+use crate::sub::prefix::{
+    Ipv4ControlInfo, Ipv6ControlInfo, MultiTopologyId, Srv6TlvFlags, ptake, ptakev6,
+};
+use crate::sub::{cap, neigh, prefix};
+use crate::{
+    Algo, IsLevel, IsisLspEntry, IsisLspId, IsisLspTypes, IsisNeighborId, IsisPacket,
+    IsisParseError, IsisSysId, IsisTlv, IsisTlvP2p3Way, IsisTlvType, IsisType, LSP_ENTRY_LEN,
+    NeighborAddr, parse,
+};
+
+const ISIS_IRDP_DISC: u8 = 0x83;
+
+// Common header shared by all PDU types.
+const COMMON_HEADER_LEN: usize = 8;
+
+// Fixed part of each PDU following the common header.
+const HELLO_HEADER_LEN: usize = 19;
+const P2P_HELLO_HEADER_LEN: usize = 12;
+const LSP_HEADER_LEN: usize = 19;
+const CSNP_HEADER_LEN: usize = 25;
+const PSNP_HEADER_LEN: usize = 9;
+
+fn u16_at(input: &[u8], pos: usize) -> u16 {
+    u16::from_be_bytes([input[pos], input[pos + 1]])
+}
+
+fn u32_at(input: &[u8], pos: usize) -> u32 {
+    u32::from_be_bytes([input[pos], input[pos + 1], input[pos + 2], input[pos + 3]])
+}
+
+fn sys_id_at(input: &[u8], pos: usize) -> IsisSysId {
+    let mut sys_id = IsisSysId::default();
+    sys_id.id.copy_from_slice(&input[pos..pos + 6]);
+    sys_id
+}
+
+fn neighbor_id_at(input: &[u8], pos: usize) -> IsisNeighborId {
+    let mut neighbor_id = IsisNeighborId::default();
+    neighbor_id.id.copy_from_slice(&input[pos..pos + 7]);
+    neighbor_id
+}
+
+fn lsp_id_at(input: &[u8], pos: usize) -> IsisLspId {
+    let mut lsp_id = IsisLspId::default();
+    lsp_id.id.copy_from_slice(&input[pos..pos + 8]);
+    lsp_id
+}
+
+/// Borrowed view of an IS-IS packet. Only the common header is validated up
+/// front, the PDU header and TLVs are decoded on access straight from the
+/// input slice without allocation.
+#[derive(Debug, Clone, Copy)]
+pub struct IsisPacketRef<'a> {
+    raw: &'a [u8],
+}
+
+impl<'a> IsisPacketRef<'a> {
+    /// Validate the headers and bound the packet to its PDU length, so
+    /// trailing frame padding is not taken for TLVs.
+    pub fn parse(input: &'a [u8]) -> Result<Self, IsisParseError> {
+        if input.len() < COMMON_HEADER_LEN {
+            return Err(IsisParseError::incomplete_data(
+                COMMON_HEADER_LEN - input.len(),
+            ));
+        }
+        if input[0] != ISIS_IRDP_DISC {
+            return Err(IsisParseError::InvalidDiscriminator { found: input[0] });
+        }
+        let pdu_type = IsisType::from(input[4]);
+        let (header_len, pdu_len_pos) = match pdu_type {
+            IsisType::L1Hello | IsisType::L2Hello => (HELLO_HEADER_LEN, 9),
+            IsisType::P2PHello => (P2P_HELLO_HEADER_LEN, 9),
+            IsisType::L1Lsp | IsisType::L2Lsp => (LSP_HEADER_LEN, 0),
+            IsisType::L1Csnp | IsisType::L2Csnp => (CSNP_HEADER_LEN, 0),
+            IsisType::L1Psnp | IsisType::L2Psnp => (PSNP_HEADER_LEN, 0),
+            _ => return Ok(Self { raw: input }),
+        };
+        let min_len = COMMON_HEADER_LEN + header_len;
+        if input.len() < min_len {
+            return Err(IsisParseError::incomplete_data(min_len - input.len()));
+        }
+        let pdu_len = u16_at(input, COMMON_HEADER_LEN + pdu_len_pos) as usize;
+        if pdu_len < min_len {
+            return Err(IsisParseError::InvalidPacketLength {
+                expected: min_len,
+                found: pdu_len,
+            });
+        }
+        if pdu_len > input.len() {
+            return Err(IsisParseError::incomplete_data(pdu_len - input.len()));
+        }
+        Ok(Self {
+            raw: &input[..pdu_len],
+        })
+    }
+
+    pub fn raw(&self) -> &'a [u8] {
+        self.raw
+    }
+
+    pub fn length_indicator(&self) -> u8 {
+        self.raw[1]
+    }
+
+    pub fn id_length(&self) -> u8 {
+        self.raw[3]
+    }
+
+    pub fn pdu_type(&self) -> IsisType {
+        IsisType::from(self.raw[4])
+    }
+
+    pub fn version(&self) -> u8 {
+        self.raw[5]
+    }
+
+    pub fn max_area_addr(&self) -> u8 {
+        self.raw[7]
+    }
+
+    pub fn pdu(&self) -> IsisPduRef<'a> {
+        use IsisType::*;
+        let raw = &self.raw[COMMON_HEADER_LEN..];
+        match self.pdu_type() {
+            L1Hello => IsisPduRef::L1Hello(IsisHelloRef { raw }),
+            L2Hello => IsisPduRef::L2Hello(IsisHelloRef { raw }),
+            P2PHello => IsisPduRef::P2pHello(IsisP2pHelloRef { raw }),
+            L1Lsp => IsisPduRef::L1Lsp(IsisLspRef { raw }),
+            L2Lsp => IsisPduRef::L2Lsp(IsisLspRef { raw }),
+            L1Csnp => IsisPduRef::L1Csnp(IsisCsnpRef { raw }),
+            L2Csnp => IsisPduRef::L2Csnp(IsisCsnpRef { raw }),
+            L1Psnp => IsisPduRef::L1Psnp(IsisPsnpRef { raw }),
+            L2Psnp => IsisPduRef::L2Psnp(IsisPsnpRef { raw }),
+            _ => IsisPduRef::Unknown(raw),
+        }
+    }
+
+    /// TLVs of the PDU, empty for unknown PDU types.
+    pub fn tlvs(&self) -> IsisTlvIter<'a> {
+        use IsisPduRef::*;
+        match self.pdu() {
+            L1Hello(v) | L2Hello(v) => v.tlvs(),
+            P2pHello(v) => v.tlvs(),
+            L1Lsp(v) | L2Lsp(v) => v.tlvs(),
+            L1Csnp(v) | L2Csnp(v) => v.tlvs(),
+            L1Psnp(v) | L2Psnp(v) => v.tlvs(),
+            Unknown(_) => IsisTlvIter::new(&[]),
+        }
+    }
+
+    /// Fully decode the packet, same as `parse()` on the PDU bytes.
+    pub fn into_owned(self) -> Result<IsisPacket, IsisParseError> {
+        let (_, packet) = parse(self.raw)?;
+        Ok(packet)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum IsisPduRef<'a> {
+    L1Hello(IsisHelloRef<'a>),
+    L2Hello(IsisHelloRef<'a>),
+    P2pHello(IsisP2pHelloRef<'a>),
+    L1Lsp(IsisLspRef<'a>),
+    L2Lsp(IsisLspRef<'a>),
+    L1Csnp(IsisCsnpRef<'a>),
+    L2Csnp(IsisCsnpRef<'a>),
+    L1Psnp(IsisPsnpRef<'a>),
+    L2Psnp(IsisPsnpRef<'a>),
+    Unknown(&'a [u8]),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct IsisHelloRef<'a> {
+    raw: &'a [u8],
+}
+
+impl<'a> IsisHelloRef<'a> {
+    pub fn circuit_type(&self) -> IsLevel {
+        IsLevel::from(self.raw[0])
+    }
+
+    pub fn source_id(&self) -> IsisSysId {
+        sys_id_at(self.raw, 1)
+    }
+
+    pub fn hold_time(&self) -> u16 {
+        u16_at(self.raw, 7)
+    }
+
+    pub fn pdu_len(&self) -> u16 {
+        u16_at(self.raw, 9)
+    }
+
+    pub fn priority(&self) -> u8 {
+        self.raw[11]
+    }
+
+    pub fn lan_id(&self) -> IsisNeighborId {
+        neighbor_id_at(self.raw, 12)
+    }
+
+    pub fn tlvs(&self) -> IsisTlvIter<'a> {
+        IsisTlvIter::new(&self.raw[HELLO_HEADER_LEN..])
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct IsisP2pHelloRef<'a> {
+    raw: &'a [u8],
+}
+
+impl<'a> IsisP2pHelloRef<'a> {
+    pub fn circuit_type(&self) -> IsLevel {
+        IsLevel::from(self.raw[0])
+    }
+
+    pub fn source_id(&self) -> IsisSysId {
+        sys_id_at(self.raw, 1)
+    }
+
+    pub fn hold_time(&self) -> u16 {
+        u16_at(self.raw, 7)
+    }
+
+    pub fn pdu_len(&self) -> u16 {
+        u16_at(self.raw, 9)
+    }
+
+    pub fn circuit_id(&self) -> u8 {
+        self.raw[11]
+    }
+
+    pub fn tlvs(&self) -> IsisTlvIter<'a> {
+        IsisTlvIter::new(&self.raw[P2P_HELLO_HEADER_LEN..])
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct IsisLspRef<'a> {
+    raw: &'a [u8],
+}
+
+impl<'a> IsisLspRef<'a> {
+    pub fn pdu_len(&self) -> u16 {
+        u16_at(self.raw, 0)
+    }
+
+    pub fn hold_time(&self) -> u16 {
+        u16_at(self.raw, 2)
+    }
+
+    pub fn lsp_id(&self) -> IsisLspId {
+        lsp_id_at(self.raw, 4)
+    }
+
+    pub fn seq_number(&self) -> u32 {
+        u32_at(self.raw, 12)
+    }
+
+    pub fn checksum(&self) -> u16 {
+        u16_at(self.raw, 16)
+    }
+
+    pub fn types(&self) -> IsisLspTypes {
+        self.raw[18].into()
+    }
+
+    pub fn entry(&self) -> IsisLspEntry {
+        IsisLspEntry {
+            hold_time: self.hold_time(),
+            lsp_id: self.lsp_id(),
+            seq_number: self.seq_number(),
+            checksum: self.checksum(),
+        }
+    }
+
+    pub fn hostname(&self) -> Option<Cow<'a, str>> {
+        self.tlvs().flatten().find_map(|tlv| match tlv.view() {
+            Ok(IsisTlvView::Hostname(hostname)) => Some(hostname),
+            _ => None,
+        })
+    }
+
+    pub fn tlvs(&self) -> IsisTlvIter<'a> {
+        IsisTlvIter::new(&self.raw[LSP_HEADER_LEN..])
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct IsisCsnpRef<'a> {
+    raw: &'a [u8],
+}
+
+impl<'a> IsisCsnpRef<'a> {
+    pub fn pdu_len(&self) -> u16 {
+        u16_at(self.raw, 0)
+    }
+
+    pub fn source_id(&self) -> IsisSysId {
+        sys_id_at(self.raw, 2)
+    }
+
+    pub fn source_id_circuit(&self) -> u8 {
+        self.raw[8]
+    }
+
+    pub fn start(&self) -> IsisLspId {
+        lsp_id_at(self.raw, 9)
+    }
+
+    pub fn end(&self) -> IsisLspId {
+        lsp_id_at(self.raw, 17)
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = IsisLspEntry> + 'a {
+        tlvs_entries(self.tlvs())
+    }
+
+    pub fn tlvs(&self) -> IsisTlvIter<'a> {
+        IsisTlvIter::new(&self.raw[CSNP_HEADER_LEN..])
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct IsisPsnpRef<'a> {
+    raw: &'a [u8],
+}
+
+impl<'a> IsisPsnpRef<'a> {
+    pub fn pdu_len(&self) -> u16 {
+        u16_at(self.raw, 0)
+    }
+
+    pub fn source_id(&self) -> IsisSysId {
+        sys_id_at(self.raw, 2)
+    }
+
+    pub fn source_id_circuit(&self) -> u8 {
+        self.raw[8]
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = IsisLspEntry> + 'a {
+        tlvs_entries(self.tlvs())
+    }
+
+    pub fn tlvs(&self) -> IsisTlvIter<'a> {
+        IsisTlvIter::new(&self.raw[PSNP_HEADER_LEN..])
+    }
+}
+
+fn tlvs_entries(tlvs: IsisTlvIter<'_>) -> impl Iterator<Item = IsisLspEntry> + '_ {
+    tlvs.flatten()
+        .filter_map(|tlv| match tlv.view() {
+            Ok(IsisTlvView::LspEntries(entries)) => Some(entries),
+            _ => None,
+        })
+        .flatten()
+}
+
+/// Lazy iterator over the TLVs of a PDU. A truncated TLV yields an error
+/// and ends the iteration.
+#[derive(Debug, Clone)]
+pub struct IsisTlvIter<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> IsisTlvIter<'a> {
+    pub fn new(input: &'a [u8]) -> Self {
+        Self { input }
+    }
+}
+
+impl<'a> Iterator for IsisTlvIter<'a> {
+    type Item = Result<IsisTlvRef<'a>, IsisParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.input.is_empty() {
+            return None;
+        }
+        let input = std::mem::take(&mut self.input);
+        if input.len() < 2 {
+            return Some(Err(IsisParseError::incomplete_data(2 - input.len())));
+        }
+        let len = input[1] as usize;
+        if input.len() < 2 + len {
+            return Some(Err(IsisParseError::InvalidTlvLength {
+                tlv_type: input[0].into(),
+                expected: len,
+                found: input.len() - 2,
+            }));
+        }
+        let (raw, rest) = input.split_at(2 + len);
+        self.input = rest;
+        Some(Ok(IsisTlvRef { raw }))
+    }
+}
+
+/// One TLV borrowed from the PDU, including its type and length octets.
+#[derive(Debug, Clone, Copy)]
+pub struct IsisTlvRef<'a> {
+    raw: &'a [u8],
+}
+
+impl<'a> IsisTlvRef<'a> {
+    pub fn typ(&self) -> IsisTlvType {
+        self.raw[0].into()
+    }
+
+    pub fn value(&self) -> &'a [u8] {
+        &self.raw[2..]
+    }
+
+    pub fn raw(&self) -> &'a [u8] {
+        self.raw
+    }
+
+    /// Typed view of the value. Fixed size values which are too short are
+    /// reported as an error, variable entries stop at the first truncated
+    /// one.
+    pub fn view(&self) -> Result<IsisTlvView<'a>, IsisParseError> {
+        use IsisTlvType::*;
+        let value = self.value();
+        let view = match self.typ() {
+            AreaAddr => IsisTlvView::AreaAddr(IsisEntryIter::new(value, area_addr_entry)),
+            IsNeighbor => IsisTlvView::IsNeighbor(IsisEntryIter::new(value, neighbor_entry)),
+            Padding => IsisTlvView::Padding(value),
+            LspEntries => IsisTlvView::LspEntries(IsisEntryIter::new(value, lsp_entry)),
+            ExtIsReach => IsisTlvView::ExtIsReach(IsisEntryIter::new(value, ext_is_reach_entry)),
+            Srv6 => {
+                let value = self.fixed(value, 2)?;
+                IsisTlvView::Srv6 {
+                    flags: u16_at(value, 0).into(),
+                    locators: IsisEntryIter::new(&value[2..], srv6_locator_entry),
+                }
+            }
+            ProtSupported => IsisTlvView::ProtoSupported(value),
+            Ipv4IfAddr => IsisTlvView::Ipv4IfAddr(self.ipv4(value)?),
+            TeRouterId => IsisTlvView::TeRouterId(self.ipv4(value)?),
+            ExtIpReach => IsisTlvView::ExtIpReach(IsisEntryIter::new(value, ext_ip_reach_entry)),
+            DynamicHostname => IsisTlvView::Hostname(String::from_utf8_lossy(value)),
+            Ipv6TeRouterId => IsisTlvView::Ipv6TeRouterId(self.ipv6(value)?),
+            Ipv6IfAddr => IsisTlvView::Ipv6IfAddr(self.ipv6(value)?),
+            Ipv6GlobalIfAddr => IsisTlvView::Ipv6GlobalIfAddr(self.ipv6(value)?),
+            MtIpReach => {
+                let value = self.fixed(value, 2)?;
+                IsisTlvView::MtIpReach {
+                    mt: u16_at(value, 0).into(),
+                    entries: IsisEntryIter::new(&value[2..], ext_ip_reach_entry),
+                }
+            }
+            Ipv6Reach => IsisTlvView::Ipv6Reach(IsisEntryIter::new(value, ipv6_reach_entry)),
+            MtIpv6Reach => {
+                let value = self.fixed(value, 2)?;
+                IsisTlvView::MtIpv6Reach {
+                    mt: u16_at(value, 0).into(),
+                    entries: IsisEntryIter::new(&value[2..], ipv6_reach_entry),
+                }
+            }
+            P2p3Way => {
+                let value = self.fixed(value, 16)?;
+                IsisTlvView::P2p3Way(IsisTlvP2p3Way {
+                    state: value[0],
+                    circuit_id: u32_at(value, 1),
+                    neighbor_id: neighbor_id_at(value, 5),
+                    neighbor_circuit_id: u32_at(value, 12),
+                })
+            }
+            RouterCap => {
+                let value = self.fixed(value, 5)?;
+                IsisTlvView::RouterCap {
+                    router_id: Ipv4Addr::from(u32_at(value, 0)),
+                    flags: value[4].into(),
+                    subs: IsisEntryIter::new(&value[5..], sub_tlv_entry),
+                }
+            }
+            typ => IsisTlvView::Unknown(typ, value),
+        };
+        Ok(view)
+    }
+
+    /// Decode into the owned TLV, same as `IsisTlv::parse_tlv()`.
+    pub fn into_owned(self) -> Result<IsisTlv, IsisParseError> {
+        let (_, tlv) = IsisTlv::parse_tlv(self.raw)?;
+        Ok(tlv)
+    }
+
+    fn fixed(&self, value: &'a [u8], len: usize) -> Result<&'a [u8], IsisParseError> {
+        if value.len() < len {
+            return Err(IsisParseError::InvalidTlvLength {
+                tlv_type: self.typ(),
+                expected: len,
+                found: value.len(),
+            });
+        }
+        Ok(value)
+    }
+
+    fn ipv4(&self, value: &'a [u8]) -> Result<Ipv4Addr, IsisParseError> {
+        let value = self.fixed(value, 4)?;
+        Ok(Ipv4Addr::from(u32_at(value, 0)))
+    }
+
+    fn ipv6(&self, value: &'a [u8]) -> Result<Ipv6Addr, IsisParseError> {
+        let value = self.fixed(value, 16)?;
+        let mut octets = [0u8; 16];
+        octets.copy_from_slice(&value[..16]);
+        Ok(Ipv6Addr::from(octets))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum IsisTlvView<'a> {
+    AreaAddr(IsisEntryIter<'a, &'a [u8]>),
+    IsNeighbor(IsisEntryIter<'a, NeighborAddr>),
+    Padding(&'a [u8]),
+    LspEntries(IsisEntryIter<'a, IsisLspEntry>),
+    ExtIsReach(IsisEntryIter<'a, IsisExtIsReachRef<'a>>),
+    Srv6 {
+        flags: Srv6TlvFlags,
+        locators: IsisEntryIter<'a, IsisSrv6LocatorRef<'a>>,
+    },
+    ProtoSupported(&'a [u8]),
+    Ipv4IfAddr(Ipv4Addr),
+    TeRouterId(Ipv4Addr),
+    ExtIpReach(IsisEntryIter<'a, IsisExtIpReachRef<'a>>),
+    Hostname(Cow<'a, str>),
+    Ipv6TeRouterId(Ipv6Addr),
+    Ipv6IfAddr(Ipv6Addr),
+    Ipv6GlobalIfAddr(Ipv6Addr),
+    MtIpReach {
+        mt: MultiTopologyId,
+        entries: IsisEntryIter<'a, IsisExtIpReachRef<'a>>,
+    },
+    Ipv6Reach(IsisEntryIter<'a, IsisIpv6ReachRef<'a>>),
+    MtIpv6Reach {
+        mt: MultiTopologyId,
+        entries: IsisEntryIter<'a, IsisIpv6ReachRef<'a>>,
+    },
+    P2p3Way(IsisTlvP2p3Way),
+    RouterCap {
+        router_id: Ipv4Addr,
+        flags: RouterCapFlags,
+        subs: IsisEntryIter<'a, IsisSubTlvRef<'a>>,
+    },
+    Unknown(IsisTlvType, &'a [u8]),
+}
+
+type EntryParser<'a, T> = fn(&'a [u8]) -> Option<(&'a [u8], T)>;
+
+/// Lazy iterator over the entries of a TLV value. Iteration stops at the
+/// first truncated entry.
+#[derive(Debug, Clone)]
+pub struct IsisEntryIter<'a, T> {
+    input: &'a [u8],
+    parser: EntryParser<'a, T>,
+}
+
+impl<'a, T> IsisEntryIter<'a, T> {
+    fn new(input: &'a [u8], parser: EntryParser<'a, T>) -> Self {
+        Self { input, parser }
+    }
+
+    /// Bytes not consumed yet.
+    pub fn remaining(&self) -> &'a [u8] {
+        self.input
+    }
+}
+
+impl<'a, T> Iterator for IsisEntryIter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.input.is_empty() {
+            return None;
+        }
+        let input = std::mem::take(&mut self.input);
+        let (rest, entry) = (self.parser)(input)?;
+        self.input = rest;
+        Some(entry)
+    }
+}
+
+/// Sub-TLV borrowed from a TLV entry. The code space depends on the
+/// enclosing TLV, so the typed sub-TLV is decoded on request with the
+/// accessor matching it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IsisSubTlvRef<'a> {
+    pub code: u8,
+    pub value: &'a [u8],
+    raw: &'a [u8],
+}
+
+impl<'a> IsisSubTlvRef<'a> {
+    /// The sub-TLV including its code and length octets.
+    pub fn raw(&self) -> &'a [u8] {
+        self.raw
+    }
+
+    /// Sub-TLV of the Extended IS Reachability TLVs.
+    pub fn neigh(&self) -> Result<neigh::IsisSubTlv, IsisParseError> {
+        let (_, sub) = neigh::IsisSubTlv::parse_subs(self.raw)?;
+        Ok(sub)
+    }
+
+    /// Sub-TLV of the IP Reachability and SRv6 Locator TLVs.
+    pub fn prefix(&self) -> Result<prefix::IsisSubTlv, IsisParseError> {
+        let (_, sub) = prefix::IsisSubTlv::parse_subs(self.raw)?;
+        Ok(sub)
+    }
+
+    /// Sub-TLV of the Router Capability TLV.
+    pub fn cap(&self) -> Result<cap::IsisSubTlv, IsisParseError> {
+        let (_, sub) = cap::IsisSubTlv::parse_subs(self.raw)?;
+        Ok(sub)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IsisExtIsReachRef<'a> {
+    pub neighbor_id: IsisNeighborId,
+    pub metric: u32,
+    pub subs: IsisEntryIter<'a, IsisSubTlvRef<'a>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct IsisExtIpReachRef<'a> {
+    pub metric: u32,
+    pub flags: Ipv4ControlInfo,
+    pub prefix: Ipv4Net,
+    pub subs: IsisEntryIter<'a, IsisSubTlvRef<'a>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct IsisIpv6ReachRef<'a> {
+    pub metric: u32,
+    pub flags: Ipv6ControlInfo,
+    pub prefix: Ipv6Net,
+    pub subs: IsisEntryIter<'a, IsisSubTlvRef<'a>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct IsisSrv6LocatorRef<'a> {
+    pub metric: u32,
+    pub flags: u8,
+    pub algo: Algo,
+    pub locator: Ipv6Net,
+    pub subs: IsisEntryIter<'a, IsisSubTlvRef<'a>>,
+}
+
+// Split a length prefixed block off the input.
+fn take_len(input: &[u8]) -> Option<(&[u8], &[u8])> {
+    let (&len, input) = input.split_first()?;
+    if input.len() < len as usize {
+        return None;
+    }
+    let (block, rest) = input.split_at(len as usize);
+    Some((rest, block))
+}
+
+fn sub_tlv_entry(input: &[u8]) -> Option<(&[u8], IsisSubTlvRef<'_>)> {
+    let (&code, body) = input.split_first()?;
+    let (rest, value) = take_len(body)?;
+    let raw = &input[..value.len() + 2];
+    Some((rest, IsisSubTlvRef { code, value, raw }))
+}
+
+fn area_addr_entry(input: &[u8]) -> Option<(&[u8], &[u8])> {
+    take_len(input)
+}
+
+fn neighbor_entry(input: &[u8]) -> Option<(&[u8], NeighborAddr)> {
+    if input.len() < 6 {
+        return None;
+    }
+    let mut octets = [0u8; 6];
+    octets.copy_from_slice(&input[..6]);
+    Some((&input[6..], NeighborAddr { octets }))
+}
+
+fn lsp_entry(input: &[u8]) -> Option<(&[u8], IsisLspEntry)> {
+    if input.len() < LSP_ENTRY_LEN as usize {
+        return None;
+    }
+    let entry = IsisLspEntry {
+        hold_time: u16_at(input, 0),
+        lsp_id: lsp_id_at(input, 2),
+        seq_number: u32_at(input, 10),
+        checksum: u16_at(input, 14),
+    };
+    Some((&input[LSP_ENTRY_LEN as usize..], entry))
+}
+
+fn ext_is_reach_entry(input: &[u8]) -> Option<(&[u8], IsisExtIsReachRef<'_>)> {
+    if input.len() < 10 {
+        return None;
+    }
+    let neighbor_id = neighbor_id_at(input, 0);
+    let metric = u32::from_be_bytes([0, input[7], input[8], input[9]]);
+    let (rest, subs) = take_len(&input[10..])?;
+    let entry = IsisExtIsReachRef {
+        neighbor_id,
+        metric,
+        subs: IsisEntryIter::new(subs, sub_tlv_entry),
+    };
+    Some((rest, entry))
+}
+
+fn ext_ip_reach_entry(input: &[u8]) -> Option<(&[u8], IsisExtIpReachRef<'_>)> {
+    if input.len() < 5 {
+        return None;
+    }
+    let metric = u32_at(input, 0);
+    let flags: Ipv4ControlInfo = input[4].into();
+    let (input, prefix) = ptake(&input[5..], flags.prefixlen() as u8).ok()?;
+    let (rest, subs) = if flags.sub_tlv() {
+        take_len(input)?
+    } else {
+        (input, &[][..])
+    };
+    let entry = IsisExtIpReachRef {
+        metric,
+        flags,
+        prefix,
+        subs: IsisEntryIter::new(subs, sub_tlv_entry),
+    };
+    Some((rest, entry))
+}
+
+fn ipv6_reach_entry(input: &[u8]) -> Option<(&[u8], IsisIpv6ReachRef<'_>)> {
+    if input.len() < 6 {
+        return None;
+    }
+    let metric = u32_at(input, 0);
+    let flags: Ipv6ControlInfo = input[4].into();
+    let (input, prefix) = ptakev6(&input[6..], input[5]).ok()?;
+    let (rest, subs) = if flags.sub_tlv() {
+        take_len(input)?
+    } else {
+        (input, &[][..])
+    };
+    let entry = IsisIpv6ReachRef {
+        metric,
+        flags,
+        prefix,
+        subs: IsisEntryIter::new(subs, sub_tlv_entry),
+    };
+    Some((rest, entry))
+}
+
+fn srv6_locator_entry(input: &[u8]) -> Option<(&[u8], IsisSrv6LocatorRef<'_>)> {
+    if input.len() < 7 {
+        return None;
+    }
+    let metric = u32_at(input, 0);
+    let flags = input[4];
+    let algo = input[5].into();
+    let (input, locator) = ptakev6(&input[7..], input[6]).ok()?;
+    let (rest, subs) = take_len(input)?;
+    let entry = IsisSrv6LocatorRef {
+        metric,
+        flags,
+        algo,
+        locator,
+        subs: IsisEntryIter::new(subs, sub_tlv_entry),
+    };
+    Some((rest, entry))
+}
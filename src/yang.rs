@@ -0,0 +1,584 @@
+use bytes::BytesMut;
+use serde_json::{Map, Value, json};
+
+use crate::neigh::IsisSubTlv as NeighSubTlv;
+use crate::prefix::IsisSubTlv as PrefixSubTlv;
+use crate::prefix::{IsisSub2Tlv, Srv6Locator};
+use crate::{
+    AdjSidFlags, Algo, Behavior, IsisLsdb, IsisLsp, IsisLspTypes, IsisSubTlvUnknown, IsisTlv,
+    IsisTlvExtIpReachEntry, IsisTlvExtIsReachEntry, IsisTlvIpv6ReachEntry, IsisTlvRouterCap,
+    PrefixSidFlags, SidLabelTlv, SidLabelValue,
+};
+
+// Module names used to qualify augmented nodes (RFC 7951 section 4).
+const SR_MPLS: &str = "ietf-isis-sr-mpls";
+const SRV6: &str = "ietf-isis-srv6";
+const SR_COMMON: &str = "ietf-segment-routing-common";
+
+fn augment(module: &str, name: &str) -> String {
+    format!("{module}:{name}")
+}
+
+// yang:hex-string, octets separated by colons.
+fn hex_string(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+// Entry of an `unknown-tlvs` list from an emitted TLV.
+fn unknown_tlv(buf: &[u8]) -> Value {
+    json!({
+        "type": buf[0],
+        "length": buf[1],
+        "value": hex_string(&buf[2..]),
+    })
+}
+
+fn unknown_sub(sub: &IsisSubTlvUnknown) -> Value {
+    json!({
+        "type": sub.code,
+        "length": sub.len,
+        "value": hex_string(&sub.data),
+    })
+}
+
+fn insert_list(obj: &mut Map<String, Value>, container: &str, list: &str, values: Vec<Value>) {
+    if !values.is_empty() {
+        obj.insert(container.into(), json!({ list: values }));
+    }
+}
+
+fn lsp_flags(types: &IsisLspTypes) -> Vec<&'static str> {
+    let mut flags = Vec::new();
+    if types.p_bits() {
+        flags.push("lsp-partitioned-flag");
+    }
+    let att = types.att_bits();
+    if att & 0x08 != 0 {
+        flags.push("lsp-attached-error-metric-flag");
+    }
+    if att & 0x04 != 0 {
+        flags.push("lsp-attached-expense-metric-flag");
+    }
+    if att & 0x02 != 0 {
+        flags.push("lsp-attached-delay-metric-flag");
+    }
+    if att & 0x01 != 0 {
+        flags.push("lsp-attached-default-metric-flag");
+    }
+    if types.ol_bits() {
+        flags.push("lsp-overload-flag");
+    }
+    flags
+}
+
+// Algorithms without an identity in ietf-segment-routing-common, such as
+// Flex-Algorithms, are left out.
+fn algorithm(algo: &Algo) -> Option<String> {
+    match algo {
+        Algo::Spf => Some(augment(SR_COMMON, "prefix-sid-algorithm-shortest-path")),
+        Algo::StrictSpf => Some(augment(SR_COMMON, "prefix-sid-algorithm-strict-spf")),
+        _ => None,
+    }
+}
+
+fn sid_value(sid: &SidLabelValue) -> u32 {
+    match sid {
+        SidLabelValue::Label(v) | SidLabelValue::Index(v) => *v,
+    }
+}
+
+fn sid_label(sid: &SidLabelTlv) -> u32 {
+    match sid {
+        SidLabelTlv::Label(v) | SidLabelTlv::Index(v) => *v,
+    }
+}
+
+fn behavior(behavior: &Behavior) -> Value {
+    match behavior {
+        Behavior::Resv(v) => json!(v),
+        _ => json!(behavior.to_string()),
+    }
+}
+
+fn sid_structure(sub2s: &[IsisSub2Tlv]) -> Option<Value> {
+    sub2s.iter().find_map(|sub2| match sub2 {
+        IsisSub2Tlv::SidStructure(v) => Some(json!({
+            "locator-block-length": v.lb_len,
+            "locator-node-length": v.ln_len,
+            "function-length": v.fun_len,
+            "argument-length": v.arg_len,
+        })),
+        _ => None,
+    })
+}
+
+fn prefix_sid_flags(flags: &PrefixSidFlags) -> Vec<String> {
+    [
+        (flags.r_flag(), "prefix-flag-readvertisement"),
+        (flags.n_flag(), "prefix-flag-node"),
+        (flags.p_flag(), "prefix-flag-no-php"),
+        (flags.e_flag(), "prefix-flag-explicit-null"),
+        (flags.v_flag(), "prefix-flag-value"),
+        (flags.l_flag(), "prefix-flag-local"),
+    ]
+    .into_iter()
+    .filter(|(set, _)| *set)
+    .map(|(_, name)| augment(SR_MPLS, name))
+    .collect()
+}
+
+fn adj_sid_flags(flags: &AdjSidFlags) -> Vec<String> {
+    [
+        (flags.f_flag(), "adj-flag-address-family"),
+        (flags.b_flag(), "adj-flag-backup"),
+        (flags.v_flag(), "adj-flag-value"),
+        (flags.l_flag(), "adj-flag-local"),
+        (flags.s_flag(), "adj-flag-set"),
+        (flags.p_flag(), "adj-flag-persistent"),
+    ]
+    .into_iter()
+    .filter(|(set, _)| *set)
+    .map(|(_, name)| augment(SR_MPLS, name))
+    .collect()
+}
+
+// Sub-TLVs of extended reachability prefixes.
+fn prefix_subs(obj: &mut Map<String, Value>, subs: &[PrefixSubTlv]) {
+    let mut sids = Vec::new();
+    let mut unknown = Vec::new();
+    for sub in subs {
+        match sub {
+            PrefixSubTlv::PrefixSid(v) => {
+                let mut sid = json!({
+                    "prefix-sid-flags": { "flag": prefix_sid_flags(&v.flags) },
+                    "sid": sid_value(&v.sid),
+                });
+                if let Some(algo) = algorithm(&v.algo) {
+                    sid["algorithm"] = json!(algo);
+                }
+                sids.push(sid);
+            }
+            PrefixSubTlv::Unknown(v) => unknown.push(unknown_sub(v)),
+            _ => {
+                let mut buf = BytesMut::new();
+                sub.emit(&mut buf);
+                unknown.push(unknown_tlv(&buf));
+            }
+        }
+    }
+    insert_list(
+        obj,
+        &augment(SR_MPLS, "prefix-sid-sub-tlvs"),
+        "prefix-sid-sub-tlv",
+        sids,
+    );
+    insert_list(obj, "unknown-tlvs", "unknown-tlv", unknown);
+}
+
+fn ipv4_prefix(entry: &IsisTlvExtIpReachEntry, mt_id: Option<u16>) -> Value {
+    let mut obj = Map::new();
+    if let Some(mt_id) = mt_id {
+        obj.insert("mt-id".into(), json!(mt_id));
+    }
+    obj.insert("up-down".into(), json!(entry.flags.distribution()));
+    obj.insert("ip-prefix".into(), json!(entry.prefix.addr().to_string()));
+    obj.insert("prefix-len".into(), json!(entry.prefix.prefix_len()));
+    obj.insert("metric".into(), json!(entry.metric));
+    prefix_subs(&mut obj, &entry.subs);
+    Value::Object(obj)
+}
+
+fn ipv6_prefix(entry: &IsisTlvIpv6ReachEntry, mt_id: Option<u16>) -> Value {
+    let mut obj = Map::new();
+    if let Some(mt_id) = mt_id {
+        obj.insert("mt-id".into(), json!(mt_id));
+    }
+    obj.insert("up-down".into(), json!(entry.flags.dist_up()));
+    obj.insert("ip-prefix".into(), json!(entry.prefix.addr().to_string()));
+    obj.insert("prefix-len".into(), json!(entry.prefix.prefix_len()));
+    obj.insert("metric".into(), json!(entry.metric));
+    obj.insert(
+        "external-prefix-flag".into(),
+        json!(entry.flags.dist_internal()),
+    );
+    prefix_subs(&mut obj, &entry.subs);
+    Value::Object(obj)
+}
+
+fn neighbor_instance(id: usize, entry: &IsisTlvExtIsReachEntry) -> Value {
+    let mut obj = Map::new();
+    obj.insert("id".into(), json!(id));
+    obj.insert("metric".into(), json!(entry.metric));
+
+    let mut local = Vec::new();
+    let mut remote = Vec::new();
+    let mut adj_sids = Vec::new();
+    let mut endx_sids = Vec::new();
+    let mut unknown = Vec::new();
+    for sub in &entry.subs {
+        match sub {
+            NeighSubTlv::Ipv4IfAddr(v) => local.push(json!(v.addr.to_string())),
+            NeighSubTlv::Ipv4NeighAddr(v) => remote.push(json!(v.addr.to_string())),
+            NeighSubTlv::AdjSid(v) => adj_sids.push(json!({
+                "adj-sid-flags": { "flag": adj_sid_flags(&v.flags) },
+                "weight": v.weight,
+                "sid": sid_value(&v.sid),
+            })),
+            NeighSubTlv::LanAdjSid(v) => adj_sids.push(json!({
+                "adj-sid-flags": { "flag": adj_sid_flags(&v.flags) },
+                "weight": v.weight,
+                "neighbor-id": v.system_id.to_string(),
+                "sid": sid_value(&v.sid),
+            })),
+            NeighSubTlv::Srv6EndXSid(v) => {
+                let mut sid = json!({
+                    "flags": v.flags,
+                    "algorithm": v.algo.to_byte(),
+                    "weight": v.weight,
+                    "endpoint-behavior": behavior(&v.behavior),
+                    "sid": v.sid.to_string(),
+                });
+                if let Some(structure) = sid_structure(&v.sub2s) {
+                    sid["sid-structure"] = structure;
+                }
+                endx_sids.push(sid);
+            }
+            NeighSubTlv::Srv6LanEndXSid(v) => {
+                let mut sid = json!({
+                    "neighbor-id": v.system_id.to_string(),
+                    "flags": v.flags,
+                    "algorithm": v.algo.to_byte(),
+                    "weight": v.weight,
+                    "endpoint-behavior": behavior(&v.behavior),
+                    "sid": v.sid.to_string(),
+                });
+                if let Some(structure) = sid_structure(&v.sub2s) {
+                    sid["sid-structure"] = structure;
+                }
+                endx_sids.push(sid);
+            }
+            NeighSubTlv::Unknown(v) => unknown.push(unknown_sub(v)),
+            _ => {
+                let mut buf = BytesMut::new();
+                sub.emit(&mut buf);
+                unknown.push(unknown_tlv(&buf));
+            }
+        }
+    }
+    insert_list(&mut obj, "local-if-ipv4-addrs", "local-if-ipv4-addr", local);
+    insert_list(
+        &mut obj,
+        "remote-if-ipv4-addrs",
+        "remote-if-ipv4-addr",
+        remote,
+    );
+    insert_list(
+        &mut obj,
+        &augment(SR_MPLS, "adj-sid-sub-tlvs"),
+        "adj-sid-sub-tlv",
+        adj_sids,
+    );
+    insert_list(
+        &mut obj,
+        &augment(SRV6, "srv6-endx-sid-sub-tlvs"),
+        "srv6-endx-sid-sub-tlv",
+        endx_sids,
+    );
+    insert_list(&mut obj, "unknown-tlvs", "unknown-tlv", unknown);
+    Value::Object(obj)
+}
+
+// Parallel adjacencies to one neighbor are instances of the same neighbor.
+fn neighbors<'a>(entries: impl Iterator<Item = &'a IsisTlvExtIsReachEntry>) -> Vec<Value> {
+    let mut neighbors: Vec<(String, Vec<Value>)> = Vec::new();
+    for entry in entries {
+        let neighbor_id = entry.neighbor_id.to_string();
+        let pos = match neighbors.iter().position(|(id, _)| *id == neighbor_id) {
+            Some(pos) => pos,
+            None => {
+                neighbors.push((neighbor_id, Vec::new()));
+                neighbors.len() - 1
+            }
+        };
+        let instances = &mut neighbors[pos].1;
+        instances.push(neighbor_instance(instances.len(), entry));
+    }
+    neighbors
+        .into_iter()
+        .map(|(neighbor_id, instances)| {
+            json!({
+                "neighbor-id": neighbor_id,
+                "instances": { "instance": instances },
+            })
+        })
+        .collect()
+}
+
+fn router_capability(tlv: &IsisTlvRouterCap) -> Value {
+    use crate::cap::IsisSubTlv::*;
+
+    let mut flags = Vec::new();
+    if tlv.flags.s_flag() {
+        flags.push("router-capability-flooding-flag");
+    }
+    if tlv.flags.d_flag() {
+        flags.push("router-capability-down-flag");
+    }
+    let mut obj = Map::new();
+    obj.insert("flags".into(), json!({ "router-capability-flags": flags }));
+
+    let mut unknown = Vec::new();
+    for sub in &tlv.subs {
+        match sub {
+            SegmentRoutingCap(v) => {
+                let mut cap_flags = Vec::new();
+                if v.flags.i_flag() {
+                    cap_flags.push(augment(SR_MPLS, "sr-capability-flag-ipv4"));
+                }
+                if v.flags.v_flag() {
+                    cap_flags.push(augment(SR_MPLS, "sr-capability-flag-ipv6"));
+                }
+                obj.insert(
+                    augment(SR_MPLS, "sr-capability"),
+                    json!({
+                        "sr-capability": { "sr-capability-flags": cap_flags },
+                        "global-blocks": {
+                            "global-block": [{
+                                "range-size": v.range,
+                                "sid-sub-tlv": { "sid": sid_label(&v.sid_label) },
+                            }],
+                        },
+                    }),
+                );
+            }
+            SegmentRoutingAlgo(v) => {
+                let algos: Vec<String> = v.algo.iter().filter_map(algorithm).collect();
+                obj.insert(
+                    augment(SR_MPLS, "sr-algorithms"),
+                    json!({ "sr-algorithm": algos }),
+                );
+            }
+            SegmentRoutingLB(v) => {
+                obj.insert(
+                    augment(SR_MPLS, "local-blocks"),
+                    json!({
+                        "local-block": [{
+                            "range-size": v.range,
+                            "sid-sub-tlv": { "sid": sid_label(&v.sid_label) },
+                        }],
+                    }),
+                );
+            }
+            NodeMaxSidDepth(v) => {
+                obj.insert(
+                    augment(SR_MPLS, "node-msd-tlv"),
+                    json!({
+                        "node-msds": [{ "msd-type": v.flags, "msd-value": v.depth }],
+                    }),
+                );
+            }
+            Srv6(v) => {
+                let mut srv6_flags = Vec::new();
+                if v.flags.o_flag() {
+                    srv6_flags.push("o-flag");
+                }
+                obj.insert(
+                    augment(SRV6, "srv6-capability"),
+                    json!({ "flags": { "srv6-capability-flag": srv6_flags } }),
+                );
+            }
+            FlexAlgoDef(_) | AreaLeader(_) | DynamicFlooding(_) => {
+                let mut buf = BytesMut::new();
+                sub.emit(&mut buf);
+                unknown.push(unknown_tlv(&buf));
+            }
+            Unknown(v) => unknown.push(unknown_sub(v)),
+        }
+    }
+    insert_list(&mut obj, "unknown-tlvs", "unknown-tlv", unknown);
+    Value::Object(obj)
+}
+
+fn srv6_locator(mt_id: u16, locator: &Srv6Locator) -> Value {
+    let mut obj = Map::new();
+    obj.insert("mt-id".into(), json!(mt_id));
+    obj.insert("metric".into(), json!(locator.metric));
+    obj.insert("flags".into(), json!(locator.flags));
+    obj.insert("algorithm".into(), json!(locator.algo.to_byte()));
+    obj.insert("locator".into(), json!(locator.locator.addr().to_string()));
+    obj.insert("prefix-len".into(), json!(locator.locator.prefix_len()));
+
+    let mut end_sids = Vec::new();
+    let mut unknown = Vec::new();
+    for sub in &locator.subs {
+        match sub {
+            PrefixSubTlv::Srv6EndSid(v) => {
+                let mut sid = json!({
+                    "flags": v.flags,
+                    "endpoint-behavior": behavior(&v.behavior),
+                    "sid": v.sid.to_string(),
+                });
+                if let Some(structure) = sid_structure(&v.sub2s) {
+                    sid["sid-structure"] = structure;
+                }
+                end_sids.push(sid);
+            }
+            PrefixSubTlv::Unknown(v) => unknown.push(unknown_sub(v)),
+            _ => {
+                let mut buf = BytesMut::new();
+                sub.emit(&mut buf);
+                unknown.push(unknown_tlv(&buf));
+            }
+        }
+    }
+    insert_list(&mut obj, "srv6-end-sids", "srv6-end-sid", end_sids);
+    insert_list(&mut obj, "unknown-tlvs", "unknown-tlv", unknown);
+    Value::Object(obj)
+}
+
+impl IsisLsp {
+    /// Entry of the RFC 9130 `ietf-isis` database `lsp` list, with the
+    /// segment routing augmentations.
+    pub fn yang(&self) -> Value {
+        let mut ipv4_addrs = Vec::new();
+        let mut ipv6_addrs = Vec::new();
+        let mut ipv4_te_routerid = None;
+        let mut ipv6_te_routerid = None;
+        let mut protocols = Vec::new();
+        let mut hostname = None;
+        let mut caps = Vec::new();
+        let mut is_reach = Vec::new();
+        let mut ipv4_reach = Vec::new();
+        let mut mt_ipv4_reach = Vec::new();
+        let mut ipv6_reach = Vec::new();
+        let mut mt_ipv6_reach = Vec::new();
+        let mut locators = Vec::new();
+        let mut unknown = Vec::new();
+
+        for tlv in &self.tlvs {
+            match tlv {
+                IsisTlv::Ipv4IfAddr(v) => ipv4_addrs.push(v.addr.to_string()),
+                IsisTlv::Ipv6IfAddr(v) => ipv6_addrs.push(v.addr.to_string()),
+                IsisTlv::TeRouterId(v) => ipv4_te_routerid = Some(v.router_id.to_string()),
+                IsisTlv::Ipv6TeRouterId(v) => ipv6_te_routerid = Some(v.router_id.to_string()),
+                IsisTlv::ProtoSupported(v) => protocols.extend(v.nlpids.iter().copied()),
+                IsisTlv::Hostname(v) => hostname = Some(v.hostname.clone()),
+                IsisTlv::RouterCap(v) => caps.push(router_capability(v)),
+                IsisTlv::ExtIsReach(v) => is_reach.extend(v.entries.iter()),
+                IsisTlv::ExtIpReach(v) => {
+                    ipv4_reach.extend(v.entries.iter().map(|entry| ipv4_prefix(entry, None)))
+                }
+                IsisTlv::MtIpReach(v) => mt_ipv4_reach.extend(
+                    v.entries
+                        .iter()
+                        .map(|entry| ipv4_prefix(entry, Some(v.mt.id()))),
+                ),
+                IsisTlv::Ipv6Reach(v) => {
+                    ipv6_reach.extend(v.entries.iter().map(|entry| ipv6_prefix(entry, None)))
+                }
+                IsisTlv::MtIpv6Reach(v) => mt_ipv6_reach.extend(
+                    v.entries
+                        .iter()
+                        .map(|entry| ipv6_prefix(entry, Some(v.mt.id()))),
+                ),
+                IsisTlv::Srv6(v) => locators.extend(
+                    v.locators
+                        .iter()
+                        .map(|locator| srv6_locator(v.flags.mt_id(), locator)),
+                ),
+                IsisTlv::Unknown(v) => unknown.push(json!({
+                    "type": u8::from(v.typ),
+                    "length": v.len,
+                    "value": hex_string(&v.values),
+                })),
+                _ => {
+                    let mut buf = BytesMut::new();
+                    tlv.emit(&mut buf);
+                    unknown.push(unknown_tlv(&buf));
+                }
+            }
+        }
+
+        let mut obj = Map::new();
+        obj.insert("decoded-completed".into(), json!(true));
+        obj.insert("lsp-id".into(), json!(self.lsp_id.to_string()));
+        obj.insert("checksum".into(), json!(self.checksum));
+        obj.insert("remaining-lifetime".into(), json!(self.hold_time));
+        obj.insert("sequence".into(), json!(self.seq_number));
+        obj.insert(
+            "attributes".into(),
+            json!({ "lsp-flags": lsp_flags(&self.types) }),
+        );
+        if !ipv4_addrs.is_empty() {
+            obj.insert("ipv4-addresses".into(), json!(ipv4_addrs));
+        }
+        if !ipv6_addrs.is_empty() {
+            obj.insert("ipv6-addresses".into(), json!(ipv6_addrs));
+        }
+        if let Some(router_id) = ipv4_te_routerid {
+            obj.insert("ipv4-te-routerid".into(), json!(router_id));
+        }
+        if let Some(router_id) = ipv6_te_routerid {
+            obj.insert("ipv6-te-routerid".into(), json!(router_id));
+        }
+        if !protocols.is_empty() {
+            obj.insert("protocol-supported".into(), json!(protocols));
+        }
+        if let Some(hostname) = hostname {
+            obj.insert("dynamic-hostname".into(), json!(hostname));
+        }
+        insert_list(&mut obj, "router-capabilities", "router-capability", caps);
+        insert_list(
+            &mut obj,
+            "extended-is-neighbor",
+            "neighbor",
+            neighbors(is_reach.into_iter()),
+        );
+        insert_list(
+            &mut obj,
+            "extended-ipv4-reachability",
+            "prefixes",
+            ipv4_reach,
+        );
+        insert_list(
+            &mut obj,
+            "mt-extended-ipv4-reachability",
+            "prefixes",
+            mt_ipv4_reach,
+        );
+        insert_list(&mut obj, "mt-ipv6-reachability", "prefixes", mt_ipv6_reach);
+        insert_list(&mut obj, "ipv6-reachability", "prefixes", ipv6_reach);
+        insert_list(
+            &mut obj,
+            &augment(SRV6, "srv6-locator"),
+            "locators",
+            locators,
+        );
+        insert_list(&mut obj, "unknown-tlvs", "unknown-tlv", unknown);
+        Value::Object(obj)
+    }
+}
+
+impl IsisLsdb {
+    /// Entry of the RFC 9130 `ietf-isis` database `levels` list.
+    pub fn yang(&self, level: u8) -> Value {
+        json!({
+            "level": level,
+            "lsp": self.iter().map(IsisLsp::yang).collect::<Vec<_>>(),
+        })
+    }
+}
+
+/// RFC 9130 `ietf-isis` database container with one entry per level, ready
+/// to be sent as telemetry.
+pub fn yang_database(levels: &[(u8, &IsisLsdb)]) -> Value {
+    let levels: Vec<Value> = levels
+        .iter()
+        .map(|(level, lsdb)| lsdb.yang(*level))
+        .collect();
+    json!({ "ietf-isis:database": { "levels": levels } })
+}
@@ -0,0 +1,178 @@
+mod common;
+
+use hex_literal::hex;
+use isis_packet::prefix::{
+    IsisSub2SidStructure, IsisSubSrv6EndSid, IsisSubTlv, IsisTlvSrv6, Srv6Locator,
+};
+use isis_packet::*;
+
+use common::{L1_LSP, parse_lsp};
+
+fn lsp() -> IsisLsp {
+    parse_lsp(L1_LSP)
+}
+
+#[test]
+pub fn bgp_ls_node() {
+    let nlris = lsp().bgp_ls(1, 0);
+    let node = &nlris[0];
+    assert_eq!(node.typ, IsisBgpLsNlriType::Node);
+    assert_eq!(
+        node.nlri[..],
+        hex!(
+            "00 01 00 17 01 00 00 00 00 00 00 00 00
+             01 00 00 0a 02 03 00 06 00 00 00 00 00 01"
+        )
+    );
+    assert_eq!(
+        node.attr[..],
+        hex!(
+            "04 03 00 03 49 00 00
+             04 02 00 07 75 62 75 6e 74 75 31
+             04 04 00 04 01 01 01 01
+             04 0a 00 0c c0 00 00 1f 40 04 89 00 03 00 3e 80
+             04 0b 00 01 00
+             04 0c 00 0c 00 00 00 03 e8 04 89 00 03 00 3a 98
+             01 0a 00 02 01 08"
+        )
+    );
+}
+
+#[test]
+pub fn bgp_ls_link() {
+    let nlris = lsp().bgp_ls(1, 0);
+    let links: Vec<_> = nlris
+        .iter()
+        .filter(|nlri| nlri.typ == IsisBgpLsNlriType::Link)
+        .collect();
+    assert_eq!(links.len(), 2);
+    assert_eq!(
+        links[0].nlri[..],
+        hex!(
+            "00 02 00 36 01 00 00 00 00 00 00 00 00
+             01 00 00 0a 02 03 00 06 00 00 00 00 00 01
+             01 01 00 0b 02 03 00 07 00 00 00 00 00 01 03
+             01 03 00 04 0b 00 00 01
+             01 04 00 04 0b 00 00 02"
+        )
+    );
+    assert_eq!(
+        links[0].attr[..],
+        hex!(
+            "04 47 00 03 00 00 0a
+             04 4c 00 0d 30 00 00 00 00 00 00 00 00 02 00 3a 98"
+        )
+    );
+}
+
+#[test]
+pub fn bgp_ls_prefix() {
+    let nlris = lsp().bgp_ls(1, 0);
+    let prefixes: Vec<_> = nlris
+        .iter()
+        .filter(|nlri| nlri.typ == IsisBgpLsNlriType::Ipv4Prefix)
+        .collect();
+    assert_eq!(prefixes.len(), 3);
+    assert_eq!(
+        prefixes[0].nlri[..],
+        hex!(
+            "00 03 00 20 01 00 00 00 00 00 00 00 00
+             01 00 00 0a 02 03 00 06 00 00 00 00 00 01
+             01 09 00 05 20 01 01 01 01"
+        )
+    );
+    assert_eq!(
+        prefixes[0].attr[..],
+        hex!(
+            "04 83 00 04 00 00 00 0a
+             04 86 00 08 00 00 00 00 00 00 00 64"
+        )
+    );
+    // Only the significant octets of 11.0.0.0/24.
+    assert_eq!(prefixes[1].nlri[27..], hex!("01 09 00 04 18 0b 00 00"));
+}
+
+#[test]
+pub fn bgp_ls_mt_prefix() {
+    // MT IPv6 Reachability TLV of topology 2, IPv6 unicast.
+    let tlv = hex!("ED 10 00 02 00 00 00 0A 00 40 20 01 0D B8 00 00 00 01");
+    let (_, tlv) = IsisTlv::parse_tlv(&tlv).unwrap();
+    let mut lsp = lsp();
+    lsp.tlvs = vec![tlv];
+
+    let nlris = lsp.bgp_ls(2, 0);
+    let prefixes: Vec<_> = nlris
+        .iter()
+        .filter(|nlri| nlri.typ == IsisBgpLsNlriType::Ipv6Prefix)
+        .collect();
+    assert_eq!(prefixes.len(), 1);
+    assert_eq!(
+        prefixes[0].nlri[..],
+        hex!(
+            "00 04 00 2a 02 00 00 00 00 00 00 00 00
+             01 00 00 0a 02 03 00 06 00 00 00 00 00 01
+             01 07 00 02 00 02
+             01 09 00 09 40 20 01 0d b8 00 00 00 01"
+        )
+    );
+}
+
+#[test]
+pub fn bgp_ls_srv6() {
+    let end_sid = IsisSubSrv6EndSid {
+        flags: 0,
+        behavior: Behavior::End,
+        sid: "2001:db8:1::".parse().unwrap(),
+        sub2s: vec![IsisSub2Tlv::SidStructure(IsisSub2SidStructure {
+            lb_len: 32,
+            ln_len: 16,
+            fun_len: 16,
+            arg_len: 0,
+        })],
+    };
+    let locator = Srv6Locator {
+        metric: 10,
+        flags: 0,
+        algo: Algo::Spf,
+        locator: "2001:db8:1::/48".parse().unwrap(),
+        subs: vec![IsisSubTlv::Srv6EndSid(end_sid)],
+    };
+    let mut lsp = lsp();
+    lsp.tlvs = vec![IsisTlv::Srv6(IsisTlvSrv6 {
+        locators: vec![locator],
+        ..Default::default()
+    })];
+
+    let nlris = lsp.bgp_ls(2, 0);
+    assert_eq!(nlris.len(), 3);
+    assert_eq!(nlris[1].typ, IsisBgpLsNlriType::Ipv6Prefix);
+    assert_eq!(
+        nlris[1].nlri[..],
+        hex!(
+            "00 04 00 22 02 00 00 00 00 00 00 00 00
+             01 00 00 0a 02 03 00 06 00 00 00 00 00 01
+             01 09 00 07 30 20 01 0d b8 00 01"
+        )
+    );
+    assert_eq!(
+        nlris[1].attr[..],
+        hex!("04 8a 00 08 00 00 00 00 00 00 00 0a")
+    );
+
+    assert_eq!(nlris[2].typ, IsisBgpLsNlriType::Srv6Sid);
+    assert_eq!(
+        nlris[2].nlri[..],
+        hex!(
+            "00 06 00 2b 02 00 00 00 00 00 00 00 00
+             01 00 00 0a 02 03 00 06 00 00 00 00 00 01
+             02 06 00 10 20 01 0d b8 00 01 00 00 00 00 00 00 00 00 00 00"
+        )
+    );
+    assert_eq!(
+        nlris[2].attr[..],
+        hex!(
+            "04 e2 00 04 00 01 00 00
+             04 e4 00 04 20 10 10 00"
+        )
+    );
+}
@@ -0,0 +1,102 @@
+use bytes::BytesMut;
+use hex_literal::hex;
+use isis_packet::*;
+
+// LSP of `len` bytes on the wire.
+fn lsp_packet(fragment: u8, buffer_size: Option<u16>, len: usize) -> IsisPacket {
+    let mut tlvs = Vec::new();
+    if let Some(size) = buffer_size {
+        tlvs.push(IsisTlvLspBufferSize { size }.into());
+    }
+    let lsp = |tlvs: Vec<IsisTlv>| IsisLsp {
+        pdu_len: 0,
+        hold_time: 1200,
+        lsp_id: IsisLspId::new(
+            IsisSysId {
+                id: [0, 0, 0, 0, 0, 1],
+            },
+            0,
+            fragment,
+        ),
+        seq_number: 1,
+        checksum: 0,
+        types: IsisLspTypes::new().with_is_bits(3),
+        tlvs,
+    };
+    let mut buf = BytesMut::new();
+    IsisPacket::from(IsisType::L2Lsp, IsisPdu::L2Lsp(lsp(tlvs.clone()))).emit(&mut buf);
+    let mut remaining = len - buf.len();
+    while remaining > 0 {
+        let n = (remaining - 2).min(255);
+        tlvs.push(IsisTlv::Padding(IsisTlvPadding {
+            padding: vec![0; n],
+        }));
+        remaining -= n + 2;
+    }
+    let mut buf = BytesMut::new();
+    IsisPacket::from(IsisType::L2Lsp, IsisPdu::L2Lsp(lsp(tlvs))).emit(&mut buf);
+    assert_eq!(buf.len(), len);
+    parse(&buf).unwrap().1
+}
+
+#[test]
+pub fn lsp_buffer_size_parse_emit() {
+    let bytes = hex!("0e 02 05 d4");
+    let (rest, tlv) = IsisTlv::parse_tlv(&bytes).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(
+        tlv,
+        IsisTlv::LspBufferSize(IsisTlvLspBufferSize { size: 1492 })
+    );
+    let mut buf = BytesMut::new();
+    tlv.emit(&mut buf);
+    assert_eq!(&buf[..], &bytes[..]);
+}
+
+#[test]
+pub fn lsp_buffer_size_check() {
+    let packet = lsp_packet(0, Some(1492), 200);
+    let IsisPdu::L2Lsp(lsp) = &packet.pdu else {
+        panic!("not an LSP");
+    };
+    assert_eq!(lsp.lsp_buffer_size(), Some(1492));
+    assert_eq!(packet.check_lsp_size(None, 1497), Ok(()));
+    // Source allowed to grow LSPs beyond the local MTU.
+    assert_eq!(
+        packet.check_lsp_size(None, 1400),
+        Err(IsisLspSizeError::BufferSizeExceedsMtu {
+            buffer_size: 1492,
+            mtu: 1400
+        })
+    );
+
+    let packet = lsp_packet(0, Some(256), 300);
+    assert_eq!(
+        packet.check_lsp_size(None, 1497),
+        Err(IsisLspSizeError::ExceedsBufferSize {
+            len: 300,
+            buffer_size: 256
+        })
+    );
+
+    // Fragments other than 0 use the buffer size of fragment 0.
+    let packet = lsp_packet(1, None, 300);
+    assert_eq!(packet.check_lsp_size(None, 1497), Ok(()));
+    assert!(packet.check_lsp_size(Some(256), 1497).is_err());
+    assert_eq!(
+        packet.check_lsp_size(None, 280),
+        Err(IsisLspSizeError::ExceedsMtu { len: 300, mtu: 280 })
+    );
+}
+
+#[test]
+pub fn lsp_buffer_size_other_pdu() {
+    let psnp = IsisPsnp {
+        pdu_len: 0,
+        source_id: IsisSysId::default(),
+        source_id_curcuit: 0,
+        tlvs: vec![],
+    };
+    let packet = IsisPacket::from(IsisType::L2Psnp, IsisPdu::L2Psnp(psnp));
+    assert_eq!(packet.check_lsp_size(Some(1), 1), Ok(()));
+}
@@ -0,0 +1,237 @@
+use hex_literal::hex;
+use isis_packet::*;
+use std::time::Duration;
+
+// L1 LSP in 802.3 with LLC, source MAC 00:1c:42:e8:0c:23.
+const FRAME: &[u8] = &hex!(
+    "
+        01 80 c2 00 00 14 00 1c 42 e8 0c 23 00 9e fe fe
+        03 83 1b 01 00 12 01 00 00 00 9b 02 2b 00 00 00
+        00 00 02 00 00 00 00 00 04 c9 f3 01 81 01 cc 01
+        04 03 49 00 00 89 07 75 62 75 6e 74 75 32 f2 22
+        0a 0a 00 01 00 02 09 c0 00 1f 40 01 03 00 3e 80
+        13 01 00 16 09 00 00 03 e8 01 03 00 3a 98 17 02
+        01 08 86 04 02 02 02 02 16 18 00 00 00 00 00 01
+        04 00 00 0a 0d 20 0b 30 00 00 00 00 00 00 01 00
+        3a 98 84 04 0a 0a 00 01 87 22 00 00 00 0a 60 02
+        02 02 02 08 03 06 00 00 00 00 00 c8 00 00 00 0a
+        18 0a 00 00 00 00 00 0a 18 0a 0a 00
+        "
+);
+
+const SRC_MAC: [u8; 6] = [0x00, 0x1c, 0x42, 0xe8, 0x0c, 0x23];
+
+fn pdu() -> &'static [u8] {
+    &FRAME[17..]
+}
+
+fn pcap(linktype: u32, frames: &[(u32, u32, Vec<u8>)]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&0xa1b2c3d4u32.to_le_bytes());
+    buf.extend_from_slice(&2u16.to_le_bytes());
+    buf.extend_from_slice(&4u16.to_le_bytes());
+    buf.extend_from_slice(&[0; 8]);
+    buf.extend_from_slice(&65535u32.to_le_bytes());
+    buf.extend_from_slice(&linktype.to_le_bytes());
+    for (secs, usecs, frame) in frames {
+        buf.extend_from_slice(&secs.to_le_bytes());
+        buf.extend_from_slice(&usecs.to_le_bytes());
+        buf.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+        buf.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+        buf.extend_from_slice(frame);
+    }
+    buf
+}
+
+fn vlan() -> Vec<u8> {
+    let mut frame = FRAME[..12].to_vec();
+    frame.extend_from_slice(&[0x81, 0x00, 0x00, 0x64]);
+    frame.extend_from_slice(&FRAME[12..]);
+    frame
+}
+
+fn gre() -> Vec<u8> {
+    let mut frame = FRAME[..12].to_vec();
+    frame.extend_from_slice(&[0x08, 0x00]);
+    let total = (20 + 4 + pdu().len()) as u16;
+    frame.extend_from_slice(&[0x45, 0x00]);
+    frame.extend_from_slice(&total.to_be_bytes());
+    frame.extend_from_slice(&[0, 0, 0, 0, 64, 47, 0, 0, 10, 0, 0, 1, 10, 0, 0, 2]);
+    frame.extend_from_slice(&[0x00, 0x00, 0x00, 0xfe]);
+    frame.extend_from_slice(pdu());
+    frame
+}
+
+fn sll() -> Vec<u8> {
+    let mut frame = vec![0x00, 0x00, 0x00, 0x01, 0x00, 0x06];
+    frame.extend_from_slice(&SRC_MAC);
+    frame.extend_from_slice(&[0x00, 0x00, 0x00, 0x04]);
+    frame.extend_from_slice(&FRAME[14..]);
+    frame
+}
+
+fn sll2() -> Vec<u8> {
+    let mut frame = vec![0x00, 0x04, 0x00, 0x00, 0, 0, 0, 2, 0x00, 0x01, 0x00, 0x06];
+    frame.extend_from_slice(&SRC_MAC);
+    frame.extend_from_slice(&[0x00, 0x00]);
+    frame.extend_from_slice(&FRAME[14..]);
+    frame
+}
+
+#[test]
+pub fn capture_pcap_ethernet() {
+    let arp = hex!("ff ff ff ff ff ff 00 1c 42 e8 0c 23 08 06 00 01").to_vec();
+    let file = pcap(
+        LINKTYPE_ETHERNET as u32,
+        &[
+            (100, 250_000, FRAME.to_vec()),
+            (101, 0, arp),
+            (102, 1, vlan()),
+            (103, 0, gre()),
+        ],
+    );
+    let packets: Vec<IsisCapturePacket> = IsisCaptureReader::new(&file[..])
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(packets.len(), 3);
+    assert_eq!(packets[0].timestamp, Duration::new(100, 250_000_000));
+    assert_eq!(packets[1].timestamp, Duration::new(102, 1_000));
+    assert_eq!(packets[2].timestamp, Duration::from_secs(103));
+    for packet in &packets {
+        assert_eq!(packet.src_mac, Some(SRC_MAC));
+        assert_eq!(packet.packet.pdu_type, IsisType::L1Lsp);
+    }
+}
+
+#[test]
+pub fn capture_pcap_cooked() {
+    for (linktype, frame) in [(LINKTYPE_LINUX_SLL, sll()), (LINKTYPE_LINUX_SLL2, sll2())] {
+        let file = pcap(linktype as u32, &[(1, 0, frame)]);
+        let packets: Vec<IsisCapturePacket> = IsisCaptureReader::new(&file[..])
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(packets.len(), 1);
+        assert_eq!(packets[0].src_mac, Some(SRC_MAC));
+        assert_eq!(packets[0].packet.pdu_type, IsisType::L1Lsp);
+    }
+}
+
+fn pcapng_block(typ: u32, body: &[u8]) -> Vec<u8> {
+    let len = (12 + body.len().next_multiple_of(4)) as u32;
+    let mut block = Vec::new();
+    block.extend_from_slice(&typ.to_be_bytes());
+    block.extend_from_slice(&len.to_be_bytes());
+    block.extend_from_slice(body);
+    block.resize(len as usize - 4, 0);
+    block.extend_from_slice(&len.to_be_bytes());
+    block
+}
+
+#[test]
+pub fn capture_pcapng() {
+    let mut shb = Vec::new();
+    shb.extend_from_slice(&0x1a2b3c4du32.to_be_bytes());
+    shb.extend_from_slice(&[0, 1, 0, 0]);
+    shb.extend_from_slice(&[0xff; 8]);
+
+    // Ethernet with nanosecond timestamps.
+    let mut idb = vec![0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff];
+    idb.extend_from_slice(&[0x00, 0x09, 0x00, 0x01, 0x09, 0, 0, 0]);
+    idb.extend_from_slice(&[0, 0, 0, 0]);
+
+    let ts: u64 = 1_700_000_000_123_456_789;
+    let mut epb = Vec::new();
+    epb.extend_from_slice(&0u32.to_be_bytes());
+    epb.extend_from_slice(&((ts >> 32) as u32).to_be_bytes());
+    epb.extend_from_slice(&(ts as u32).to_be_bytes());
+    epb.extend_from_slice(&(FRAME.len() as u32).to_be_bytes());
+    epb.extend_from_slice(&(FRAME.len() as u32).to_be_bytes());
+    epb.extend_from_slice(FRAME);
+
+    let mut file = pcapng_block(0x0a0d0d0a, &shb);
+    file.extend(pcapng_block(1, &idb));
+    file.extend(pcapng_block(6, &epb));
+
+    let mut reader = IsisCaptureReader::new(&file[..]).unwrap();
+    let packet = reader.next().unwrap().unwrap();
+    assert_eq!(packet.timestamp, Duration::new(1_700_000_000, 123_456_789));
+    assert_eq!(packet.src_mac, Some(SRC_MAC));
+    let IsisPdu::L1Lsp(lsp) = packet.packet.pdu else {
+        panic!("not L1 LSP");
+    };
+    assert_eq!(lsp.seq_number, 0x04);
+    assert!(reader.next().is_none());
+}
+
+#[test]
+pub fn capture_pcapng_tsresol() {
+    let mut shb = Vec::new();
+    shb.extend_from_slice(&0x1a2b3c4du32.to_be_bytes());
+    shb.extend_from_slice(&[0, 1, 0, 0]);
+    shb.extend_from_slice(&[0xff; 8]);
+
+    // Resolutions of 10^-127 and 2^-127 seconds do not fit the timestamps.
+    for tsresol in [0x7f, 0xff] {
+        let mut idb = vec![0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff];
+        idb.extend_from_slice(&[0x00, 0x09, 0x00, 0x01, tsresol, 0, 0, 0]);
+        idb.extend_from_slice(&[0, 0, 0, 0]);
+        let mut file = pcapng_block(0x0a0d0d0a, &shb);
+        file.extend(pcapng_block(1, &idb));
+
+        let mut reader = IsisCaptureReader::new(&file[..]).unwrap();
+        assert!(matches!(
+            reader.next(),
+            Some(Err(IsisCaptureError::InvalidFormat { .. }))
+        ));
+    }
+}
+
+#[test]
+pub fn capture_invalid() {
+    assert!(matches!(
+        IsisCaptureReader::new(&[0u8; 24][..]),
+        Err(IsisCaptureError::InvalidFormat { .. })
+    ));
+}
+
+#[test]
+pub fn capture_write_frame() {
+    let (_, packet) = parse(pdu()).unwrap();
+    let mut frame = bytes::BytesMut::new();
+    packet.emit_frame(SRC_MAC, &mut frame);
+    assert_eq!(hex::encode(&frame[..]), hex::encode(FRAME));
+
+    let (_, l2) = parse(pdu()).unwrap();
+    let l2 = IsisPacket::from(IsisType::L2Lsp, l2.pdu);
+    let mut frame = bytes::BytesMut::new();
+    l2.emit_frame(SRC_MAC, &mut frame);
+    assert_eq!(frame[..6], ALL_L2_ISS);
+    assert_eq!(isis_dst_mac(IsisType::P2PHello), ALL_ISS);
+}
+
+#[test]
+pub fn capture_write_read() {
+    let (_, packet) = parse(pdu()).unwrap();
+    for format in [IsisCaptureFormat::Pcap, IsisCaptureFormat::Pcapng] {
+        let mut writer = IsisCaptureWriter::new(Vec::new(), format).unwrap();
+        writer
+            .write_packet(Duration::new(10, 5), SRC_MAC, &packet)
+            .unwrap();
+        writer
+            .write_packet(Duration::new(11, 999_999_999), SRC_MAC, &packet)
+            .unwrap();
+        let file = writer.into_inner();
+
+        let packets: Vec<IsisCapturePacket> = IsisCaptureReader::new(&file[..])
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(packets.len(), 2);
+        assert_eq!(packets[0].timestamp, Duration::new(10, 5));
+        assert_eq!(packets[1].timestamp, Duration::new(11, 999_999_999));
+        assert_eq!(packets[1].src_mac, Some(SRC_MAC));
+        assert_eq!(packets[1].packet.pdu_type, IsisType::L1Lsp);
+    }
+}
@@ -0,0 +1,126 @@
+// Fixtures shared by the integration tests, each test crate using a part
+// of them.
+#![allow(dead_code)]
+
+use bytes::BytesMut;
+use hex_literal::hex;
+use isis_packet::*;
+
+// L1 LSP of ubuntu1 with SR-MPLS router capability, LAN Adj-SID and
+// Prefix-SID.
+pub const L1_LSP: &[u8] = &hex!(
+    "
+    83 1B 01 00 12 01 00 00 00 CB 04 8F 00 00 00 00
+    00 01 00 00 00 00 00 9A 0F 44 01 81 01 CC 01 04
+    03 49 00 00 89 07 75 62 75 6E 74 75 31 F2 22 AC
+    13 00 01 00 02 09 C0 00 1F 40 01 03 00 3E 80 13
+    01 00 16 09 00 00 03 E8 01 03 00 3A 98 17 02 01
+    08 86 04 01 01 01 01 16 48 00 00 00 00 00 01 03
+    00 00 0A 19 06 04 0B 00 00 01 08 04 0B 00 00 02
+    20 0B 30 00 00 00 00 00 00 02 00 3A 98 00 00 00
+    00 00 01 04 00 00 0A 19 06 04 0A 00 00 01 08 04
+    0A 00 00 03 20 0B 30 00 00 00 00 00 00 03 00 3A
+    99 84 04 AC 13 00 01 87 22 00 00 00 0A 60 01 01
+    01 01 08 03 06 00 00 00 00 00 64 00 00 00 0A 18
+    0B 00 00 00 00 00 0A 18 0A 00 00
+    "
+);
+
+// L2 LSP of ubuntu1 with SR-MPLS and SRv6, IPv4 and IPv6 reachability.
+pub const L2_LSP: &[u8] = &hex!(
+    "
+83 1b 01 00 14 01 00 00 01 9d 01 4f 00 00 00 00
+00 01 00 00 00 00 0e 7d ae a3 03 81 02 cc 8e 01
+04 03 49 00 00 89 07 75 62 75 6e 74 75 31 f2 30
+0b 00 00 01 00 02 09 c0 00 1f 40 01 03 00 3e 80
+13 01 00 16 09 00 00 03 e8 01 03 00 3a 98 17 02
+01 08 19 02 00 00 17 08 29 03 2a 03 2c 02 2d 05
+86 04 01 01 01 01 16 af 00 00 00 00 00 01 03 00
+00 0a 2b 06 04 0b 00 00 01 08 04 0b 00 00 02 0c
+10 20 11 00 00 00 00 00 00 00 00 00 00 00 00 00
+01 20 0b 30 00 00 00 00 00 00 02 00 3a 9a 00 00
+00 00 00 01 04 00 00 0a 6e 06 04 0a 00 00 01 08
+04 0a 00 00 03 0c 10 20 10 00 00 00 00 00 00 00
+00 00 00 00 00 00 01 0d 10 20 10 00 00 00 00 00
+00 00 00 00 00 00 00 00 02 20 0b 30 00 00 00 00
+00 00 03 00 3a 98 20 0b b0 00 00 00 00 00 00 03
+00 3a 99 2c 22 00 00 00 00 00 03 00 00 00 00 05
+20 01 de ad 00 01 00 00 00 01 00 00 00 00 00 00
+06 01 04 28 18 10 00 84 04 0b 00 00 01 87 22 00
+00 00 0a 60 01 01 01 01 08 03 06 00 00 00 00 00
+01 00 00 00 0a 18 0b 00 00 00 00 00 0a 18 0a 00
+00 ec 2a 00 00 00 00 00 40 20 01 de ad 00 01 00
+00 00 00 00 0a 00 40 20 11 00 00 00 00 00 00 00
+00 00 0a 00 40 20 10 00 00 00 00 00 00 1b 2e 00
+00 00 00 00 00 00 00 40 20 01 de ad 00 01 00 00
+1c 05 1a 00 00 01 20 01 de ad 00 01 00 00 00 00
+00 00 00 00 00 00 06 01 04 28 18 10 00
+"
+);
+
+pub fn sys_id(id: u8) -> IsisSysId {
+    IsisSysId {
+        id: [0, 0, 0, 0, 0, id],
+    }
+}
+
+pub fn node(id: u8) -> IsisNeighborId {
+    IsisNeighborId::from_sys_id(&sys_id(id), 0)
+}
+
+// L1/L2 LSP fragment 0 of router `id`.
+pub fn lsp(id: u8, tlvs: Vec<IsisTlv>) -> IsisLsp {
+    IsisLsp {
+        pdu_len: 0,
+        hold_time: 1200,
+        lsp_id: IsisLspId::new(sys_id(id), 0, 0),
+        seq_number: 1,
+        checksum: 0,
+        types: IsisLspTypes::new().with_is_bits(3),
+        tlvs,
+    }
+}
+
+// Extended IS Reachability to the routers of `links`, (neighbor, metric).
+pub fn is_reach(links: &[(u8, u32)]) -> IsisTlv {
+    IsisTlvExtIsReach {
+        entries: links
+            .iter()
+            .map(|&(to, metric)| IsisTlvExtIsReachEntry {
+                neighbor_id: node(to),
+                metric,
+                subs: vec![],
+            })
+            .collect(),
+    }
+    .into()
+}
+
+// LAN hello from `source` with `dis` as DIS.
+pub fn lan_hello(source: u8, dis: u8, tlvs: Vec<IsisTlv>) -> IsisHello {
+    IsisHello {
+        circuit_type: IsLevel::L1,
+        source_id: sys_id(source),
+        hold_time: 30,
+        pdu_len: 0,
+        priority: 64,
+        lan_id: IsisNeighborId::from_sys_id(&sys_id(dis), 1),
+        tlvs,
+    }
+}
+
+pub fn parse_lsp(input: &[u8]) -> IsisLsp {
+    let (_, packet) = parse(input).unwrap();
+    match packet.pdu {
+        IsisPdu::L1Lsp(lsp) | IsisPdu::L2Lsp(lsp) => lsp,
+        pdu => panic!("not an LSP: {pdu:?}"),
+    }
+}
+
+// Emit and parse back to go through the wire format.
+pub fn round_trip(packet: IsisPacket) -> IsisPacket {
+    let mut buf = BytesMut::new();
+    packet.emit(&mut buf);
+    let (_, packet) = parse(&buf).unwrap();
+    packet
+}
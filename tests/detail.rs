@@ -0,0 +1,110 @@
+mod common;
+
+use hex_literal::hex;
+use isis_packet::*;
+
+use common::{L2_LSP, parse_lsp, sys_id};
+
+#[test]
+pub fn lsp_detail() {
+    let lsp = parse_lsp(L2_LSP);
+    let hostnames: IsisHostnameMap = {
+        let mut map = IsisHostnameMap::new();
+        map.insert_lsp(&lsp);
+        map.insert(sys_id(2), "r2".into());
+        map
+    };
+    let expected = "\
+ubuntu1.00-00             413   0x00000e7d  0xaea3     335    0/0/0
+  Protocols Supported: IPv4, IPv6
+  Area Address: 49.0000
+  Hostname: ubuntu1
+  Router Capability: 11.0.0.1 , D:0, S:0
+    Segment Routing: I:1 V:1, Global Block Base: 16000 Range: 8000
+    SR Algorithm:
+      0: SPF
+    SR Local Block Base: 15000 Range: 1000
+    Node Maximum SID Depth: 8
+    SRv6: O:0
+    Node Maximum SID Depth: 3
+  TE Router ID: 1.1.1.1
+  Extended Reachability: ubuntu1.03 (Metric: 10)
+    Local Interface IP Address(es): 11.0.0.1
+    Remote Interface IP Address(es): 11.0.0.2
+    Local Interface IPv6 Address(es): 2011::1
+    Lan-Adjacency-SID: 15002, Weight: 0, Flags: F:0 B:0, V:1, L:1, S:0, P:0
+      Neighbor-ID: r2
+  Extended Reachability: ubuntu1.04 (Metric: 10)
+    Local Interface IP Address(es): 10.0.0.1
+    Remote Interface IP Address(es): 10.0.0.3
+    Local Interface IPv6 Address(es): 2010::1
+    Remote Interface IPv6 Address(es): 2010::2
+    Lan-Adjacency-SID: 15000, Weight: 0, Flags: F:0 B:0, V:1, L:1, S:0, P:0
+      Neighbor-ID: 0000.0000.0003
+    Lan-Adjacency-SID: 15001, Weight: 0, Flags: F:1 B:0, V:1, L:1, S:0, P:0
+      Neighbor-ID: 0000.0000.0003
+    SRv6 Lan End.X SID: 2001:dead:1:0:1::, Algorithm: 0, Weight: 0, Endpoint Behavior: End.X, Flags: B:0, S:0, P:0 Neighbor-ID: 0000.0000.0003
+      SRv6 SID Structure Locator Block length: 40, Locator Node length: 24, Function length: 16, Argument length: 0
+  IPv4 Interface Address: 11.0.0.1
+  Extended IP Reachability: 1.1.1.1/32 (Metric: 10)
+    Subtlvs:
+      SR Prefix-SID Index: 1, Algorithm: 0, Flags: PHP
+  Extended IP Reachability: 11.0.0.0/24 (Metric: 10)
+  Extended IP Reachability: 10.0.0.0/24 (Metric: 10)
+  IPv6 Reachability: 2001:dead:1::/64 (Metric: 0)
+  IPv6 Reachability: 2011::/64 (Metric: 10)
+  IPv6 Reachability: 2010::/64 (Metric: 10)
+  SRv6 Locator: 2001:dead:1::/64 (Metric: 0) SPF
+    SRv6 End SID: 2001:dead:1::, Endpoint Behavior: End
+      SRv6 SID Structure Locator Block length: 40, Locator Node length: 24, Function length: 16, Argument length: 0
+";
+    assert_eq!(lsp.detail(Some(&hostnames)).to_string(), expected);
+
+    // Without hostnames the system IDs are kept.
+    let detail = lsp.detail(None).to_string();
+    assert!(detail.starts_with("0000.0000.0001.00-00      413   0x00000e7d"));
+    assert!(detail.contains("Extended Reachability: 0000.0000.0001.03 (Metric: 10)"));
+}
+
+#[test]
+pub fn lsp_detail_mt() {
+    // MT IPv6 Reachability TLV of topology 2, IPv6 unicast.
+    let tlv = hex!("ED 10 00 02 00 00 00 0A 00 40 20 01 0D B8 00 00 00 01");
+    let (_, tlv) = IsisTlv::parse_tlv(&tlv).unwrap();
+    let mut lsp = parse_lsp(L2_LSP);
+    lsp.tlvs = vec![tlv];
+    let detail = lsp.detail(None).to_string();
+    assert_eq!(
+        detail.lines().nth(1),
+        Some("  MT IPv6 Reachability: 2001:db8:0:1::/64 (Metric: 10) ipv6-unicast")
+    );
+}
+
+#[test]
+pub fn lsdb_detail() {
+    let lsp1 = parse_lsp(L2_LSP);
+    let mut lsp2 = lsp1.clone();
+    lsp2.lsp_id = IsisLspId::new(sys_id(2), 0, 0);
+    lsp2.types = lsp2.types.with_ol_bits(true).with_att_bits(1);
+    lsp2.tlvs.clear();
+    let lsdb: IsisLsdb = [lsp1, lsp2].into_iter().collect();
+    let hostnames = IsisHostnameMap::from(&lsdb);
+    let own = sys_id(1);
+    let detail = IsisLsdbDetail {
+        own: Some(&own),
+        ..lsdb.detail(2, Some(&hostnames))
+    }
+    .to_string();
+    let lines: Vec<&str> = detail.lines().collect();
+    assert_eq!(lines[0], "IS-IS Level-2 link-state database:");
+    assert_eq!(
+        lines[1],
+        "LSP ID                  PduLen  SeqNumber   Chksum  Holdtime  ATT/P/OL"
+    );
+    assert_eq!(
+        lines[2],
+        "ubuntu1.00-00        *    413   0x00000e7d  0xaea3     335    0/0/0"
+    );
+    assert!(lines.contains(&"0000.0000.0002.00-00      413   0x00000e7d  0xaea3     335    1/0/1"));
+    assert_eq!(lines.last(), Some(&"    2 LSPs"));
+}
@@ -0,0 +1,79 @@
+use bytes::BytesMut;
+use isis_packet::*;
+
+fn sys_id(last: u8) -> IsisSysId {
+    IsisSysId {
+        id: [0, 0, 0, 0, 0, last],
+    }
+}
+
+fn adj(last: u8, priority: u8) -> IsisLanAdj {
+    IsisLanAdj {
+        sys_id: sys_id(last),
+        snpa: [0, 0x1c, 0x42, 0, 0, last],
+        priority,
+        lan_id: IsisNeighborId::from_sys_id(&sys_id(last), 1),
+    }
+}
+
+#[test]
+pub fn dis_election() {
+    let mut circuit = IsisLanCircuit::new(IsLevel::L1, 2, sys_id(2), [0, 0x1c, 0x42, 0, 0, 2], 64);
+
+    // No adjacency, no DIS.
+    assert_eq!(circuit.update(), IsisDisEvent::NoChange);
+    assert!(!circuit.is_dis());
+
+    // Same priority, highest SNPA wins.
+    circuit.adj_up(adj(1, 64));
+    let IsisDisEvent::Elected(dis) = circuit.update() else {
+        panic!("DIS must be elected");
+    };
+    assert!(dis.is_self);
+    assert_eq!(circuit.lan_id(), IsisNeighborId::from_sys_id(&sys_id(2), 2));
+
+    // Higher priority takes over and its LAN ID is used.
+    circuit.adj_up(adj(3, 100));
+    let IsisDisEvent::Changed { old, new } = circuit.update() else {
+        panic!("DIS must change");
+    };
+    assert_eq!(old.sys_id, sys_id(2));
+    assert_eq!(new.sys_id, sys_id(3));
+    assert_eq!(circuit.lan_id(), IsisNeighborId::from_sys_id(&sys_id(3), 1));
+    assert!(circuit.pseudonode_lsp(1, 1200).is_none());
+
+    // And hands it back when it goes away.
+    circuit.adj_down(&sys_id(3));
+    assert!(matches!(circuit.update(), IsisDisEvent::Changed { .. }));
+    assert!(circuit.is_dis());
+    assert_eq!(circuit.update(), IsisDisEvent::NoChange);
+}
+
+#[test]
+pub fn dis_pseudonode_lsp() {
+    let mut circuit = IsisLanCircuit::new(IsLevel::L2, 5, sys_id(9), [0, 0x1c, 0x42, 0, 0, 9], 100);
+    for last in 1..=30 {
+        circuit.adj_up(adj(last, 64));
+    }
+    circuit.update();
+
+    let lsp = circuit.pseudonode_lsp(1, 1200).unwrap();
+    assert_eq!(lsp.lsp_id, IsisLspId::new(sys_id(9), 5, 0));
+
+    let neighbors: Vec<&IsisTlvExtIsReachEntry> = lsp
+        .tlvs
+        .iter()
+        .filter_map(|tlv| match tlv {
+            IsisTlv::ExtIsReach(tlv) => Some(tlv.entries.iter()),
+            _ => None,
+        })
+        .flatten()
+        .collect();
+    assert_eq!(neighbors.len(), 31);
+    assert!(neighbors.iter().all(|entry| entry.metric == 0));
+
+    let packet = IsisPacket::from(IsisType::L2Lsp, IsisPdu::L2Lsp(lsp));
+    let mut buf = BytesMut::new();
+    packet.emit(&mut buf);
+    assert!(parse(&buf).is_ok());
+}
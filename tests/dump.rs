@@ -0,0 +1,76 @@
+use std::process::Command;
+
+// L1 LSP of ubuntu2 as an Ethernet frame.
+const FRAME: &str = "01 80 c2 00 00 14 00 1c 42 e8 0c 23 00 9e fe fe 03 83 1b 01 00 12 01 00 00 00 9b 02 2b 00 00 00 00 00 02 00 00 00 00 00 04 c9 f3 01 81 01 cc 01 04 03 49 00 00 89 07 75 62 75 6e 74 75 32 f2 22 0a 0a 00 01 00 02 09 c0 00 1f 40 01 03 00 3e 80 13 01 00 16 09 00 00 03 e8 01 03 00 3a 98 17 02 01 08 86 04 02 02 02 02 16 18 00 00 00 00 00 01 04 00 00 0a 0d 20 0b 30 00 00 00 00 00 00 01 00 3a 98 84 04 0a 0a 00 01 87 22 00 00 00 0a 60 02 02 02 02 08 03 06 00 00 00 00 00 c8 00 00 00 0a 18 0a 00 00 00 00 00 0a 18 0a 0a 00";
+
+fn dump(args: &[&str]) -> (bool, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_isis-dump"))
+        .args(args)
+        .output()
+        .unwrap();
+    (
+        output.status.success(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+#[test]
+pub fn dump_hex() {
+    let (ok, out) = dump(&["--hex", FRAME]);
+    assert!(ok);
+    assert!(out.contains("PDU Type: L1 LSP"));
+    assert!(out.contains("ubuntu2"));
+    assert!(out.contains("00:1c:42:e8:0c:23"));
+}
+
+#[test]
+pub fn dump_json() {
+    let (ok, out) = dump(&["--json", "--hex", FRAME]);
+    assert!(ok);
+    let json: serde_json::Value = serde_json::from_str(out.trim()).unwrap();
+    assert_eq!(json["pdu-type"], "l1-lsp");
+}
+
+#[test]
+pub fn dump_detail() {
+    // LSP of ubuntu1, the DIS of the LAN ubuntu2 is attached to, after the
+    // LSP of ubuntu2.
+    let lsp = "83 1b 01 00 12 01 00 00 00 2d 04 8f 00 00 00 00 00 01 00 00 00 00 00 9a 0f 44 01 81 01 cc 01 04 03 49 00 00 89 07 75 62 75 6e 74 75 31";
+    let (ok, out) = dump(&["--detail", "--hex", FRAME, "--hex", lsp]);
+    assert!(ok);
+    assert!(out.contains("ubuntu2.00-00"));
+    assert!(out.contains("Extended Reachability: ubuntu1.04 (Metric: 10)"));
+}
+
+#[test]
+pub fn dump_filter() {
+    let (ok, out) = dump(&["--type", "csnp", "--hex", FRAME]);
+    assert!(ok);
+    assert!(out.is_empty());
+
+    let (_, out) = dump(&["--lsp-id", "0000.0000.0002.00-00", "--hex", FRAME]);
+    assert!(out.contains("ubuntu2"));
+
+    let (_, out) = dump(&["--sys-id", "0000.0000.0001", "--hex", FRAME]);
+    assert!(out.is_empty());
+
+    let (_, out) = dump(&["--tlv", "dynamic-hostname", "--hex", FRAME]);
+    assert!(out.contains("ubuntu2"));
+
+    let (_, out) = dump(&["--tlv", "242", "--hex", FRAME]);
+    assert!(!out.is_empty());
+}
+
+#[test]
+pub fn dump_verify() {
+    let (ok, out) = dump(&["--verify", "--hex", FRAME]);
+    assert!(ok, "{out}");
+    assert!(out.ends_with("L1 LSP OK\n"));
+
+    // Corrupt a byte covered by the checksum.
+    let mut bad = FRAME.to_string();
+    bad.replace_range(bad.len() - 5.., "ff 00");
+    let (ok, out) = dump(&["--verify", "--hex", &bad]);
+    assert!(!ok);
+    assert!(out.contains("invalid LSP checksum"));
+}
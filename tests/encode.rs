@@ -0,0 +1,68 @@
+use isis_packet::*;
+
+const LSP_YAML: &str = r#"
+pdu:
+  l1-lsp:
+    hold_time: 1200
+    lsp_id: 0000.0000.0001.00-00
+    seq_number: 5
+    types: 1
+    tlvs:
+      - hostname: { hostname: r1 }
+      - ext-ip-reach:
+          entries:
+            - metric: 10
+              prefix: 10.0.0.0/24
+"#;
+
+#[test]
+pub fn encode_yaml_lsp() {
+    let buf = encode_yaml(LSP_YAML).unwrap();
+    assert!(is_valid_checksum(&buf));
+    assert_eq!(u16::from_be_bytes([buf[8], buf[9]]) as usize, buf.len());
+
+    let (_, packet) = parse(&buf).unwrap();
+    let IsisPdu::L1Lsp(lsp) = packet.pdu else {
+        panic!("not L1 LSP");
+    };
+    assert_eq!(lsp.seq_number, 5);
+    assert_eq!(lsp.hostname_tlv().unwrap().hostname, "r1");
+    let IsisTlv::ExtIpReach(reach) = &lsp.tlvs[1] else {
+        panic!("not Extended IP Reachability");
+    };
+    assert_eq!(reach.entries[0].flags.prefixlen(), 24);
+    assert_eq!(reach.entries[0].prefix.to_string(), "10.0.0.0/24");
+}
+
+#[test]
+pub fn encode_json_override() {
+    let json = r#"{
+        "pdu": {"l2-psnp": {"source_id": "0000.0000.0002", "source_id_curcuit": 0, "tlvs": []}},
+        "pdu_len": 100,
+        "max_area_addr": 3
+    }"#;
+    let buf = encode_json(json).unwrap();
+    assert_eq!(buf.len(), 17);
+    assert_eq!(buf[4], u8::from(IsisType::L2Psnp));
+    assert_eq!(buf[7], 3);
+    assert_eq!(u16::from_be_bytes([buf[8], buf[9]]), 100);
+
+    // Explicit checksum is kept as is.
+    let yaml = format!("{LSP_YAML}checksum: 0x1234\n");
+    let buf = encode_yaml(&yaml).unwrap();
+    assert_eq!(buf[24..26], [0x12, 0x34]);
+    assert!(!is_valid_checksum(&buf));
+}
+
+#[test]
+pub fn encode_error_path() {
+    let yaml = LSP_YAML.replace("prefix: 10.0.0.0/24", "prefix: 10.0.0.0/33");
+    let err = encode_yaml(&yaml).unwrap_err();
+    let IsisEncodeError::Schema { path, .. } = &err else {
+        panic!("not a schema error");
+    };
+    assert_eq!(path, "pdu.l1-lsp.tlvs[1].ext-ip-reach.entries[0].prefix");
+
+    let err = encode_json(r#"{"pdu": {"l1-lsp": {}}, "bogus": 1}"#).unwrap_err();
+    assert!(matches!(err, IsisEncodeError::Schema { .. }));
+}
@@ -0,0 +1,275 @@
+mod common;
+
+use std::collections::BTreeSet;
+
+use bytes::BytesMut;
+use hex_literal::hex;
+use isis_packet::neigh::IsisSubTlv as NeighSubTlv;
+use isis_packet::prefix::{IsisSubTlv as PrefixSubTlv, Srv6Locator};
+use isis_packet::*;
+
+use common::{lsp, node, sys_id};
+
+const RED: u32 = 0x2;
+const BLUE: u32 = 0x1;
+
+fn fad(algo: u8, metric_type: IsisFlexAlgoMetric, priority: u8) -> IsisSubFlexAlgoDef {
+    IsisSubFlexAlgoDef {
+        algo: Algo::FlexAlgo(algo),
+        metric_type,
+        calc_type: 0,
+        priority,
+        subs: vec![],
+    }
+}
+
+fn prefix_sid(algo: Algo, index: u32) -> PrefixSubTlv {
+    PrefixSubTlv::PrefixSid(IsisSubPrefixSid {
+        flags: PrefixSidFlags::new().with_n_flag(true),
+        algo,
+        sid: SidLabelValue::Index(index),
+    })
+}
+
+// Router with links of (neighbor, IGP metric, min delay, admin group), the
+// loopback 10.0.0.<id>/32 with Prefix-SIDs for algorithms 0 and 128 and
+// the algorithm 129 locator 2001:db8:<id>:81::/64.
+fn router(
+    id: u8,
+    algos: &[u8],
+    fads: Vec<IsisSubFlexAlgoDef>,
+    links: &[(u8, u32, u32, u32)],
+) -> IsisLsp {
+    let entries = links
+        .iter()
+        .map(|&(to, metric, delay, group)| IsisTlvExtIsReachEntry {
+            neighbor_id: node(to),
+            metric,
+            subs: vec![
+                NeighSubTlv::AdminGroup(IsisSubAdminGroup { group }),
+                NeighSubTlv::MinMaxDelay(IsisSubMinMaxDelay {
+                    anomalous: false,
+                    min: delay,
+                    max: delay,
+                }),
+            ],
+        })
+        .collect();
+    let mut subs = vec![cap::IsisSubTlv::SegmentRoutingAlgo(
+        IsisSubSegmentRoutingAlgo {
+            algo: algos.iter().map(|a| Algo::from(*a)).collect(),
+        },
+    )];
+    subs.extend(fads.into_iter().map(cap::IsisSubTlv::FlexAlgoDef));
+    lsp(
+        id,
+        vec![
+            IsisTlv::RouterCap(IsisTlvRouterCap {
+                router_id: format!("10.0.0.{id}").parse().unwrap(),
+                flags: 0.into(),
+                subs,
+            }),
+            IsisTlv::ExtIsReach(IsisTlvExtIsReach { entries }),
+            IsisTlv::ExtIpReach(IsisTlvExtIpReach {
+                entries: vec![IsisTlvExtIpReachEntry {
+                    metric: 10,
+                    flags: 0x60.into(),
+                    prefix: format!("10.0.0.{id}/32").parse().unwrap(),
+                    subs: vec![
+                        prefix_sid(Algo::Spf, id as u32),
+                        prefix_sid(Algo::FlexAlgo(128), 100 + id as u32),
+                    ],
+                }],
+            }),
+            IsisTlv::Srv6(prefix::IsisTlvSrv6 {
+                locators: vec![Srv6Locator {
+                    metric: 0,
+                    flags: 0,
+                    algo: Algo::FlexAlgo(129),
+                    locator: format!("2001:db8:{id}:81::/64").parse().unwrap(),
+                    subs: vec![],
+                }],
+                ..Default::default()
+            }),
+        ],
+    )
+}
+
+// Square 1 - 2 - 4 over red links and 1 - 3 - 4 over blue low delay links,
+// with the cheapest IGP path through router 5 outside the flex-algos.
+fn graph() -> IsisSpfGraph {
+    let include_red = IsisSubFlexAlgoDef {
+        subs: vec![IsisFadSubTlv::IncludeAll(IsisFadAdminGroup {
+            groups: vec![RED],
+        })],
+        ..fad(129, IsisFlexAlgoMetric::Igp, 100)
+    };
+    let exclude_blue = IsisSubFlexAlgoDef {
+        subs: vec![IsisFadSubTlv::ExcludeAny(IsisFadAdminGroup {
+            groups: vec![BLUE],
+        })],
+        ..fad(128, IsisFlexAlgoMetric::Igp, 100)
+    };
+    let lsdb: IsisLsdb = [
+        router(
+            1,
+            &[0, 128, 129, 130],
+            vec![
+                fad(128, IsisFlexAlgoMetric::Igp, 50),
+                fad(130, IsisFlexAlgoMetric::Unknown(9), 100),
+            ],
+            &[(2, 10, 1000, RED), (3, 20, 10, BLUE), (5, 1, 1, 0)],
+        ),
+        router(
+            2,
+            &[0, 128, 129],
+            vec![exclude_blue],
+            &[(1, 10, 1000, RED), (4, 10, 1000, RED)],
+        ),
+        router(
+            3,
+            &[0, 128, 129],
+            vec![fad(128, IsisFlexAlgoMetric::MinDelay, 100)],
+            &[(1, 20, 10, BLUE), (4, 20, 10, BLUE)],
+        ),
+        router(
+            4,
+            &[0, 128, 129],
+            vec![include_red],
+            &[(2, 10, 1000, RED), (3, 20, 10, BLUE), (5, 1, 1, 0)],
+        ),
+        router(5, &[0], vec![], &[(1, 1, 1, 0), (4, 1, 1, 0)]),
+    ]
+    .into_iter()
+    .collect();
+    IsisSpfGraph::new(&lsdb)
+}
+
+fn nexthops(ids: &[u8]) -> BTreeSet<IsisSysId> {
+    ids.iter().map(|id| sys_id(*id)).collect()
+}
+
+#[test]
+pub fn fad_election() {
+    let graph = graph();
+    // Equal priority goes to the highest system ID.
+    let (source, fad) = graph.fad(Algo::FlexAlgo(128)).unwrap();
+    assert_eq!(source, sys_id(3));
+    assert_eq!(fad.metric_type, IsisFlexAlgoMetric::MinDelay);
+    assert!(graph.fad(Algo::FlexAlgo(131)).is_none());
+    assert!(graph.participates(&node(4), Algo::FlexAlgo(128)));
+    assert!(!graph.participates(&node(5), Algo::FlexAlgo(128)));
+}
+
+#[test]
+pub fn flex_algo_spf() {
+    let graph = graph();
+    let tree = graph.spf(&node(1));
+    assert_eq!(tree.path(&node(4)).unwrap(), [node(1), node(5), node(4)]);
+
+    // Minimum delay over the blue links, router 5 does not take part.
+    let tree = graph.flex_spf(&node(1), Algo::FlexAlgo(128)).unwrap();
+    assert_eq!(tree.distance(&node(4)), Some(20));
+    assert_eq!(tree.path(&node(4)).unwrap(), [node(1), node(3), node(4)]);
+    assert_eq!(tree.distance(&node(5)), None);
+
+    // IGP metric over the red links only.
+    let tree = graph.flex_spf(&node(1), Algo::FlexAlgo(129)).unwrap();
+    assert_eq!(tree.path(&node(4)).unwrap(), [node(1), node(2), node(4)]);
+    assert_eq!(tree.distance(&node(3)), None);
+
+    // Unknown metric type and non participating root.
+    assert!(graph.flex_spf(&node(1), Algo::FlexAlgo(130)).is_none());
+    assert!(graph.flex_spf(&node(5), Algo::FlexAlgo(128)).is_none());
+}
+
+#[test]
+pub fn flex_algo_tables() {
+    let tables = graph().flex_algo_tables(&sys_id(1));
+    assert_eq!(
+        tables.iter().map(|t| t.algo).collect::<Vec<_>>(),
+        [Algo::FlexAlgo(128), Algo::FlexAlgo(129)]
+    );
+
+    let routes = &tables[0].routes;
+    assert_eq!(tables[0].fad_source, sys_id(3));
+    assert_eq!(routes.len(), 4);
+    assert_eq!(
+        routes[0],
+        IsisFlexAlgoRoute {
+            prefix: "10.0.0.1/32".parse().unwrap(),
+            metric: 10,
+            nexthops: nexthops(&[]),
+            originators: nexthops(&[1]),
+            sid: Some(SidLabelValue::Index(101)),
+        }
+    );
+    assert_eq!(routes[1].metric, 1010);
+    assert_eq!(routes[1].nexthops, nexthops(&[2]));
+    assert_eq!(routes[3].prefix, "10.0.0.4/32".parse().unwrap());
+    assert_eq!(routes[3].metric, 30);
+    assert_eq!(routes[3].nexthops, nexthops(&[3]));
+    assert_eq!(routes[3].sid, Some(SidLabelValue::Index(104)));
+
+    // SRv6 locators of the reachable routers.
+    let routes = &tables[1].routes;
+    assert_eq!(
+        routes
+            .iter()
+            .map(|r| (r.prefix.to_string(), r.metric, r.sid.clone()))
+            .collect::<Vec<_>>(),
+        [
+            ("2001:db8:1:81::/64".into(), 0, None),
+            ("2001:db8:2:81::/64".into(), 10, None),
+            ("2001:db8:4:81::/64".into(), 20, None),
+        ]
+    );
+}
+
+#[test]
+pub fn fad_parse_emit() {
+    // Algorithm 128, min delay, priority 100, Exclude-Any 0x1 and the M flag.
+    let bytes = hex!("1a 0d 80 01 00 64 01 04 00 00 00 01 04 01 80");
+    let (rest, sub) = cap::IsisSubTlv::parse_subs(&bytes).unwrap();
+    assert!(rest.is_empty());
+    let cap::IsisSubTlv::FlexAlgoDef(fad) = &sub else {
+        panic!("not a FAD: {sub:?}");
+    };
+    assert_eq!(fad.algo, Algo::FlexAlgo(128));
+    assert_eq!(fad.metric_type, IsisFlexAlgoMetric::MinDelay);
+    assert_eq!(fad.priority, 100);
+    assert_eq!(fad.exclude_any(), [1]);
+    assert!(fad.include_all().is_empty());
+    assert!(fad.m_flag());
+    let mut buf = BytesMut::new();
+    sub.emit(&mut buf);
+    assert_eq!(&buf[..], &bytes[..]);
+
+    // Admin group, extended admin group and min/max delay link attributes.
+    let bytes =
+        hex!("03 04 00 00 00 02 0e 08 00 00 00 02 00 00 00 01 22 08 80 00 00 0a 00 00 00 14");
+    let mut input = &bytes[..];
+    let mut subs = Vec::new();
+    while !input.is_empty() {
+        let (rest, sub) = NeighSubTlv::parse_subs(input).unwrap();
+        subs.push(sub);
+        input = rest;
+    }
+    assert_eq!(
+        subs,
+        [
+            NeighSubTlv::AdminGroup(IsisSubAdminGroup { group: RED }),
+            NeighSubTlv::ExtAdminGroup(IsisSubExtAdminGroup {
+                groups: vec![RED, BLUE]
+            }),
+            NeighSubTlv::MinMaxDelay(IsisSubMinMaxDelay {
+                anomalous: true,
+                min: 10,
+                max: 20
+            }),
+        ]
+    );
+    let mut buf = BytesMut::new();
+    subs.iter().for_each(|sub| sub.emit(&mut buf));
+    assert_eq!(&buf[..], &bytes[..]);
+}
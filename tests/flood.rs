@@ -0,0 +1,153 @@
+use std::collections::BTreeSet;
+
+use bytes::BytesMut;
+use hex_literal::hex;
+use isis_packet::*;
+
+fn sys_id(id: u16) -> IsisSysId {
+    let [hi, lo] = id.to_be_bytes();
+    IsisSysId {
+        id: [0, 0, 0, 0, hi, lo],
+    }
+}
+
+fn parse_emit(bytes: &[u8]) -> IsisTlv {
+    let (rest, tlv) = IsisTlv::parse_tlv(bytes).unwrap();
+    assert!(rest.is_empty());
+    let mut buf = BytesMut::new();
+    tlv.emit(&mut buf);
+    assert_eq!(&buf[..], bytes);
+    tlv
+}
+
+#[test]
+pub fn dynamic_flooding_tlvs() {
+    let tlv = parse_emit(&hex!("11 0f 00 02 80 00 00 00 00 00 01 00 00 00 00 00 02"));
+    let IsisTlv::AreaSysIds(v) = tlv else {
+        panic!("not Area System IDs: {tlv:?}");
+    };
+    assert_eq!(v.start, 2);
+    assert!(v.flags.l_flag());
+    assert_eq!(v.sys_ids, [sys_id(1), sys_id(2)]);
+
+    let tlv = parse_emit(&hex!("12 06 00 00 00 02 00 01"));
+    assert_eq!(
+        tlv,
+        IsisTlv::FloodingPath(IsisTlvFloodingPath {
+            path: vec![0, 2, 1]
+        })
+    );
+
+    let tlv = parse_emit(&hex!("13 02 81 02"));
+    let IsisTlv::FloodingRequest(v) = tlv else {
+        panic!("not a Flooding Request: {tlv:?}");
+    };
+    assert!(v.scopes[0].r_flag());
+    assert_eq!(v.scopes[0].scope(), 1);
+    assert!(!v.scopes[1].r_flag());
+
+    // Router capability with Area Leader priority 200, centralized, and
+    // dynamic flooding algorithms 0 and 128.
+    let tlv = parse_emit(&hex!("f2 0d 0a 00 00 01 00 1b 02 c8 00 1c 02 00 80"));
+    let IsisTlv::RouterCap(v) = tlv else {
+        panic!("not a Router Capability: {tlv:?}");
+    };
+    assert_eq!(
+        v.subs,
+        [
+            cap::IsisSubTlv::AreaLeader(IsisSubAreaLeader {
+                priority: 200,
+                algo: 0
+            }),
+            cap::IsisSubTlv::DynamicFlooding(IsisSubDynamicFlooding {
+                algos: vec![0, 128]
+            }),
+        ]
+    );
+}
+
+// Leaf and spine fabric with every leaf flooding to two spines only.
+fn fabric(spines: u16, leaves: u16) -> Vec<(IsisSysId, IsisSysId)> {
+    (0..leaves)
+        .flat_map(|leaf| {
+            let leaf = 1000 + leaf;
+            [
+                (sys_id(leaf), sys_id(leaf % spines)),
+                (sys_id((leaf + 1) % spines), sys_id(leaf)),
+            ]
+        })
+        .collect()
+}
+
+#[test]
+pub fn flooding_topology_encode_decode() {
+    let edges = fabric(4, 60);
+    let topo = IsisFloodingTopology::new(&edges);
+    assert_eq!(topo.sys_ids.len(), 64);
+    assert_eq!(topo.edges.len(), 120);
+
+    let tlvs = topo.encode();
+    let area: Vec<&IsisTlvAreaSysIds> = tlvs
+        .iter()
+        .filter_map(|tlv| match tlv {
+            IsisTlv::AreaSysIds(v) => Some(v),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(area.len(), 2);
+    assert_eq!(area[1].start, 42);
+    assert!(!area[0].flags.l_flag() && area[1].flags.l_flag());
+    // Every TLV fits in an LSP.
+    for tlv in &tlvs {
+        let mut buf = BytesMut::new();
+        tlv.emit(&mut buf);
+        assert!(buf.len() <= 257);
+    }
+
+    let decoded = IsisFloodingTopology::decode(&tlvs).unwrap();
+    assert_eq!(decoded, topo);
+    let links: BTreeSet<_> = decoded.links().into_iter().collect();
+    for (a, b) in edges {
+        let reverse = (b.clone(), a.clone());
+        assert!(links.contains(&(a, b)) || links.contains(&reverse));
+    }
+    assert_eq!(
+        decoded.adjacencies(&sys_id(1000)),
+        [sys_id(0), sys_id(1)].into()
+    );
+    assert_eq!(decoded.adjacencies(&sys_id(0)).len(), 30);
+    assert!(decoded.adjacencies(&sys_id(999)).is_empty());
+}
+
+#[test]
+pub fn flooding_topology_long_path() {
+    // A ring longer than one Flooding Path TLV.
+    let edges: Vec<_> = (0..300)
+        .map(|i| (sys_id(i), sys_id((i + 1) % 300)))
+        .collect();
+    let topo = IsisFloodingTopology::new(&edges);
+    let tlvs = topo.encode();
+    assert!(
+        tlvs.iter()
+            .filter(|tlv| matches!(tlv, IsisTlv::FloodingPath(_)))
+            .count()
+            > 1
+    );
+    assert_eq!(IsisFloodingTopology::decode(&tlvs).unwrap(), topo);
+}
+
+#[test]
+pub fn flooding_topology_decode_error() {
+    let path = IsisTlv::FloodingPath(IsisTlvFloodingPath { path: vec![0, 2] });
+    let area = |start, last| {
+        IsisTlv::AreaSysIds(IsisTlvAreaSysIds {
+            start,
+            flags: AreaSysIdsFlags::new().with_l_flag(last),
+            sys_ids: vec![sys_id(1), sys_id(2)],
+        })
+    };
+    assert!(IsisFloodingTopology::decode(&[area(0, false), path.clone()]).is_err());
+    assert!(IsisFloodingTopology::decode(&[area(1, true), path.clone()]).is_err());
+    // Index 2 beyond the two systems.
+    assert!(IsisFloodingTopology::decode(&[area(0, true), path]).is_err());
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use isis_packet::*;
+
+use common::sys_id;
+
+fn lsp(id: u8, hostname: Option<&str>) -> IsisLsp {
+    let tlvs = hostname
+        .map(|hostname| {
+            IsisTlv::Hostname(IsisTlvHostname {
+                hostname: hostname.into(),
+            })
+        })
+        .into_iter()
+        .collect();
+    common::lsp(id, tlvs)
+}
+
+#[test]
+pub fn hostname_map() {
+    let lsdb: IsisLsdb = [lsp(1, Some("r1")), lsp(2, None)].into_iter().collect();
+    let map = IsisHostnameMap::from(&lsdb);
+    assert_eq!(map.len(), 1);
+    assert_eq!(map.get(&sys_id(1)), Some("r1"));
+    assert_eq!(map.get(&sys_id(2)), None);
+
+    assert_eq!(map.sys_id(&sys_id(1)).to_string(), "r1");
+    let lan_id = IsisNeighborId::from_sys_id(&sys_id(1), 2);
+    assert_eq!(map.neighbor_id(&lan_id).to_string(), "r1.02");
+    let lsp_id = IsisLspId::new(sys_id(1), 0, 3);
+    assert_eq!(map.lsp_id(&lsp_id).to_string(), "r1.00-03");
+
+    // Unknown systems keep the system ID.
+    assert_eq!(map.sys_id(&sys_id(2)).to_string(), "0000.0000.0002");
+    let lsp_id = IsisLspId::new(sys_id(2), 0, 0);
+    assert_eq!(map.lsp_id(&lsp_id).to_string(), "0000.0000.0002.00-00");
+    assert_eq!(
+        IsisNamed::new(&sys_id(1), None).to_string(),
+        "0000.0000.0001"
+    );
+}
+
+#[test]
+pub fn hostname_map_update() {
+    let mut map = IsisHostnameMap::new();
+    assert!(map.is_empty());
+    map.insert_lsp(&lsp(1, Some("r1")));
+    map.insert_lsp(&lsp(1, Some("core-1")));
+    map.insert_lsp(&lsp(2, None));
+    assert_eq!(map.len(), 1);
+    assert_eq!(map.get(&sys_id(1)), Some("core-1"));
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use bytes::BytesMut;
+use hex_literal::hex;
+use isis_packet::*;
+
+use common::{lan_hello, lsp, round_trip, sys_id};
+
+fn instance(iid: u16, itids: &[u16]) -> IsisTlv {
+    IsisTlvInstanceId {
+        iid,
+        itids: itids.to_vec(),
+    }
+    .into()
+}
+
+fn hello(tlvs: Vec<IsisTlv>) -> IsisPacket {
+    let hello = lan_hello(1, 1, tlvs);
+    IsisPacket::from(IsisType::L1Hello, IsisPdu::L1Hello(hello))
+}
+
+#[test]
+pub fn instance_id_parse_emit() {
+    let bytes = hex!("07 06 00 05 00 01 00 02");
+    let (rest, tlv) = IsisTlv::parse_tlv(&bytes).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(tlv, instance(5, &[1, 2]));
+    let mut buf = BytesMut::new();
+    tlv.emit(&mut buf);
+    assert_eq!(&buf[..], &bytes[..]);
+
+    let (_, tlv) = IsisTlv::parse_tlv(&hex!("07 02 00 05")).unwrap();
+    assert_eq!(tlv, instance(5, &[]));
+}
+
+#[test]
+pub fn instance_id_hello() {
+    // Hellos list every topology of the instance.
+    let packet = round_trip(hello(vec![instance(5, &[1, 2])]));
+    assert_eq!(
+        packet.instance_id_tlv(),
+        Some(&IsisTlvInstanceId {
+            iid: 5,
+            itids: vec![1, 2]
+        })
+    );
+    assert!(packet.is_instance(5));
+    assert!(!packet.is_instance(0));
+    assert!(packet.is_topology(5, 2));
+    assert!(!packet.is_topology(5, 0));
+    assert!(!packet.is_topology(6, 1));
+}
+
+#[test]
+pub fn instance_id_lsp_snp() {
+    let lsp = lsp(1, vec![instance(7, &[3])]);
+    let packet = round_trip(IsisPacket::from(IsisType::L2Lsp, IsisPdu::L2Lsp(lsp)));
+    assert!(packet.is_instance(7));
+    assert!(packet.is_topology(7, 3));
+
+    let psnp = IsisPsnp {
+        pdu_len: 0,
+        source_id: sys_id(1),
+        source_id_curcuit: 0,
+        tlvs: vec![instance(7, &[])],
+    };
+    let packet = round_trip(IsisPacket::from(IsisType::L2Psnp, IsisPdu::L2Psnp(psnp)));
+    assert!(packet.is_instance(7));
+    // Without ITIDs only the standard topology.
+    assert!(packet.is_topology(7, 0));
+    assert!(!packet.is_topology(7, 3));
+}
+
+#[test]
+pub fn instance_id_default() {
+    // PDUs without the TLV belong to the standard instance.
+    let packet = hello(vec![]);
+    assert!(packet.instance_id_tlv().is_none());
+    assert!(packet.is_instance(0));
+    assert!(packet.is_topology(0, 0));
+    assert!(!packet.is_instance(5));
+
+    // PDUs with more than one Instance ID TLV belong to none.
+    let packet = hello(vec![instance(5, &[]), instance(5, &[])]);
+    assert!(packet.instance_id_tlv().is_none());
+    assert!(!packet.is_instance(5));
+    assert!(!packet.is_instance(0));
+}
@@ -105,5 +105,40 @@ pub fn json_round_trip_test() {
     let deserialized: IsisSysId = serde_json::from_str(&serialized).unwrap();
     assert_eq!(sys_id, deserialized);
 
+    // Identifiers are strings and read back from the same form
+    let lsp_id = IsisLspId::new(sys_id.clone(), 0, 3);
+    let serialized = serde_json::to_string(&lsp_id).unwrap();
+    assert_eq!(serialized, "\"0001.0203.0405.00-03\"");
+    let deserialized: IsisLspId = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(lsp_id, deserialized);
+
+    let neighbor_id = lsp_id.neighbor_id();
+    let serialized = serde_json::to_string(&neighbor_id).unwrap();
+    let deserialized: IsisNeighborId = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(neighbor_id, deserialized);
+
     println!("All round-trip JSON serialization/deserialization tests passed!");
 }
+
+#[test]
+pub fn id_from_str_test() {
+    let sys_id: IsisSysId = "0000.0000.0001".parse().unwrap();
+    assert_eq!(sys_id.id, [0, 0, 0, 0, 0, 1]);
+    assert_eq!("000000000001".parse::<IsisSysId>().unwrap(), sys_id);
+
+    let neighbor_id: IsisNeighborId = "0000.0000.0001.02".parse().unwrap();
+    assert_eq!(neighbor_id, IsisNeighborId::from_sys_id(&sys_id, 2));
+
+    let lsp_id: IsisLspId = "0000.0000.0001.02-03".parse().unwrap();
+    assert_eq!(lsp_id, IsisLspId::new(sys_id, 2, 3));
+    assert_eq!("00000000000102 03".parse::<IsisLspId>().ok(), None);
+
+    assert!(matches!(
+        "0000.0000.0001".parse::<IsisLspId>(),
+        Err(IsisParseError::InvalidLspId { .. })
+    ));
+    assert!(matches!(
+        "r1".parse::<IsisSysId>(),
+        Err(IsisParseError::InvalidSysId { .. })
+    ));
+}
@@ -0,0 +1,161 @@
+mod common;
+
+use isis_packet::prefix::IsisSubTlv as PrefixSubTlv;
+use isis_packet::*;
+
+use common::{lsp, sys_id};
+
+fn sid(flags: PrefixSidFlags, sid: SidLabelValue) -> Vec<PrefixSubTlv> {
+    vec![PrefixSubTlv::PrefixSid(IsisSubPrefixSid {
+        flags,
+        algo: Algo::Spf,
+        sid,
+    })]
+}
+
+fn v4(prefix: &str, subs: Vec<PrefixSubTlv>) -> IsisTlvExtIpReachEntry {
+    IsisTlvExtIpReachEntry {
+        metric: 10,
+        flags: 0x40.into(),
+        prefix: prefix.parse().unwrap(),
+        subs,
+    }
+}
+
+fn router(id: u8, srgb: &[(u32, u32)], tlvs: Vec<IsisTlv>) -> IsisLsp {
+    let subs = srgb
+        .iter()
+        .map(|&(base, range)| {
+            cap::IsisSubTlv::SegmentRoutingCap(IsisSubSegmentRoutingCap {
+                flags: 0xc0.into(),
+                range,
+                sid_label: SidLabelTlv::Label(base),
+            })
+        })
+        .collect();
+    let mut lsp = lsp(
+        id,
+        vec![IsisTlv::RouterCap(IsisTlvRouterCap {
+            router_id: format!("10.0.0.{id}").parse().unwrap(),
+            flags: 0.into(),
+            subs,
+        })],
+    );
+    lsp.tlvs.extend(tlvs);
+    lsp
+}
+
+// Router 1 has two SRGB ranges, router 2 one and router 3 none.
+fn resolver() -> IsisSrResolver {
+    let node = PrefixSidFlags::new().with_n_flag(true);
+    let r1 = router(
+        1,
+        &[(16000, 100), (20000, 100)],
+        vec![IsisTlv::ExtIpReach(IsisTlvExtIpReach {
+            entries: vec![
+                v4("10.0.0.1/32", sid(node, SidLabelValue::Index(150))),
+                v4(
+                    "10.1.0.0/24",
+                    sid(
+                        PrefixSidFlags::new()
+                            .with_p_flag(true)
+                            .with_v_flag(true)
+                            .with_l_flag(true),
+                        SidLabelValue::Label(24001),
+                    ),
+                ),
+                v4(
+                    "10.2.0.0/24",
+                    sid(
+                        PrefixSidFlags::new().with_v_flag(true),
+                        SidLabelValue::Index(1),
+                    ),
+                ),
+            ],
+        })],
+    );
+    let r2 = router(
+        2,
+        &[(16000, 8000)],
+        vec![
+            IsisTlv::ExtIpReach(IsisTlvExtIpReach {
+                entries: vec![
+                    v4(
+                        "10.0.0.2/32",
+                        sid(node.with_e_flag(true), SidLabelValue::Index(9000)),
+                    ),
+                    v4(
+                        "10.3.0.0/24",
+                        sid(
+                            PrefixSidFlags::new().with_p_flag(true).with_e_flag(true),
+                            SidLabelValue::Index(3),
+                        ),
+                    ),
+                ],
+            }),
+            IsisTlv::Ipv6Reach(IsisTlvIpv6Reach {
+                entries: vec![IsisTlvIpv6ReachEntry {
+                    metric: 10,
+                    flags: 0x20.into(),
+                    prefix: "2001:db8::2/128".parse().unwrap(),
+                    subs: sid(node.with_e_flag(true), SidLabelValue::Index(2)),
+                }],
+            }),
+        ],
+    );
+    let r3 = router(3, &[], vec![]);
+    let lsdb: IsisLsdb = [r1, r2, r3].into_iter().collect();
+    IsisSrResolver::new(&lsdb)
+}
+
+#[test]
+pub fn srgb_ranges() {
+    let srgb = IsisSrgb {
+        ranges: vec![(16000, 100), (20000, 100)],
+    };
+    assert_eq!(srgb.size(), 200);
+    assert_eq!(srgb.label(99), Some(16099));
+    assert_eq!(srgb.label(100), Some(20000));
+    assert_eq!(srgb.label(200), None);
+    assert_eq!(IsisSrgb::default().label(0), None);
+}
+
+#[test]
+pub fn resolve_prefix_sid() {
+    let resolver = resolver();
+    assert_eq!(resolver.srgbs[&sys_id(1)].size(), 200);
+    assert!(resolver.srgbs[&sys_id(3)].is_empty());
+    let resolve = |router: u8, prefix: &str| {
+        resolver
+            .resolve(&sys_id(router), &prefix.parse().unwrap(), Algo::Spf)
+            .unwrap()
+    };
+
+    // Index 150 falls in the second range of router 1.
+    assert_eq!(resolve(1, "10.0.0.1/32"), IsisSrLabel::ImplicitNull);
+    assert_eq!(resolve(2, "10.0.0.1/32"), IsisSrLabel::Label(16150));
+    assert_eq!(resolve(3, "10.0.0.1/32"), IsisSrLabel::NoSrgb);
+    assert_eq!(resolve(1, "10.0.0.1/32").label(), Some(3));
+
+    // Explicit NULL of the prefix address family on the originator.
+    assert_eq!(resolve(2, "10.0.0.2/32"), IsisSrLabel::ExplicitNull(0));
+    assert_eq!(resolve(1, "10.0.0.2/32"), IsisSrLabel::OutOfRange(9000));
+    assert_eq!(resolve(2, "2001:db8::2/128"), IsisSrLabel::ExplicitNull(2));
+    assert_eq!(resolve(1, "2001:db8::2/128"), IsisSrLabel::Label(16002));
+
+    // The E flag asks for explicit NULL even with the P flag set.
+    assert_eq!(resolve(2, "10.3.0.0/24"), IsisSrLabel::ExplicitNull(0));
+    assert_eq!(resolve(1, "10.3.0.0/24"), IsisSrLabel::Label(16003));
+
+    // Local label with the P flag is only expected by the originator.
+    assert_eq!(resolve(1, "10.1.0.0/24"), IsisSrLabel::Label(24001));
+    assert_eq!(resolve(2, "10.1.0.0/24"), IsisSrLabel::Invalid);
+    assert_eq!(resolve(2, "10.2.0.0/24"), IsisSrLabel::Invalid);
+
+    assert!(
+        resolver
+            .resolve(&sys_id(1), &"10.9.0.0/24".parse().unwrap(), Algo::Spf)
+            .is_none()
+    );
+    assert_eq!(resolver.labels().len(), 3 * 6);
+}
@@ -0,0 +1,189 @@
+mod common;
+
+use std::collections::BTreeSet;
+
+use isis_packet::prefix::{Ipv4ControlInfo, Ipv6ControlInfo, IsisSubTlv as PrefixSubTlv};
+use isis_packet::*;
+
+use common::{is_reach, lsp, sys_id};
+
+fn v4(prefix: &str, metric: u32, up_down: bool) -> IsisTlvExtIpReachEntry {
+    let prefix: ipnet::Ipv4Net = prefix.parse().unwrap();
+    IsisTlvExtIpReachEntry {
+        metric,
+        flags: Ipv4ControlInfo::new()
+            .with_prefixlen(prefix.prefix_len() as usize)
+            .with_distribution(up_down),
+        prefix,
+        subs: vec![],
+    }
+}
+
+fn v6(prefix: &str, metric: u32, external: bool) -> IsisTlvIpv6ReachEntry {
+    IsisTlvIpv6ReachEntry {
+        metric,
+        flags: Ipv6ControlInfo::new().with_dist_internal(external),
+        prefix: prefix.parse().unwrap(),
+        subs: vec![],
+    }
+}
+
+fn router(
+    id: u8,
+    area: u8,
+    attached: bool,
+    links: &[(u8, u32)],
+    ipv4: Vec<IsisTlvExtIpReachEntry>,
+    ipv6: Vec<IsisTlvIpv6ReachEntry>,
+) -> IsisLsp {
+    let mut lsp = lsp(
+        id,
+        vec![
+            IsisTlv::AreaAddr(IsisTlvAreaAddr {
+                area_addr: vec![0x49, 0, area],
+            }),
+            is_reach(links),
+            IsisTlv::ExtIpReach(IsisTlvExtIpReach { entries: ipv4 }),
+            IsisTlv::Ipv6Reach(IsisTlvIpv6Reach { entries: ipv6 }),
+        ],
+    );
+    lsp.types.set_att_bits(attached as u8);
+    lsp
+}
+
+// Area 49.0001 with L1 router 1 and L1/L2 routers 2 and 3, router 4 in
+// area 49.0002 reached over L2 from router 2.
+fn l1() -> IsisLsdb {
+    let mut sid = v4("10.9.9.9/32", 0, false);
+    sid.subs = vec![PrefixSubTlv::PrefixSid(IsisSubPrefixSid {
+        flags: PrefixSidFlags::new().with_n_flag(true),
+        algo: Algo::Spf,
+        sid: SidLabelValue::Index(9),
+    })];
+    [
+        router(
+            1,
+            1,
+            false,
+            &[(2, 10), (3, 10)],
+            vec![
+                v4("10.1.1.0/24", 10, false),
+                v4("10.1.2.0/24", 20, false),
+                v4("172.16.0.0/16", 10, true),
+                sid,
+            ],
+            vec![v6("2001:db8:1::/64", 10, true)],
+        ),
+        router(
+            2,
+            1,
+            true,
+            &[(1, 10)],
+            vec![v4("10.2.0.0/24", 10, false)],
+            vec![],
+        ),
+        router(3, 1, true, &[(1, 10)], vec![], vec![]),
+    ]
+    .into_iter()
+    .collect()
+}
+
+fn l2(remote_area: u8) -> IsisLsdb {
+    [
+        router(2, 1, false, &[(4, 20)], vec![], vec![]),
+        router(
+            4,
+            remote_area,
+            false,
+            &[(2, 20)],
+            vec![v4("10.4.0.0/24", 10, false), v4("192.0.2.0/24", 5, false)],
+            vec![v6("2001:db8:4::/64", 10, false)],
+        ),
+    ]
+    .into_iter()
+    .collect()
+}
+
+fn policy() -> IsisLevelPolicy {
+    IsisLevelPolicy {
+        summaries: vec!["10.1.0.0/16".parse().unwrap()],
+        leak_down: vec![
+            "10.4.0.0/16".parse().unwrap(),
+            "2001:db8:4::/48".parse().unwrap(),
+        ],
+        no_attached: false,
+    }
+}
+
+#[test]
+pub fn leak_l1_into_l2() {
+    let leak = policy().apply(&sys_id(2), &l1(), &l2(2));
+    // The summary takes the lowest metric, the leaked 172.16.0.0/16 stays
+    // in L1 and the Prefix-SID is propagated with the R flag.
+    assert_eq!(
+        leak.l2_ipv4
+            .iter()
+            .map(|e| (e.prefix.to_string(), e.metric))
+            .collect::<Vec<_>>(),
+        [
+            ("10.1.0.0/16".into(), 20),
+            ("10.2.0.0/24".into(), 10),
+            ("10.9.9.9/32".into(), 10),
+        ]
+    );
+    assert_eq!(leak.l2_ipv4[0].flags.prefixlen(), 16);
+    let sid = &leak.l2_ipv4[2];
+    assert!(sid.flags.sub_tlv());
+    let sid = sid.prefix_sid().unwrap();
+    assert!(sid.flags.r_flag() && sid.flags.n_flag());
+
+    assert_eq!(leak.l2_ipv6.len(), 1);
+    assert_eq!(leak.l2_ipv6[0].metric, 20);
+    assert!(leak.l2_ipv6[0].flags.dist_internal());
+    assert!(!leak.l2_ipv6[0].flags.dist_up());
+}
+
+#[test]
+pub fn leak_l2_into_l1() {
+    let leak = policy().apply(&sys_id(2), &l1(), &l2(2));
+    assert_eq!(leak.l1_ipv4.len(), 1);
+    assert_eq!(leak.l1_ipv4[0].prefix.to_string(), "10.4.0.0/24");
+    assert_eq!(leak.l1_ipv4[0].metric, 30);
+    assert!(leak.l1_ipv4[0].flags.distribution());
+    assert_eq!(leak.l1_ipv6.len(), 1);
+    assert!(leak.l1_ipv6[0].flags.dist_up());
+    assert_eq!(leak.l1_ipv6[0].metric, 30);
+}
+
+#[test]
+pub fn attached_bit() {
+    assert!(policy().apply(&sys_id(2), &l1(), &l2(2)).attached);
+    // L2 only reaches the local area.
+    assert!(!policy().apply(&sys_id(2), &l1(), &l2(1)).attached);
+    let policy = IsisLevelPolicy {
+        no_attached: true,
+        ..policy()
+    };
+    assert!(!policy.apply(&sys_id(2), &l1(), &l2(2)).attached);
+}
+
+#[test]
+pub fn l1_default_route() {
+    let default = l1().l1_default(&sys_id(1)).unwrap();
+    assert_eq!(default.metric, 10);
+    let both: BTreeSet<IsisSysId> = [sys_id(2), sys_id(3)].into();
+    assert_eq!(default.routers, both);
+    assert_eq!(default.nexthops, both);
+
+    let mut lsdb = l1();
+    lsdb.insert(router(3, 1, false, &[(1, 10)], vec![], vec![]));
+    let default = lsdb.l1_default(&sys_id(1)).unwrap();
+    assert_eq!(default.routers, [sys_id(2)].into());
+    // An attached router points the default at the others, not itself.
+    assert!(
+        l1().l1_default(&sys_id(2))
+            .unwrap()
+            .routers
+            .contains(&sys_id(3))
+    );
+}
@@ -0,0 +1,58 @@
+use isis_packet::*;
+
+fn sys_id() -> IsisSysId {
+    IsisSysId {
+        id: [0x19, 0x21, 0x68, 0x00, 0x10, 0x01],
+    }
+}
+
+#[test]
+pub fn nsap_area_sys_id() {
+    let area = IsisTlvAreaAddr {
+        area_addr: vec![0x49, 0x00, 0x01],
+    };
+    let nsap = Nsap::from((&area, &sys_id()));
+    assert_eq!(nsap.to_string(), "49.0001.1921.6800.1001.00");
+    assert_eq!(nsap, "49.0001.1921.6800.1001.00".parse().unwrap());
+
+    // Back to the TLV and system ID.
+    assert_eq!(IsisTlvAreaAddr::from(&nsap), area);
+    assert_eq!(IsisSysId::from(&nsap), sys_id());
+    assert_eq!(
+        nsap.neighbor_id(),
+        IsisNeighborId::from_sys_id(&sys_id(), 0)
+    );
+}
+
+#[test]
+pub fn nsap_validate() {
+    let nsap = |s: &str| s.parse::<Nsap>().unwrap();
+    assert!(nsap("49.0001.1921.6800.1001.00").validate().is_ok());
+    assert!(
+        nsap("49.0102.0304.0506.0708.090a.0b0c.0000.0000.0001.00")
+            .validate()
+            .is_ok()
+    );
+    assert!(
+        nsap("49.0102.0304.0506.0708.090a.0b0c.0d.0000.0000.0001.00")
+            .validate()
+            .is_err()
+    );
+
+    // ISO DCC and ICD: IDI, DFI, AA, reserved, RD and area.
+    assert!(
+        nsap("39.0840.8000.0000.0000.0000.0001.0000.0000.0001.00")
+            .validate()
+            .is_ok()
+    );
+    assert!(
+        nsap("47.0005.8000.0000.0000.0000.0001.0000.0000.0001.00")
+            .validate()
+            .is_ok()
+    );
+    let err = nsap("39.0001.0000.0000.0001.00").validate().unwrap_err();
+    assert!(matches!(err, IsisParseError::InvalidNsapAddress { .. }));
+    assert!(err.to_string().contains("expected 13"));
+
+    assert!(nsap("45.0001.0000.0000.0001.00").validate().is_err());
+}
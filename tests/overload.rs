@@ -0,0 +1,111 @@
+mod common;
+
+use hex_literal::hex;
+use isis_packet::*;
+
+use common::{is_reach, lsp, node, parse_lsp, round_trip};
+
+// Captured L1 LSP with IS reachability to 0000.0000.0001.00 and
+// 0000.0000.0003.00.
+const LSP: &[u8] = &hex!(
+    "
+    83 1b 01 00 12 01 00 00 00 33 04 a0 00 00 00
+    00 00 01 03 00 00 00 00 01 95 34 01 16 16 00 00
+    00 00 00 01 00 00 00 00 00 00 00 00 00 00 03 00
+    00 00 00 00
+    "
+);
+
+// Emit and parse the LSP again.
+fn reparse(lsp: IsisLsp) -> IsisLsp {
+    let packet = round_trip(IsisPacket::from(IsisType::L1Lsp, IsisPdu::L1Lsp(lsp)));
+    let (IsisPdu::L1Lsp(lsp) | IsisPdu::L2Lsp(lsp)) = packet.pdu else {
+        panic!("not an LSP");
+    };
+    lsp
+}
+
+fn metrics(lsp: &IsisLsp) -> Vec<(IsisNeighborId, u32)> {
+    lsp.tlvs
+        .iter()
+        .filter_map(|tlv| match tlv {
+            IsisTlv::ExtIsReach(v) => Some(v.entries.iter()),
+            _ => None,
+        })
+        .flatten()
+        .map(|entry| (entry.neighbor_id.clone(), entry.metric))
+        .collect()
+}
+
+fn captured() -> IsisLsp {
+    parse_lsp(LSP)
+}
+
+#[test]
+pub fn overload_bit() {
+    let mut lsp = captured();
+    assert!(!lsp.is_overloaded());
+    lsp.set_overload(true);
+    let lsp = reparse(lsp);
+    assert!(lsp.is_overloaded());
+    assert_eq!(lsp.types.is_bits(), 1);
+
+    let mut lsp = lsp;
+    lsp.set_overload(false);
+    assert!(!reparse(lsp).is_overloaded());
+}
+
+#[test]
+pub fn max_metric() {
+    let mut lsp = captured();
+    assert_eq!(metrics(&lsp).len(), 2);
+    lsp.set_max_metric(&[node(3)]);
+    assert_eq!(
+        metrics(&reparse(lsp)),
+        [(node(1), MAX_LINK_METRIC), (node(3), LINK_METRIC_EXCLUDED)]
+    );
+}
+
+fn router(id: u8, overload: bool, links: &[(u8, u32)]) -> IsisLsp {
+    let mut lsp = lsp(id, vec![is_reach(links)]);
+    lsp.set_overload(overload);
+    lsp
+}
+
+// Line 1 - 2 - 3 with a longer path 1 - 4 - 3.
+fn lsdb(overload: bool) -> IsisLsdb {
+    [
+        router(1, false, &[(2, 10), (4, 20)]),
+        router(2, overload, &[(1, 10), (3, 10)]),
+        router(3, false, &[(2, 10), (4, 20)]),
+        router(4, false, &[(1, 20), (3, 20)]),
+    ]
+    .into_iter()
+    .collect()
+}
+
+#[test]
+pub fn spf_overload() {
+    let tree = IsisSpfGraph::new(&lsdb(false)).spf(&node(1));
+    assert_eq!(tree.path(&node(3)).unwrap(), [node(1), node(2), node(3)]);
+
+    // The overloaded router stays reachable without carrying transit.
+    let tree = IsisSpfGraph::new(&lsdb(true)).spf(&node(1));
+    assert_eq!(tree.distance(&node(2)), Some(10));
+    assert_eq!(tree.path(&node(3)).unwrap(), [node(1), node(4), node(3)]);
+
+    // Its own tree is not affected.
+    let tree = IsisSpfGraph::new(&lsdb(true)).spf(&node(2));
+    assert_eq!(tree.distance(&node(4)), Some(30));
+}
+
+#[test]
+pub fn spf_excluded_link() {
+    let mut lsdb = lsdb(false);
+    let mut lsp = router(2, false, &[(1, 10), (3, 10)]);
+    lsp.set_max_metric(&[node(3)]);
+    lsdb.insert(lsp);
+    let tree = IsisSpfGraph::new(&lsdb).spf(&node(1));
+    assert_eq!(tree.distance(&node(2)), Some(10));
+    assert_eq!(tree.path(&node(3)).unwrap(), [node(1), node(4), node(3)]);
+}
@@ -20,3 +20,127 @@ pub fn padding_hello() {
     packet.emit(&mut buf);
     assert_eq!(buf.len(), 1500 - 3);
 }
+
+// Ethernet destination, source and length ahead of the LLC header.
+const ETHER_HEADER_LEN: usize = 14;
+
+fn lan_hello(pdu_type: IsisType) -> IsisPacket {
+    let hello = IsisHello {
+        circuit_type: IsLevel::L1L2,
+        source_id: IsisSysId::default(),
+        hold_time: 30,
+        pdu_len: 0,
+        priority: 64,
+        lan_id: IsisNeighborId::default(),
+        tlvs: vec![
+            IsisTlvAreaAddr {
+                area_addr: vec![0x49, 0, 1],
+            }
+            .into(),
+        ],
+    };
+    let pdu = match pdu_type {
+        IsisType::L1Hello => IsisPdu::L1Hello(hello),
+        _ => IsisPdu::L2Hello(hello),
+    };
+    IsisPacket::from(pdu_type, pdu)
+}
+
+fn p2p_hello() -> IsisPacket {
+    let hello = IsisP2pHello {
+        circuit_type: IsLevel::L2,
+        source_id: IsisSysId::default(),
+        hold_time: 30,
+        pdu_len: 0,
+        circuit_id: 1,
+        tlvs: vec![
+            IsisTlvP2p3Way {
+                state: 2,
+                circuit_id: 1,
+                neighbor_id: IsisNeighborId::default(),
+                neighbor_circuit_id: 0,
+            }
+            .into(),
+        ],
+    };
+    IsisPacket::from(IsisType::P2PHello, IsisPdu::P2pHello(hello))
+}
+
+fn frame_len(packet: &IsisPacket) -> usize {
+    let mut buf = BytesMut::new();
+    packet.emit_frame([0; 6], &mut buf);
+    buf.len() - ETHER_HEADER_LEN
+}
+
+#[test]
+pub fn padding_hello_types() {
+    for mtu in [1500, 1497, 1243, 9000] {
+        for mut packet in [
+            lan_hello(IsisType::L1Hello),
+            lan_hello(IsisType::L2Hello),
+            p2p_hello(),
+        ] {
+            assert!(packet.pad_hello(&IsisHelloPadding::new(mtu), false));
+            assert_eq!(frame_len(&packet), mtu, "{:?} MTU {mtu}", packet.pdu_type);
+            // Still parses with the padding.
+            let mut buf = BytesMut::new();
+            packet.emit(&mut buf);
+            let (_, parsed) = parse(&buf).unwrap();
+            assert_eq!(parsed.pdu_type, packet.pdu_type);
+        }
+    }
+}
+
+#[test]
+pub fn padding_hello_auth() {
+    // HMAC-MD5 authentication TLV added once padded.
+    let auth = IsisTlv::Unknown(IsisTlvUnknown {
+        typ: 10.into(),
+        len: 17,
+        values: vec![0x36; 17],
+    });
+    let padding = IsisHelloPadding {
+        auth_len: 19,
+        ..IsisHelloPadding::new(1500)
+    };
+    let mut packet = p2p_hello();
+    assert!(packet.pad_hello(&padding, false));
+    let IsisPdu::P2pHello(hello) = &mut packet.pdu else {
+        panic!("not a P2P hello");
+    };
+    hello.tlvs.push(auth);
+    assert_eq!(frame_len(&packet), 1500);
+}
+
+#[test]
+pub fn padding_hello_sparse() {
+    let padding = IsisHelloPadding {
+        mode: IsisPaddingMode::Sparse,
+        ..IsisHelloPadding::new(1500)
+    };
+    let mut packet = lan_hello(IsisType::L2Hello);
+    assert!(packet.pad_hello(&padding, false));
+    assert_eq!(frame_len(&packet), 1500);
+
+    // No padding once the adjacency is up.
+    let mut packet = lan_hello(IsisType::L2Hello);
+    assert!(!packet.pad_hello(&padding, true));
+    assert!(frame_len(&packet) < 1500);
+}
+
+#[test]
+pub fn padding_hello_too_large() {
+    let pdu_len = |packet: &IsisPacket| {
+        let mut buf = BytesMut::new();
+        packet.emit(&mut buf);
+        buf.len()
+    };
+    // One byte short of a padding TLV, small frames are below the
+    // Ethernet minimum.
+    let mut packet = p2p_hello();
+    let mtu = 3 + pdu_len(&packet) + 1;
+    assert!(!packet.pad_hello(&IsisHelloPadding::new(mtu), false));
+    assert!(!packet.pad_hello(&IsisHelloPadding::new(10), false));
+    assert!(packet.pad_hello(&IsisHelloPadding::new(mtu + 1), false));
+    assert_eq!(3 + pdu_len(&packet), mtu + 1);
+}
@@ -16,6 +16,14 @@ fn parse_emit(buf: &[u8]) {
 
     let (_, packet) = packet.unwrap();
     println!("{}", packet);
+
+    // JSON round-trip keeps the packet as is.
+    let json = serde_json::to_string(&packet).unwrap();
+    let decoded: IsisPacket = serde_json::from_str(&json).unwrap();
+    assert_eq!(serde_json::to_string(&decoded).unwrap(), json);
+    let mut decoded_buf = BytesMut::new();
+    decoded.emit(&mut decoded_buf);
+    assert_eq!(decoded_buf, buf);
 }
 
 #[test]
@@ -0,0 +1,250 @@
+mod common;
+
+use bytes::BytesMut;
+use hex_literal::hex;
+use isis_packet::area_proxy::IsisSubTlv as AreaProxySubTlv;
+use isis_packet::prefix::Ipv4ControlInfo;
+use isis_packet::*;
+
+use common::{is_reach, lsp, node, round_trip, sys_id};
+
+fn area_sid() -> IsisSubAreaSid {
+    IsisSubAreaSid {
+        flags: AreaSidFlags::new(),
+        sid: SidLabelValue::Index(100),
+    }
+}
+
+fn router(id: u8, proxy: Option<Vec<AreaProxySubTlv>>, links: &[(u8, u32)]) -> IsisLsp {
+    let mut tlvs = vec![
+        IsisTlv::AreaAddr(IsisTlvAreaAddr {
+            area_addr: vec![0x49, 0, id],
+        }),
+        IsisTlv::ProtoSupported(IsisTlvProtoSupported { nlpids: vec![0xcc] }),
+    ];
+    if let Some(subs) = proxy {
+        tlvs.push(IsisTlv::AreaProxy(IsisTlvAreaProxy { subs }));
+    }
+    tlvs.push(is_reach(links));
+    // Loopback and an anycast prefix whose metric grows with the ID.
+    let prefix = |prefix: &str, metric| {
+        let prefix: ipnet::Ipv4Net = prefix.parse().unwrap();
+        IsisTlvExtIpReachEntry {
+            metric,
+            flags: Ipv4ControlInfo::new().with_prefixlen(prefix.prefix_len() as usize),
+            prefix,
+            subs: vec![],
+        }
+    };
+    tlvs.push(IsisTlv::ExtIpReach(IsisTlvExtIpReach {
+        entries: vec![
+            prefix(&format!("10.0.0.{id}/32"), 0),
+            prefix("192.0.2.0/24", id as u32),
+        ],
+    }));
+    lsp(id, tlvs)
+}
+
+// Inside nodes 1, 2 and 3 with router 2 as Area Leader, outside nodes 10
+// and 11 attached to routers 1 and 3.
+fn lsdb() -> IsisLsdb {
+    let leader = vec![
+        AreaProxySubTlv::ProxySysId(IsisSubProxySysId {
+            sys_id: sys_id(100),
+        }),
+        AreaProxySubTlv::AreaSid(area_sid()),
+    ];
+    [
+        router(1, Some(vec![]), &[(2, 1), (10, 10)]),
+        router(2, Some(leader), &[(1, 1), (3, 1)]),
+        router(3, Some(vec![]), &[(2, 1), (10, 20), (11, 5)]),
+        router(10, None, &[(1, 10), (3, 20)]),
+        router(11, None, &[(3, 5)]),
+    ]
+    .into_iter()
+    .collect()
+}
+
+#[test]
+pub fn area_proxy_parse_emit() {
+    let bytes = hex!("14 0f 01 06 00 00 00 00 00 64 02 05 00 00 00 00 64");
+    let (rest, tlv) = IsisTlv::parse_tlv(&bytes).unwrap();
+    assert!(rest.is_empty());
+    let IsisTlv::AreaProxy(v) = &tlv else {
+        panic!("not an Area Proxy TLV: {tlv:?}");
+    };
+    assert_eq!(v.proxy_sys_id(), Some(&sys_id(100)));
+    assert_eq!(v.area_sid(), Some(&area_sid()));
+    let mut buf = BytesMut::new();
+    tlv.emit(&mut buf);
+    assert_eq!(&buf[..], &bytes[..]);
+}
+
+#[test]
+pub fn area_proxy_inside() {
+    let proxy = IsisAreaProxy::new(&lsdb()).unwrap();
+    assert_eq!(proxy.proxy_id, sys_id(100));
+    assert_eq!(proxy.inside, [sys_id(1), sys_id(2), sys_id(3)].into());
+    assert_eq!(proxy.area_sid, Some(area_sid()));
+
+    let lsdb: IsisLsdb = [router(10, None, &[])].into_iter().collect();
+    assert!(IsisAreaProxy::new(&lsdb).is_none());
+}
+
+#[test]
+pub fn area_proxy_lsp() {
+    let lsdb = lsdb();
+    let [lsp]: [IsisLsp; 1] = IsisAreaProxy::new(&lsdb)
+        .unwrap()
+        .lsp(&lsdb, 7, 1200, 1492)
+        .try_into()
+        .unwrap();
+    assert_eq!(lsp.lsp_id, IsisLspId::new(sys_id(100), 0, 0));
+    assert_eq!(lsp.seq_number, 7);
+
+    let mut areas = Vec::new();
+    let mut neighbors = Vec::new();
+    let mut prefixes = Vec::new();
+    for tlv in &lsp.tlvs {
+        match tlv {
+            IsisTlv::AreaAddr(v) => areas.push(v.area_addr[2]),
+            IsisTlv::ExtIsReach(v) => {
+                neighbors.extend(v.entries.iter().map(|e| (e.neighbor_id.clone(), e.metric)))
+            }
+            IsisTlv::ExtIpReach(v) => {
+                prefixes.extend(v.entries.iter().map(|e| (e.prefix.to_string(), e.metric)))
+            }
+            IsisTlv::AreaProxy(v) => {
+                assert_eq!(v.subs, [AreaProxySubTlv::AreaSid(area_sid())])
+            }
+            _ => {}
+        }
+    }
+    assert_eq!(areas, [1, 2, 3]);
+    // Intra-area links are hidden, router 10 is reached at the lowest
+    // metric.
+    assert_eq!(neighbors, [(node(10), 10), (node(11), 5)]);
+    assert_eq!(
+        prefixes,
+        [
+            ("10.0.0.1/32".into(), 0),
+            ("10.0.0.2/32".into(), 0),
+            ("10.0.0.3/32".into(), 0),
+            ("192.0.2.0/24".into(), 1),
+        ]
+    );
+
+    // Outside routers see the area as a single node.
+    let mut outside: IsisLsdb = [
+        router(10, None, &[(100, 10)]),
+        router(11, None, &[(100, 5)]),
+    ]
+    .into_iter()
+    .collect();
+    outside.insert(lsp);
+    let tree = IsisSpfGraph::new(&outside).spf(&node(10));
+    assert_eq!(
+        tree.path(&node(11)).unwrap(),
+        [node(10), node(100), node(11)]
+    );
+}
+
+#[test]
+pub fn area_proxy_lan() {
+    // LAN of inside routers 1 and 3 and outside router 12, router 1 being
+    // the DIS.
+    let lan = IsisNeighborId::from_sys_id(&sys_id(1), 1);
+    let mut lsdb = lsdb();
+    for (id, metric) in [(1, 7), (3, 3)] {
+        let lsp = lsdb.get_mut(&IsisLspId::new(sys_id(id), 0, 0)).unwrap();
+        for tlv in lsp.tlvs.iter_mut() {
+            if let IsisTlv::ExtIsReach(v) = tlv {
+                v.entries.push(IsisTlvExtIsReachEntry {
+                    neighbor_id: lan.clone(),
+                    metric,
+                    subs: vec![],
+                });
+            }
+        }
+    }
+    let mut pseudonode = router(1, None, &[(1, 0), (3, 0), (12, 0)]);
+    pseudonode.lsp_id = IsisLspId::new(sys_id(1), 1, 0);
+    lsdb.insert(pseudonode);
+
+    let [lsp]: [IsisLsp; 1] = IsisAreaProxy::new(&lsdb)
+        .unwrap()
+        .lsp(&lsdb, 1, 1200, 1492)
+        .try_into()
+        .unwrap();
+    let neighbors: Vec<_> = lsp
+        .tlvs
+        .iter()
+        .filter_map(|tlv| match tlv {
+            IsisTlv::ExtIsReach(v) => Some(&v.entries),
+            _ => None,
+        })
+        .flatten()
+        .map(|e| (e.neighbor_id.clone(), e.metric))
+        .collect();
+    // The outside member is reached through the LAN, the pseudonode of an
+    // inside DIS is hidden.
+    assert_eq!(neighbors, [(node(10), 10), (node(11), 5), (node(12), 3)]);
+}
+
+#[test]
+pub fn area_proxy_lsp_fragments() {
+    // Router 1 with 30 outside neighbors and 200 /24 prefixes, more than
+    // one TLV and one fragment can carry.
+    let mut lsdb = lsdb();
+    let links: Vec<(u8, u32)> = (20..50).map(|id| (id, 10)).collect();
+    let lsp1 = lsdb.get_mut(&IsisLspId::new(sys_id(1), 0, 0)).unwrap();
+    lsp1.tlvs.push(is_reach(&links));
+    lsp1.tlvs.push(IsisTlv::ExtIpReach(IsisTlvExtIpReach {
+        entries: (0..200)
+            .map(|i| IsisTlvExtIpReachEntry {
+                metric: 10,
+                flags: Ipv4ControlInfo::new().with_prefixlen(24),
+                prefix: format!("10.1.{i}.0/24").parse().unwrap(),
+                subs: vec![],
+            })
+            .collect(),
+    }));
+
+    let lsps = IsisAreaProxy::new(&lsdb).unwrap().lsp(&lsdb, 1, 1200, 1492);
+    assert_eq!(lsps.len(), 2);
+    let mut neighbors = 0;
+    let mut prefixes = 0;
+    let mut reach_tlvs = 0;
+    for (fragment_id, lsp) in lsps.into_iter().enumerate() {
+        assert_eq!(
+            lsp.lsp_id,
+            IsisLspId::new(sys_id(100), 0, fragment_id as u8)
+        );
+        let packet = round_trip(IsisPacket::from(IsisType::L2Lsp, IsisPdu::L2Lsp(lsp)));
+        let IsisPdu::L2Lsp(lsp) = packet.pdu else {
+            panic!("not an L2 LSP");
+        };
+        assert!(lsp.pdu_len <= 1492);
+        for tlv in &lsp.tlvs {
+            match tlv {
+                IsisTlv::ExtIsReach(v) => {
+                    assert!(!v.entries.is_empty());
+                    neighbors += v.entries.len();
+                    reach_tlvs += 1;
+                }
+                IsisTlv::ExtIpReach(v) => {
+                    assert!(!v.entries.is_empty());
+                    prefixes += v.entries.len();
+                    reach_tlvs += 1;
+                }
+                IsisTlv::Ipv6Reach(_) => panic!("empty IPv6 Reachability TLV"),
+                _ => {}
+            }
+        }
+    }
+    // Outside 10, 11 and 20 to 49, the inside loopbacks, the anycast
+    // prefix and the 200 /24s.
+    assert_eq!(neighbors, 32);
+    assert_eq!(prefixes, 204);
+    assert!(reach_tlvs > 4);
+}
@@ -0,0 +1,112 @@
+mod common;
+
+use bytes::BytesMut;
+use hex_literal::hex;
+use isis_packet::neigh::IsisSubTlv as NeighSubTlv;
+use isis_packet::*;
+
+use common::{lan_hello, sys_id};
+
+fn hello(source: u8, dis: u8, tlv: IsisTlvReverseMetric) -> IsisHello {
+    lan_hello(source, dis, vec![tlv.into()])
+}
+
+fn reverse(w_flag: bool, u_flag: bool, metric: u32) -> IsisTlvReverseMetric {
+    IsisTlvReverseMetric {
+        flags: ReverseMetricFlags::new()
+            .with_w_flag(w_flag)
+            .with_u_flag(u_flag),
+        metric,
+        subs: vec![],
+    }
+}
+
+#[test]
+pub fn reverse_metric_parse_emit() {
+    // W and U flags, metric 1000 and TE default metric 2000.
+    let bytes = hex!("10 0a 03 00 03 e8 05 12 03 00 07 d0");
+    let (rest, tlv) = IsisTlv::parse_tlv(&bytes).unwrap();
+    assert!(rest.is_empty());
+    let IsisTlv::ReverseMetric(v) = &tlv else {
+        panic!("not a Reverse Metric: {tlv:?}");
+    };
+    assert!(v.flags.w_flag() && v.flags.u_flag());
+    assert_eq!(v.metric, 1000);
+    assert_eq!(v.te_metric(), Some(2000));
+    assert_eq!(
+        v.subs,
+        [NeighSubTlv::WideMetric(IsisSubWideMetric { metric: 2000 })]
+    );
+    let mut buf = BytesMut::new();
+    tlv.emit(&mut buf);
+    assert_eq!(&buf[..], &bytes[..]);
+
+    // Carried in an IIH.
+    let packet = IsisPacket::from(IsisType::L1Hello, IsisPdu::L1Hello(hello(1, 1, v.clone())));
+    let mut buf = BytesMut::new();
+    packet.emit(&mut buf);
+    let (_, packet) = parse(&buf).unwrap();
+    let IsisPdu::L1Hello(hello) = packet.pdu else {
+        panic!("not a hello");
+    };
+    assert_eq!(hello.reverse_metric_tlv(), Some(v));
+}
+
+#[test]
+pub fn reverse_metric_apply() {
+    let tlv = reverse(false, false, 100);
+    assert_eq!(tlv.apply(10), 110);
+    assert_eq!(tlv.apply_te(10), 10);
+    // Capped at the maximum link metric, or excluded with the U flag.
+    assert_eq!(
+        reverse(false, false, MAX_LINK_METRIC).apply(10),
+        MAX_LINK_METRIC
+    );
+    assert_eq!(
+        reverse(false, true, MAX_LINK_METRIC).apply(10),
+        LINK_METRIC_EXCLUDED
+    );
+    let tlv = IsisTlvReverseMetric {
+        subs: vec![NeighSubTlv::WideMetric(IsisSubWideMetric { metric: 5 })],
+        ..tlv
+    };
+    assert_eq!(tlv.apply_te(10), 15);
+}
+
+#[test]
+pub fn reverse_metric_lan() {
+    // Whole LAN request from the DIS.
+    assert_eq!(
+        hello(1, 1, reverse(true, false, 100)).reverse_metric(10),
+        110
+    );
+    // Only the DIS changes the metric toward the pseudonode.
+    assert_eq!(
+        hello(2, 1, reverse(true, false, 100)).reverse_metric(10),
+        10
+    );
+    assert_eq!(
+        hello(1, 1, reverse(false, false, 100)).reverse_metric(10),
+        10
+    );
+}
+
+#[test]
+pub fn reverse_metric_p2p() {
+    let p2p = |tlvs: Vec<IsisTlv>| IsisP2pHello {
+        circuit_type: IsLevel::L1,
+        source_id: sys_id(2),
+        hold_time: 30,
+        pdu_len: 0,
+        circuit_id: 1,
+        tlvs,
+    };
+    // Applied without the W flag on a point-to-point circuit.
+    let hello = p2p(vec![reverse(false, false, 100).into()]);
+    assert_eq!(hello.reverse_metric(10), 110);
+    let hello = p2p(vec![reverse(true, false, 100).into()]);
+    assert_eq!(hello.reverse_metric(10), 110);
+    let hello = p2p(vec![reverse(false, true, MAX_LINK_METRIC).into()]);
+    assert_eq!(hello.reverse_metric(10), LINK_METRIC_EXCLUDED);
+    assert_eq!(p2p(vec![]).reverse_metric(10), 10);
+}
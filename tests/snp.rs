@@ -0,0 +1,95 @@
+use bytes::BytesMut;
+use isis_packet::*;
+
+fn entry(index: u16) -> IsisLspEntry {
+    let [hi, lo] = index.to_be_bytes();
+    IsisLspEntry {
+        hold_time: 1200,
+        lsp_id: IsisLspId::new(
+            IsisSysId {
+                id: [0, 0, 0, 0, hi, lo],
+            },
+            0,
+            0,
+        ),
+        seq_number: 1,
+        checksum: 0,
+    }
+}
+
+fn emit(pdu_type: IsisType, pdu: IsisPdu) -> BytesMut {
+    let packet = IsisPacket::from(pdu_type, pdu);
+    let mut buf = BytesMut::new();
+    packet.emit(&mut buf);
+    buf
+}
+
+#[test]
+pub fn lsp_entries_len() {
+    let tlv = IsisTlvLspEntries {
+        entries: (0..15).map(entry).collect(),
+    };
+    let mut buf = BytesMut::new();
+    IsisTlv::LspEntries(tlv).emit(&mut buf);
+    assert_eq!(buf.len(), 2 + 15 * 16);
+    assert_eq!(buf[1], 15 * 16);
+}
+
+#[test]
+pub fn csnp_build_tiling() {
+    let source_id = IsisSysId::default();
+
+    // Empty database still describes the whole range.
+    let csnps = IsisCsnp::build(&source_id, Vec::new(), 1500);
+    assert_eq!(csnps.len(), 1);
+    assert_eq!(csnps[0].start, IsisLspId::start());
+    assert_eq!(csnps[0].end, IsisLspId::end());
+
+    for mtu in [512, 1500, 9000] {
+        let csnps = IsisCsnp::build(&source_id, (0..1000).map(entry), mtu);
+        assert!(csnps.len() > 1);
+
+        let mut next = IsisLspId::start();
+        let mut count = 0;
+        for csnp in csnps.iter() {
+            assert_eq!(csnp.start, next);
+            assert!(
+                csnp.entries()
+                    .all(|e| csnp.start <= e.lsp_id && e.lsp_id <= csnp.end)
+            );
+            count += csnp.entries().count();
+
+            let buf = emit(IsisType::L2Csnp, IsisPdu::L2Csnp(csnp.clone()));
+            assert!(buf.len() + 3 <= mtu);
+            let (_, packet) = parse(&buf).unwrap();
+            let IsisPdu::L2Csnp(parsed) = packet.pdu else {
+                panic!("CSNP expected");
+            };
+            assert_eq!(parsed.entries().count(), csnp.entries().count());
+            next = csnp.end.successor();
+        }
+        assert_eq!(csnps.last().unwrap().end, IsisLspId::end());
+        assert_eq!(count, 1000);
+    }
+}
+
+#[test]
+pub fn psnp_build_batch() {
+    let source_id = IsisSysId::default();
+    let psnps = IsisPsnp::build(&source_id, (0..200).map(entry), 1500);
+    // (1500 - 3 - 17) = 6 full TLVs of 242 bytes and one more entry.
+    assert_eq!(psnps.len(), 3);
+    assert_eq!(psnps[0].entries().count(), 91);
+    assert_eq!(
+        psnps
+            .iter()
+            .map(|psnp| psnp.entries().count())
+            .sum::<usize>(),
+        200
+    );
+
+    for psnp in psnps {
+        let buf = emit(IsisType::L1Psnp, IsisPdu::L1Psnp(psnp));
+        assert!(buf.len() + 3 <= 1500);
+    }
+}
@@ -0,0 +1,285 @@
+mod common;
+
+use std::net::Ipv6Addr;
+
+use isis_packet::neigh::{IsisSubAdjSid, IsisSubTlv as NeighSubTlv};
+use isis_packet::prefix::{IsisSubSrv6EndSid, IsisSubTlv as PrefixSubTlv, Srv6Locator};
+use isis_packet::*;
+
+use common::{lsp, node, sys_id};
+
+fn end_x(from: u8, to: u8) -> Ipv6Addr {
+    format!("2001:db8:{from}::{to}").parse().unwrap()
+}
+
+// Router with SRGB `base`, node SID index `id`, End SID 2001:db8:<id>:: and
+// links carrying Adj-SID 15000 + 10 * from + to and a matching End.X SID.
+fn router(id: u8, base: u32, links: &[(u8, u32)]) -> IsisLsp {
+    let entries = links
+        .iter()
+        .map(|&(to, metric)| IsisTlvExtIsReachEntry {
+            neighbor_id: node(to),
+            metric,
+            subs: vec![
+                NeighSubTlv::AdjSid(IsisSubAdjSid {
+                    flags: 0x30.into(),
+                    weight: 0,
+                    sid: SidLabelValue::Label(15000 + 10 * id as u32 + to as u32),
+                }),
+                NeighSubTlv::Srv6EndXSid(IsisSubSrv6EndXSid {
+                    flags: 0,
+                    algo: Algo::Spf,
+                    weight: 0,
+                    behavior: Behavior::EndX,
+                    sid: end_x(id, to),
+                    sub2s: vec![],
+                }),
+            ],
+        })
+        .collect();
+    let prefix_sid = IsisSubPrefixSid {
+        flags: PrefixSidFlags::new().with_n_flag(true),
+        algo: Algo::Spf,
+        sid: SidLabelValue::Index(id as u32),
+    };
+    let loopback = IsisTlvExtIpReachEntry {
+        metric: 0,
+        flags: 0x60.into(),
+        prefix: format!("10.0.0.{id}/32").parse().unwrap(),
+        subs: vec![PrefixSubTlv::PrefixSid(prefix_sid)],
+    };
+    let locator = Srv6Locator {
+        metric: 0,
+        flags: 0,
+        algo: Algo::Spf,
+        locator: format!("2001:db8:{id}::/48").parse().unwrap(),
+        subs: vec![PrefixSubTlv::Srv6EndSid(IsisSubSrv6EndSid {
+            flags: 0,
+            behavior: Behavior::End,
+            sid: format!("2001:db8:{id}::").parse().unwrap(),
+            sub2s: vec![],
+        })],
+    };
+    let cap = IsisSubSegmentRoutingCap {
+        flags: 0xc0.into(),
+        range: 8000,
+        sid_label: SidLabelTlv::Label(base),
+    };
+    lsp(
+        id,
+        vec![
+            IsisTlv::RouterCap(IsisTlvRouterCap {
+                router_id: format!("10.0.0.{id}").parse().unwrap(),
+                flags: 0.into(),
+                subs: vec![cap::IsisSubTlv::SegmentRoutingCap(cap)],
+            }),
+            IsisTlv::ExtIsReach(IsisTlvExtIsReach { entries }),
+            IsisTlv::ExtIpReach(IsisTlvExtIpReach {
+                entries: vec![loopback],
+            }),
+            IsisTlv::Srv6(prefix::IsisTlvSrv6 {
+                locators: vec![locator],
+                ..Default::default()
+            }),
+        ],
+    )
+}
+
+// 1 - 2 - 4 is the primary path, 1 - 3 - 5 - 4 the backup with an
+// expensive 5 - 4 link. Node 3 has its own SRGB.
+fn graph() -> IsisSpfGraph {
+    let lsdb: IsisLsdb = [
+        router(1, 16000, &[(2, 1), (3, 1)]),
+        router(2, 16000, &[(1, 1), (4, 1)]),
+        router(3, 20000, &[(1, 1), (5, 1)]),
+        router(4, 16000, &[(2, 1), (5, 10)]),
+        router(5, 16000, &[(3, 1), (4, 10)]),
+    ]
+    .into_iter()
+    .collect();
+    IsisSpfGraph::new(&lsdb)
+}
+
+#[test]
+pub fn spf_distance_nexthops() {
+    let graph = graph();
+    let tree = graph.spf(&node(1));
+    assert_eq!(tree.distance(&node(4)), Some(2));
+    assert_eq!(tree.distance(&node(5)), Some(2));
+    assert_eq!(tree.path(&node(4)).unwrap(), [node(1), node(2), node(4)]);
+    assert_eq!(
+        tree.nexthops(&node(5)).into_iter().collect::<Vec<_>>(),
+        [sys_id(3)]
+    );
+
+    // Links without the reverse direction fail the two-way check.
+    let mut lsdb: IsisLsdb = [router(1, 16000, &[(2, 1)]), router(2, 16000, &[])]
+        .into_iter()
+        .collect();
+    assert_eq!(
+        IsisSpfGraph::new(&lsdb).spf(&node(1)).distance(&node(2)),
+        None
+    );
+    lsdb.insert(router(2, 16000, &[(1, 1)]));
+    assert_eq!(
+        IsisSpfGraph::new(&lsdb).spf(&node(1)).distance(&node(2)),
+        Some(1)
+    );
+}
+
+#[test]
+pub fn spf_lan_pseudonode() {
+    // 1 and 2 on the LAN of pseudonode 1.01.
+    let lan = IsisNeighborId::from_sys_id(&sys_id(1), 1);
+    let mut r1 = router(1, 16000, &[]);
+    let mut r2 = router(2, 16000, &[]);
+    for lsp in [&mut r1, &mut r2] {
+        lsp.tlvs.push(IsisTlv::ExtIsReach(IsisTlvExtIsReach {
+            entries: vec![IsisTlvExtIsReachEntry {
+                neighbor_id: lan.clone(),
+                metric: 10,
+                subs: vec![],
+            }],
+        }));
+    }
+    let mut pn = router(1, 16000, &[(1, 0), (2, 0)]);
+    pn.lsp_id = IsisLspId::from_neighbor_id(lan.clone(), 0);
+    let lsdb: IsisLsdb = [r1, r2, pn].into_iter().collect();
+    let tree = IsisSpfGraph::new(&lsdb).spf(&node(1));
+    assert_eq!(tree.distance(&node(2)), Some(10));
+    assert_eq!(tree.path(&node(2)).unwrap(), [node(1), lan, node(2)]);
+    assert_eq!(
+        tree.nexthops(&node(2)).into_iter().collect::<Vec<_>>(),
+        [sys_id(2)]
+    );
+}
+
+fn repair(repairs: &[IsisTiLfaRepair], dest: u8) -> &IsisTiLfaRepair {
+    repairs
+        .iter()
+        .find(|r| r.destination == sys_id(dest))
+        .unwrap()
+}
+
+#[test]
+pub fn ti_lfa_link_mpls() {
+    let repairs = graph().ti_lfa(&sys_id(1), IsisProtection::Link, IsisDataplane::Mpls);
+    let r4 = repair(&repairs, 4);
+    assert_eq!(r4.primary, sys_id(2));
+    assert_eq!(r4.path, [node(1), node(3), node(5), node(4)]);
+    assert_eq!(r4.nexthop, Some(sys_id(3)));
+    assert!(r4.p_space.contains(&sys_id(5)));
+    assert!(!r4.p_space.contains(&sys_id(4)));
+    assert!(!r4.q_space.contains(&sys_id(5)));
+    assert_eq!(r4.p_node, Some(sys_id(5)));
+    assert_eq!(r4.q_node, Some(sys_id(4)));
+    // Node SID of 5 in the SRGB of next hop 3, then the Adj-SID of 5 to 4.
+    assert_eq!(
+        r4.segments.as_deref().unwrap(),
+        [
+            IsisSegment::Node {
+                node: sys_id(5),
+                label: 20005
+            },
+            IsisSegment::Adj {
+                from: sys_id(5),
+                to: sys_id(4),
+                label: 15054
+            },
+        ]
+    );
+
+    // Neighbor 3 reaches 2 over the failed link, so 2 needs the same
+    // P and Q nodes as 4.
+    let r2 = repair(&repairs, 2);
+    assert_eq!(r2.protection, IsisProtection::Link);
+    assert_eq!(r2.path, [node(1), node(3), node(5), node(4), node(2)]);
+    assert_eq!(r2.segments, r4.segments);
+
+    assert!(repairs.iter().all(|r| r.is_protected()));
+}
+
+#[test]
+pub fn ti_lfa_node_srv6() {
+    let repairs = graph().ti_lfa(&sys_id(1), IsisProtection::Node, IsisDataplane::Srv6);
+    let r4 = repair(&repairs, 4);
+    assert_eq!(r4.protection, IsisProtection::Node);
+    assert_eq!(
+        r4.segments.as_deref().unwrap(),
+        [
+            IsisSegment::End {
+                node: sys_id(5),
+                sid: "2001:db8:5::".parse().unwrap()
+            },
+            IsisSegment::EndX {
+                from: sys_id(5),
+                to: sys_id(4),
+                sid: end_x(5, 4)
+            },
+        ]
+    );
+
+    // The primary next hop itself falls back to link protection.
+    assert_eq!(repair(&repairs, 2).protection, IsisProtection::Link);
+}
+
+// Graph with the Adj-SIDs of node 5 advertised as indexes into an SRLB
+// starting at `srlb`, or without any SRLB.
+fn graph_adj_index(srlb: Option<u32>) -> IsisSpfGraph {
+    let mut r5 = router(5, 16000, &[(3, 1), (4, 10)]);
+    for tlv in r5.tlvs.iter_mut() {
+        match tlv {
+            IsisTlv::RouterCap(cap) => {
+                if let Some(base) = srlb {
+                    cap.subs
+                        .push(cap::IsisSubTlv::SegmentRoutingLB(IsisSubSegmentRoutingLB {
+                            flags: 0,
+                            range: 1000,
+                            sid_label: SidLabelTlv::Label(base),
+                        }));
+                }
+            }
+            IsisTlv::ExtIsReach(reach) => {
+                for entry in reach.entries.iter_mut() {
+                    for sub in entry.subs.iter_mut() {
+                        if let NeighSubTlv::AdjSid(adj) = sub {
+                            adj.sid = SidLabelValue::Index(50 + entry.neighbor_id.id[5] as u32);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    let lsdb: IsisLsdb = [
+        router(1, 16000, &[(2, 1), (3, 1)]),
+        router(2, 16000, &[(1, 1), (4, 1)]),
+        router(3, 20000, &[(1, 1), (5, 1)]),
+        router(4, 16000, &[(2, 1), (5, 10)]),
+        r5,
+    ]
+    .into_iter()
+    .collect();
+    IsisSpfGraph::new(&lsdb)
+}
+
+#[test]
+pub fn ti_lfa_adj_sid_index() {
+    // The index is resolved in the SRLB of 5, not in its SRGB.
+    let repairs =
+        graph_adj_index(Some(15000)).ti_lfa(&sys_id(1), IsisProtection::Link, IsisDataplane::Mpls);
+    let r4 = repair(&repairs, 4);
+    assert_eq!(
+        r4.segments.as_deref().unwrap()[1],
+        IsisSegment::Adj {
+            from: sys_id(5),
+            to: sys_id(4),
+            label: 15054
+        }
+    );
+
+    // Without an SRLB the index has no label.
+    let repairs =
+        graph_adj_index(None).ti_lfa(&sys_id(1), IsisProtection::Link, IsisDataplane::Mpls);
+    assert!(repair(&repairs, 4).segments.is_none());
+}
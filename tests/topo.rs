@@ -0,0 +1,108 @@
+mod common;
+
+use isis_packet::neigh::{IsisSubAdjSid, IsisSubTlv};
+use isis_packet::*;
+
+use common::sys_id;
+
+fn lsp(lsp_id: IsisLspId, hostname: Option<&str>, neighbors: &[(IsisNeighborId, u32)]) -> IsisLsp {
+    let mut tlvs = Vec::new();
+    if let Some(hostname) = hostname {
+        tlvs.push(IsisTlv::Hostname(IsisTlvHostname {
+            hostname: hostname.into(),
+        }));
+    }
+    let entries = neighbors
+        .iter()
+        .map(|(neighbor_id, metric)| IsisTlvExtIsReachEntry {
+            neighbor_id: neighbor_id.clone(),
+            metric: *metric,
+            subs: vec![],
+        })
+        .collect();
+    tlvs.push(IsisTlv::ExtIsReach(IsisTlvExtIsReach { entries }));
+    IsisLsp {
+        pdu_len: 0,
+        hold_time: 1200,
+        lsp_id,
+        seq_number: 1,
+        checksum: 0,
+        types: IsisLspTypes::from(2),
+        tlvs,
+    }
+}
+
+// r1 and r2 on a LAN with r1 as DIS, r2 to r3 point-to-point with an
+// Adj-SID.
+fn lsdb() -> IsisLsdb {
+    let lan = IsisNeighborId::from_sys_id(&sys_id(1), 1);
+    let r2 = IsisNeighborId::from_sys_id(&sys_id(2), 0);
+    let r3 = IsisNeighborId::from_sys_id(&sys_id(3), 0);
+    let mut r2_lsp = lsp(
+        IsisLspId::new(sys_id(2), 0, 0),
+        Some("r2"),
+        &[(lan.clone(), 10), (r3, 20)],
+    );
+    if let IsisTlv::ExtIsReach(tlv) = &mut r2_lsp.tlvs[1] {
+        tlv.entries[1].subs.push(IsisSubTlv::AdjSid(IsisSubAdjSid {
+            flags: 0x30.into(),
+            weight: 0,
+            sid: SidLabelValue::Label(15001),
+        }));
+    }
+    [
+        lsp(
+            IsisLspId::new(sys_id(1), 0, 0),
+            Some("r1"),
+            &[(lan.clone(), 10)],
+        ),
+        lsp(
+            IsisLspId::new(sys_id(1), 1, 0),
+            None,
+            &[
+                (IsisNeighborId::from_sys_id(&sys_id(1), 0), 0),
+                (r2.clone(), 0),
+            ],
+        ),
+        r2_lsp,
+    ]
+    .into_iter()
+    .collect()
+}
+
+#[test]
+pub fn topology_nodes_edges() {
+    let topo = IsisTopology::new(&lsdb());
+    let labels: Vec<_> = topo.nodes.iter().map(|n| n.label.as_str()).collect();
+    assert_eq!(labels, ["r1", "r1.01", "r2", "0000.0000.0003"]);
+    assert!(topo.nodes[1].pseudonode);
+    assert_eq!(topo.nodes[1].id, "0000.0000.0001.01");
+    assert_eq!(topo.edges.len(), 5);
+
+    let edge = topo
+        .edges
+        .iter()
+        .find(|e| e.target == "0000.0000.0003")
+        .unwrap();
+    assert_eq!(edge.source, "0000.0000.0002");
+    assert_eq!(edge.metric, 20);
+    assert_eq!(edge.adj_sids, [15001]);
+
+    let json = serde_json::to_value(&topo).unwrap();
+    assert_eq!(json["nodes"][1]["pseudonode"], true);
+    assert_eq!(json["edges"][4]["adj-sids"][0], 15001);
+}
+
+#[test]
+pub fn topology_dot() {
+    let dot = IsisTopology::new(&lsdb()).dot();
+    assert!(dot.starts_with("digraph isis {\n"));
+    assert!(dot.contains("  \"0000.0000.0001.01\" [label=\"r1.01\", shape=box];\n"));
+    assert!(dot.contains("  \"0000.0000.0001\" [label=\"r1\", shape=ellipse];\n"));
+    assert!(
+        dot.contains(
+            "  \"0000.0000.0002\" -> \"0000.0000.0003\" [label=\"20\\nadj-sid 15001\"];\n"
+        )
+    );
+    assert!(dot.ends_with("}\n"));
+}
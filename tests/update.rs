@@ -0,0 +1,178 @@
+use bytes::BytesMut;
+use isis_packet::*;
+
+fn sys_id(last: u8) -> IsisSysId {
+    IsisSysId {
+        id: [0, 0, 0, 0, 0, last],
+    }
+}
+
+fn lsp(last: u8, fragment_id: u8, seq_number: u32) -> IsisLsp {
+    IsisLsp {
+        pdu_len: 0,
+        hold_time: 1200,
+        lsp_id: IsisLspId::new(sys_id(last), 0, fragment_id),
+        seq_number,
+        checksum: 0,
+        types: IsisLspTypes::from(2),
+        tlvs: Vec::new(),
+    }
+}
+
+fn psnp_entries(packets: &[IsisPacket]) -> Vec<IsisLspEntry> {
+    let mut entries = Vec::new();
+    for packet in packets {
+        if let IsisPdu::L2Psnp(psnp) = &packet.pdu {
+            for tlv in psnp.tlvs.iter() {
+                if let IsisTlv::LspEntries(tlv) = tlv {
+                    entries.extend(tlv.entries.iter().cloned());
+                }
+            }
+        }
+    }
+    entries
+}
+
+#[test]
+pub fn update_flooding() {
+    let mut update = IsisUpdate::new(IsLevel::L2, sys_id(1));
+    update.circuit_add(1, IsisCircuitType::Broadcast, 1500);
+    update.circuit_add(2, IsisCircuitType::P2p, 1500);
+
+    // LSP from the LAN is flooded to the P2P circuit only.
+    update.lsp_recv(1, lsp(2, 0, 1));
+    assert!(update.send(1).is_empty());
+    let packets = update.send(2);
+    assert_eq!(packets.len(), 1);
+    assert!(matches!(packets[0].pdu, IsisPdu::L2Lsp(_)));
+
+    // P2P keeps retransmitting until acknowledged by PSNP.
+    assert_eq!(update.send(2).len(), 1);
+    let ack = IsisPsnp {
+        pdu_len: 0,
+        source_id: sys_id(3),
+        source_id_curcuit: 0,
+        tlvs: vec![IsisTlv::LspEntries(IsisTlvLspEntries {
+            entries: vec![lsp(2, 0, 1).entry()],
+        })],
+    };
+    update.psnp_recv(2, &ack);
+    assert!(update.send(2).is_empty());
+
+    // LSP received on P2P is acknowledged with a PSNP.
+    update.lsp_recv(2, lsp(3, 0, 7));
+    let packets = update.send(2);
+    assert_eq!(psnp_entries(&packets), vec![lsp(3, 0, 7).entry()]);
+    assert_eq!(update.send(1).len(), 1);
+}
+
+#[test]
+pub fn update_csnp_recv() {
+    let mut update = IsisUpdate::new(IsLevel::L2, sys_id(1));
+    update.circuit_add(1, IsisCircuitType::Broadcast, 1500);
+    update.lsp_originate(lsp(1, 0, 3));
+    update.lsp_recv(1, lsp(2, 0, 5));
+    update.send(1);
+
+    let csnp = IsisCsnp {
+        pdu_len: 0,
+        source_id: sys_id(2),
+        source_id_circuit: 0,
+        start: IsisLspId::start(),
+        end: IsisLspId::end(),
+        tlvs: vec![IsisTlv::LspEntries(IsisTlvLspEntries {
+            entries: vec![lsp(2, 0, 6).entry(), lsp(4, 0, 1).entry()],
+        })],
+    };
+    update.csnp_recv(1, &csnp);
+
+    let packets = update.send(1);
+    // Our own LSP is missing from the CSNP, send it.
+    assert!(packets.iter().any(|packet| match &packet.pdu {
+        IsisPdu::L2Lsp(lsp) => lsp.lsp_id.sys_id() == sys_id(1),
+        _ => false,
+    }));
+    // Newer and unknown LSPs are requested.
+    let requests = psnp_entries(&packets);
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].lsp_id, lsp(2, 0, 0).lsp_id);
+    assert_eq!(requests[0].seq_number, 5);
+    assert_eq!(requests[1].lsp_id, lsp(4, 0, 0).lsp_id);
+    assert_eq!(requests[1].seq_number, 0);
+}
+
+#[test]
+pub fn update_csnp_split() {
+    let mut update = IsisUpdate::new(IsLevel::L2, sys_id(1));
+    update.circuit_add(1, IsisCircuitType::Broadcast, 1500);
+    for last in 1..=50 {
+        update.lsp_recv(1, lsp(last, 0, 1));
+        update.lsp_recv(1, lsp(last, 1, 1));
+    }
+
+    let packets = update.csnp(1);
+    assert_eq!(packets.len(), 2);
+
+    let mut next = IsisLspId::start();
+    let mut count = 0;
+    for packet in packets.iter() {
+        let mut buf = BytesMut::new();
+        packet.emit(&mut buf);
+        assert!(buf.len() + 3 <= 1500);
+
+        let (_, parsed) = parse(&buf).unwrap();
+        let IsisPdu::L2Csnp(csnp) = parsed.pdu else {
+            panic!("CSNP expected");
+        };
+        assert_eq!(csnp.start, next);
+        for tlv in csnp.tlvs.iter() {
+            if let IsisTlv::LspEntries(tlv) = tlv {
+                count += tlv.entries.len();
+            }
+        }
+        let end = u64::from_be_bytes(csnp.end.id);
+        next = IsisLspId {
+            id: end.wrapping_add(1).to_be_bytes(),
+        };
+    }
+    assert_eq!(next, IsisLspId::start());
+    assert_eq!(count, 100);
+}
+
+#[test]
+pub fn update_own_lsp_checksum() {
+    let mut update = IsisUpdate::new(IsLevel::L2, sys_id(1));
+    update.circuit_add(1, IsisCircuitType::P2p, 1500);
+    update.lsp_originate(lsp(1, 0, 5));
+    update.send(1);
+
+    // Same sequence number with different contents is out-numbered.
+    let mut stale = lsp(1, 0, 5);
+    stale.checksum = 0x1234;
+    update.lsp_recv(1, stale);
+    let own = update.lsdb.get(&lsp(1, 0, 0).lsp_id).unwrap();
+    assert_eq!(own.seq_number, 6);
+    assert_eq!(own.checksum, 0);
+    assert_eq!(update.send(1).len(), 1);
+}
+
+#[test]
+pub fn update_own_lsp_seq_exhausted() {
+    let mut update = IsisUpdate::new(IsLevel::L2, sys_id(1));
+    update.circuit_add(1, IsisCircuitType::P2p, 1500);
+    update.lsp_originate(lsp(1, 0, 5));
+    update.send(1);
+
+    // No wrap to 0, the LSP waits for SEQ_NUMBER_WAIT instead.
+    let lsp_id = lsp(1, 0, 0).lsp_id;
+    update.lsp_recv(1, lsp(1, 0, u32::MAX));
+    assert!(update.seq_exhausted.contains(&lsp_id));
+    assert_eq!(update.lsdb.get(&lsp_id).unwrap().seq_number, 5);
+    update.lsp_originate(lsp(1, 0, 6));
+    assert_eq!(update.lsdb.get(&lsp_id).unwrap().seq_number, 5);
+
+    update.seq_number_restart(lsp(1, 0, 0));
+    assert!(update.seq_exhausted.is_empty());
+    assert_eq!(update.lsdb.get(&lsp_id).unwrap().seq_number, 1);
+    assert_eq!(update.send(1).len(), 1);
+}
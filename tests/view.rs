@@ -0,0 +1,149 @@
+mod common;
+
+use hex_literal::hex;
+use isis_packet::*;
+
+use common::L2_LSP;
+
+const CSNP: &[u8] = &hex!(
+    "
+        01 80 c2 00 00 14 00 1c 42 e8 0c 23 00 86 fe fe
+        03 83 21 01 00 18 01 00 00 00 83 00 00 00 00 00
+        01 00 00 00 00 00 00 00 00 00 ff ff ff ff ff ff
+        ff ff 09 60 04 81 00 00 00 00 00 01 00 00 00 00
+        00 32 2d 9d 04 97 00 00 00 00 00 01 03 00 00 00
+        00 01 95 34 04 62 00 00 00 00 00 01 04 00 00 00
+        00 22 38 70 04 8e 00 00 00 00 00 02 00 00 00 00
+        00 29 b5 9d 04 9e 00 00 00 00 00 03 00 00 00 00
+        00 01 45 70 04 a9 00 00 00 00 00 03 00 01 00 00
+        00 02 1d 26
+        "
+);
+
+#[test]
+pub fn view_lsp_tlvs() {
+    let (_, owned) = parse(L2_LSP).unwrap();
+    let IsisPdu::L2Lsp(lsp) = &owned.pdu else {
+        panic!("not L2 L2_LSP");
+    };
+
+    let packet = IsisPacketRef::parse(L2_LSP).unwrap();
+    assert_eq!(packet.pdu_type(), IsisType::L2Lsp);
+    let IsisPduRef::L2Lsp(view) = packet.pdu() else {
+        panic!("not L2 L2_LSP");
+    };
+    assert_eq!(view.lsp_id(), lsp.lsp_id);
+    assert_eq!(view.seq_number(), lsp.seq_number);
+    assert_eq!(view.checksum(), lsp.checksum);
+    assert_eq!(view.hostname().as_deref(), Some("ubuntu1"));
+
+    let tlvs: Vec<IsisTlvRef> = view.tlvs().collect::<Result<_, _>>().unwrap();
+    assert_eq!(tlvs.len(), lsp.tlvs.len());
+    for (tlv, owned) in tlvs.iter().zip(lsp.tlvs.iter()) {
+        assert_eq!(&tlv.into_owned().unwrap(), owned);
+    }
+
+    for (tlv, owned) in tlvs.iter().zip(lsp.tlvs.iter()) {
+        match (tlv.view().unwrap(), owned) {
+            (IsisTlvView::ExtIpReach(entries), IsisTlv::ExtIpReach(owned)) => {
+                let prefixes: Vec<_> = entries
+                    .map(|entry| {
+                        let subs: Vec<_> = entry.subs.map(|sub| sub.prefix().unwrap()).collect();
+                        (entry.prefix, entry.metric, subs)
+                    })
+                    .collect();
+                let expected: Vec<_> = owned
+                    .entries
+                    .iter()
+                    .map(|entry| (entry.prefix, entry.metric, entry.subs.clone()))
+                    .collect();
+                assert_eq!(prefixes, expected);
+            }
+            (IsisTlvView::ExtIsReach(entries), IsisTlv::ExtIsReach(owned)) => {
+                let neighbors: Vec<_> = entries
+                    .map(|entry| {
+                        let subs: Vec<_> = entry.subs.map(|sub| sub.neigh().unwrap()).collect();
+                        (entry.neighbor_id, subs)
+                    })
+                    .collect();
+                let expected: Vec<_> = owned
+                    .entries
+                    .iter()
+                    .map(|entry| (entry.neighbor_id.clone(), entry.subs.clone()))
+                    .collect();
+                assert_eq!(neighbors, expected);
+            }
+            (IsisTlvView::Srv6 { locators, .. }, IsisTlv::Srv6(owned)) => {
+                let locators: Vec<_> = locators.map(|locator| locator.locator).collect();
+                let expected: Vec<_> = owned.locators.iter().map(|loc| loc.locator).collect();
+                assert_eq!(locators, expected);
+            }
+            (
+                IsisTlvView::RouterCap {
+                    router_id, subs, ..
+                },
+                IsisTlv::RouterCap(owned),
+            ) => {
+                assert_eq!(router_id, owned.router_id);
+                let subs: Vec<_> = subs.map(|sub| sub.cap().unwrap()).collect();
+                assert_eq!(subs, owned.subs);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[test]
+pub fn view_into_owned() {
+    let packet = IsisPacketRef::parse(L2_LSP).unwrap();
+    let owned = packet.into_owned().unwrap();
+    assert_eq!(
+        format!("{}", owned),
+        format!("{}", parse(L2_LSP).unwrap().1)
+    );
+}
+
+#[test]
+pub fn view_csnp_entries() {
+    let frame = &CSNP[17..];
+    let (_, owned) = parse(frame).unwrap();
+    let IsisPdu::L1Csnp(csnp) = &owned.pdu else {
+        panic!("not L1 CSNP");
+    };
+    let IsisPduRef::L1Csnp(view) = IsisPacketRef::parse(frame).unwrap().pdu() else {
+        panic!("not L1 CSNP");
+    };
+    assert_eq!(view.start(), csnp.start);
+    assert_eq!(view.end(), csnp.end);
+    let entries: Vec<IsisLspEntry> = view.entries().collect();
+    let expected: Vec<IsisLspEntry> = csnp.entries().cloned().collect();
+    assert_eq!(entries, expected);
+}
+
+#[test]
+pub fn view_bounds() {
+    // Frame padding after the PDU is not part of the TLVs.
+    let mut padded = L2_LSP.to_vec();
+    padded.extend_from_slice(&[0u8; 8]);
+    let packet = IsisPacketRef::parse(&padded).unwrap();
+    assert_eq!(packet.raw().len(), L2_LSP.len());
+
+    // Truncated PDU.
+    assert!(matches!(
+        IsisPacketRef::parse(&L2_LSP[..L2_LSP.len() - 1]),
+        Err(IsisParseError::IncompleteData { needed: 1 })
+    ));
+
+    // TLV length beyond the PDU.
+    let tlvs = [0x89, 0x07, b'u', b'b'];
+    let mut iter = IsisTlvIter::new(&tlvs);
+    assert!(matches!(
+        iter.next(),
+        Some(Err(IsisParseError::InvalidTlvLength {
+            expected: 7,
+            found: 2,
+            ..
+        }))
+    ));
+    assert!(iter.next().is_none());
+}
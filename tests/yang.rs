@@ -0,0 +1,97 @@
+mod common;
+
+use hex_literal::hex;
+use isis_packet::*;
+
+use common::{L1_LSP, parse_lsp};
+
+fn lsp() -> IsisLsp {
+    parse_lsp(L1_LSP)
+}
+
+#[test]
+pub fn yang_lsp() {
+    let yang = lsp().yang();
+    assert_eq!(yang["lsp-id"], "0000.0000.0001.00-00");
+    assert_eq!(yang["remaining-lifetime"], 1167);
+    assert_eq!(yang["sequence"], 0x9a);
+    assert_eq!(yang["dynamic-hostname"], "ubuntu1");
+    assert_eq!(yang["ipv4-te-routerid"], "1.1.1.1");
+    assert_eq!(yang["protocol-supported"][0], 0xcc);
+
+    let cap = &yang["router-capabilities"]["router-capability"][0];
+    let sr = &cap["ietf-isis-sr-mpls:sr-capability"];
+    assert_eq!(
+        sr["sr-capability"]["sr-capability-flags"][0],
+        "ietf-isis-sr-mpls:sr-capability-flag-ipv4"
+    );
+    assert_eq!(sr["global-blocks"]["global-block"][0]["range-size"], 8000);
+    assert_eq!(
+        sr["global-blocks"]["global-block"][0]["sid-sub-tlv"]["sid"],
+        16000
+    );
+    assert_eq!(
+        cap["ietf-isis-sr-mpls:sr-algorithms"]["sr-algorithm"][0],
+        "ietf-segment-routing-common:prefix-sid-algorithm-shortest-path"
+    );
+
+    let neighbor = &yang["extended-is-neighbor"]["neighbor"][0];
+    assert_eq!(neighbor["neighbor-id"], "0000.0000.0001.03");
+    let instance = &neighbor["instances"]["instance"][0];
+    assert_eq!(instance["metric"], 10);
+    assert_eq!(
+        instance["local-if-ipv4-addrs"]["local-if-ipv4-addr"][0],
+        "11.0.0.1"
+    );
+    let adj_sid = &instance["ietf-isis-sr-mpls:adj-sid-sub-tlvs"]["adj-sid-sub-tlv"][0];
+    assert_eq!(adj_sid["neighbor-id"], "0000.0000.0002");
+    assert_eq!(adj_sid["sid"], 15000);
+
+    let prefix = &yang["extended-ipv4-reachability"]["prefixes"][0];
+    assert_eq!(prefix["ip-prefix"], "1.1.1.1");
+    assert_eq!(prefix["prefix-len"], 32);
+    let sid = &prefix["ietf-isis-sr-mpls:prefix-sid-sub-tlvs"]["prefix-sid-sub-tlv"][0];
+    assert_eq!(sid["sid"], 100);
+    assert_eq!(sid["prefix-sid-flags"]["flag"], serde_json::json!([]));
+
+    // Area addresses have no node in the model.
+    assert_eq!(yang["unknown-tlvs"]["unknown-tlv"][0]["type"], 1);
+}
+
+#[test]
+pub fn yang_database_levels() {
+    let lsdb: IsisLsdb = [lsp()].into_iter().collect();
+    let yang = yang_database(&[(1, &lsdb), (2, &IsisLsdb::new())]);
+    let levels = &yang["ietf-isis:database"]["levels"];
+    assert_eq!(levels[0]["level"], 1);
+    assert_eq!(levels[0]["lsp"][0]["lsp-id"], "0000.0000.0001.00-00");
+    assert_eq!(levels[1]["lsp"].as_array().unwrap().len(), 0);
+}
+
+#[test]
+pub fn yang_mt_id() {
+    // MT IPv6 Reachability and SRv6 Locator TLVs of topology 2, IPv6
+    // unicast.
+    let tlvs = hex!(
+        "
+        ED 10 00 02 00 00 00 0A 00 40 20 01 0D B8 00 00
+        00 01 1B 10 00 02 00 00 00 0A 00 00 30 20 01 0D
+        B8 00 01 00
+        "
+    );
+    let mut lsp = lsp();
+    let mut input = &tlvs[..];
+    while !input.is_empty() {
+        let (rest, tlv) = IsisTlv::parse_tlv(input).unwrap();
+        lsp.tlvs.push(tlv);
+        input = rest;
+    }
+    let yang = lsp.yang();
+    let prefix = &yang["mt-ipv6-reachability"]["prefixes"][0];
+    assert_eq!(prefix["mt-id"], 2);
+    assert_eq!(prefix["ip-prefix"], "2001:db8:0:1::");
+    assert_eq!(prefix["prefix-len"], 64);
+    let locator = &yang["ietf-isis-srv6:srv6-locator"]["locators"][0];
+    assert_eq!(locator["mt-id"], 2);
+    assert_eq!(locator["locator"], "2001:db8:1::");
+}